@@ -1,17 +1,34 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use pinocchio::Address;
+use shared::{
+    account::ZeroCopy,
+    discriminator::{discriminator, Discriminated, DISCRIMINATOR_LEN},
+};
 
+/// On-chain layout (73 bytes, no padding):
+///
+/// | offset | size | field         |
+/// |--------|------|---------------|
+/// | 0      | 8    | discriminator |
+/// | 8      | 32   | address       |
+/// | 40     | 32   | authority     |
+/// | 72     | 1    | bump          |
+#[repr(C)]
 #[derive(BorshDeserialize, BorshSerialize, shank::ShankAccount)]
 pub struct Meme {
+    pub discriminator: [u8; 8],
     #[idl_type("[u8;32]")]
     pub address: Address,
+    #[idl_type("[u8;32]")]
+    pub authority: Address,
     pub bump: u8,
 }
 
 impl Meme {
-    pub const SPACE: usize = 1  // bump
-        + 8     //count
-        + 32; //address
+    pub const SPACE: usize = DISCRIMINATOR_LEN // discriminator
+        + 32 // address
+        + 32 // authority
+        + 1; // bump
 
     pub const SEED_PREFIX: &[u8; 4] = b"meme";
 
@@ -19,3 +36,11 @@ impl Meme {
         Address::find_program_address(&[Self::SEED_PREFIX], &crate::ID.into())
     }
 }
+
+impl Discriminated for Meme {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] = discriminator(b"account:Meme");
+}
+
+unsafe impl ZeroCopy for Meme {}
+
+const _: () = assert!(Meme::LEN == Meme::SPACE);
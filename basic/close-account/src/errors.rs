@@ -0,0 +1,27 @@
+use shared::error::ErrorCode;
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error, ErrorCode)]
+#[repr(u32)]
+pub enum CloseAccountError {
+    #[error("Meme address does not match the expected PDA")]
+    InvalidMemeAddress = 6000,
+
+    #[error("Meme is already initialized")]
+    MemeAlreadyInitialized = 6001,
+
+    #[error("Meme is not owned by the program")]
+    InvalidMemeOwner = 6002,
+
+    #[error("Meme data could not be deserialized")]
+    InvalidMemeData = 6003,
+
+    #[error("Signer is not the meme authority")]
+    AuthorityMismatch = 6004,
+
+    #[error("Destination cannot be the meme account")]
+    InvalidDestination = 6005,
+
+    #[error("Account discriminator does not match the expected type")]
+    AccountDiscriminatorMismatch = 6006,
+}
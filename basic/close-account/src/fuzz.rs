@@ -0,0 +1,176 @@
+//! The close-account program as a [`test_utils::fuzz`] target, with the
+//! invariants of its memes.
+
+extern crate std;
+
+use std::{collections::BTreeMap, vec::Vec};
+
+use borsh::BorshDeserialize;
+use pinocchio::Address;
+use proptest::prelude::*;
+use shared::discriminator::Discriminated;
+use solana_sdk::{
+    account::Account, instruction::Instruction, message::AccountMeta, signer::Signer,
+};
+use test_utils::fuzz::{signer, Run, Target, SIGNERS};
+
+use crate::{accounts::Meme, CloseAccountInstruction, ID};
+
+/// The meme PDA, and the memes and instructions over it and the fuzz
+/// signers.
+pub fn target() -> Target {
+    Target {
+        program_id: ID.into(),
+        addresses: std::vec![Meme::derive().0],
+        account_data: account_data(),
+        instructions: instructions(),
+    }
+}
+
+fn account_data() -> BoxedStrategy<Vec<u8>> {
+    let (meme, bump) = Meme::derive();
+
+    (0..SIGNERS)
+        .prop_map(move |authority| {
+            borsh::to_vec(&Meme {
+                discriminator: Meme::DISCRIMINATOR,
+                address: meme,
+                authority: signer(authority).pubkey(),
+                bump,
+            })
+            .unwrap()
+        })
+        .boxed()
+}
+
+fn instructions() -> BoxedStrategy<Instruction> {
+    let meme = Meme::derive().0;
+    let system_program = AccountMeta::new_readonly(solana_system_interface::program::ID, false);
+
+    prop_oneof![
+        (0..SIGNERS).prop_map({
+            let system_program = system_program.clone();
+
+            move |payer| {
+                instruction(
+                    &CloseAccountInstruction::CreateMeme,
+                    std::vec![
+                        AccountMeta::new(signer(payer).pubkey(), true),
+                        AccountMeta::new(meme, false),
+                        system_program.clone(),
+                    ],
+                )
+            }
+        }),
+        // Any signer closing to any signer, not only the authority to itself.
+        (0..SIGNERS, 0..SIGNERS).prop_map(move |(authority, destination)| {
+            instruction(
+                &CloseAccountInstruction::CloseMeme,
+                std::vec![
+                    AccountMeta::new_readonly(signer(authority).pubkey(), true),
+                    AccountMeta::new(meme, false),
+                    AccountMeta::new(signer(destination).pubkey(), false),
+                    system_program.clone(),
+                ],
+            )
+        }),
+    ]
+    .boxed()
+}
+
+fn instruction(instruction: &CloseAccountInstruction, accounts: Vec<AccountMeta>) -> Instruction {
+    Instruction {
+        program_id: ID.into(),
+        accounts,
+        data: borsh::to_vec(instruction).unwrap(),
+    }
+}
+
+/// Asserts the invariants of every program, and that memes keep their
+/// authority, which alone closes them, refunding the destination.
+#[track_caller]
+pub fn assert_invariants(run: &Run) {
+    run.assert_invariants(&ID.into());
+
+    for (address, before) in &run.before {
+        let Some(authority) = authority_of(before) else {
+            continue;
+        };
+
+        if let Some(new_authority) = authority_of(&run.after[address]) {
+            assert_eq!(
+                new_authority, authority,
+                "authority of {address} changed: {:?}",
+                run.case
+            );
+            continue;
+        }
+
+        let closed_by = match (
+            CloseAccountInstruction::try_from_slice(&run.case.instruction.data),
+            run.case.instruction.accounts.as_slice(),
+        ) {
+            (Ok(CloseAccountInstruction::CloseMeme), [signer, meme, destination, ..])
+                if signer.is_signer && meme.pubkey == *address =>
+            {
+                Some((signer.pubkey, destination.pubkey))
+            }
+            _ => None,
+        };
+
+        let Some((signer, destination)) = closed_by else {
+            panic!("{address} closed outside of `CloseMeme`: {:?}", run.case);
+        };
+
+        assert_eq!(
+            signer, authority,
+            "{address} closed without its authority: {:?}",
+            run.case
+        );
+
+        let lamports = |accounts: &BTreeMap<Address, Option<Account>>| {
+            accounts[&destination]
+                .as_ref()
+                .map_or(0, |account| account.lamports)
+        };
+
+        assert_eq!(
+            lamports(&run.after),
+            lamports(&run.before) + before.as_ref().unwrap().lamports,
+            "{address} not refunded to {destination}: {:?}",
+            run.case
+        );
+    }
+}
+
+/// The authority of a meme of the program.
+fn authority_of(account: &Option<Account>) -> Option<Address> {
+    let account = account
+        .as_ref()
+        .filter(|account| account.owner == Address::from(ID))?;
+
+    Meme::is_discriminated(&account.data)
+        .then(|| Meme::try_from_slice(&account.data).ok())
+        .flatten()
+        .map(|meme| meme.authority)
+}
+
+#[cfg(test)]
+mod test {
+    use test_utils::{
+        fuzz::{cases, check_cases},
+        Fixture,
+    };
+
+    use super::{assert_invariants, target};
+    use crate::ID;
+
+    #[test]
+    fn keeps_invariants() {
+        check_cases(
+            Fixture::new(ID, "close-account", crate::process),
+            cases(target()),
+            assert_invariants,
+        );
+    }
+}
@@ -1,22 +1,51 @@
 use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shared::{
+    account::{close, ZeroCopy},
+    discriminator::Discriminated,
+    system,
+};
 
-pub fn process(_program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
-    let [payer, meme, system_program] = accounts else {
+use crate::{accounts::Meme, errors::CloseAccountError};
+
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, meme, destination, system_program] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    let leaving_lamports = 0;
-    let taking_lamports = meme.lamports() - leaving_lamports;
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !meme.owned_by(program_id) {
+        return Err(CloseAccountError::InvalidMemeOwner.into());
+    }
+
+    let (pda, _) = Meme::derive();
+
+    if meme.address().ne(&pda) {
+        return Err(CloseAccountError::InvalidMemeAddress.into());
+    }
 
-    meme.set_lamports(leaving_lamports);
-    payer.set_lamports(payer.lamports() + taking_lamports);
+    if system_program.address().ne(&system::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    };
 
-    meme.resize(0)?;
+    let data = meme.try_borrow()?;
 
-    unsafe {
-        meme.assign(system_program.address());
+    if !Meme::is_discriminated(&data) {
+        return Err(CloseAccountError::AccountDiscriminatorMismatch.into());
     }
 
+    let meme_data = Meme::load(&data).map_err(|_| CloseAccountError::InvalidMemeData)?;
+
+    if meme_data.authority.ne(authority.address()) {
+        return Err(CloseAccountError::AuthorityMismatch.into());
+    }
+
+    drop(data);
+
+    close(meme, destination, CloseAccountError::InvalidDestination)?;
+
     Ok(())
 }
 
@@ -24,74 +53,334 @@ pub fn process(_program_id: &Address, accounts: &[AccountView]) -> ProgramResult
 mod test {
     extern crate std;
 
-    use litesvm::LiteSVM;
-    use pinocchio::Address;
+    use std::vec::Vec;
+
+    use pinocchio::{error::ProgramError, Address};
     use solana_sdk::{
         account::Account, instruction::Instruction, message::AccountMeta,
         native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer,
-        transaction::Transaction,
     };
+    use test_utils::Fixture;
 
-    use crate::{accounts::Meme, CloseAccountInstruction, ID};
-
-    #[test]
-    fn close_meme() {
-        let mut svm = LiteSVM::new();
+    use shared::discriminator::{Discriminated, DISCRIMINATOR_LEN};
 
-        let payer = Keypair::new();
+    use crate::{accounts::Meme, errors::CloseAccountError, CloseAccountInstruction, ID};
 
-        let program_id = Address::new_from_array(ID);
+    struct Setup {
+        fixture: Fixture,
+        authority: Keypair,
+        destination: Address,
+        meme: Address,
+    }
 
-        svm.add_program_from_file(program_id, "../../target/deploy/close_account.so")
-            .unwrap();
+    /// A meme whose authority is not the fixture payer, so tests can leave its
+    /// signature out.
+    fn setup() -> Setup {
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
 
-        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let authority = fixture.funded_payer();
+        let destination = Keypair::new().pubkey();
 
         let (meme, bump) = Meme::derive();
 
-        svm.set_account(
+        fixture.set_borsh_account(
             meme,
-            Account {
-                data: borsh::to_vec(&Meme {
-                    bump,
-                    address: meme,
-                })
-                .unwrap(),
-                executable: false,
-                lamports: LAMPORTS_PER_SOL,
-                owner: ID.into(),
-                rent_epoch: 0,
+            ID.into(),
+            &Meme {
+                discriminator: Meme::DISCRIMINATOR,
+                bump,
+                address: meme,
+                authority: authority.pubkey(),
             },
-        )
-        .unwrap();
+        );
 
-        let ix_data = CloseAccountInstruction::CloseMeme;
+        Setup {
+            fixture,
+            authority,
+            destination,
+            meme,
+        }
+    }
 
-        let ix = Instruction {
-            program_id,
+    fn close_meme_ix(
+        authority: Address,
+        meme: Address,
+        destination: Address,
+        system_program: Address,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID.into(),
             accounts: [
-                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(authority, true),
                 AccountMeta::new(meme, false),
-                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(system_program, false),
             ]
             .to_vec(),
-            data: borsh::to_vec(&ix_data).unwrap(),
-        };
-
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&payer.pubkey()),
-            &[&payer],
-            svm.latest_blockhash(),
+            data: borsh::to_vec(&CloseAccountInstruction::CloseMeme).unwrap(),
+        }
+    }
+
+    #[test]
+    fn close_meme() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
+
+        let ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            destination,
+            solana_system_interface::program::ID,
+        );
+
+        fixture.send_signed(&[ix], &[&authority]).assert_success();
+
+        assert!(fixture.account(&meme).is_none());
+        assert_eq!(fixture.balance(&destination), LAMPORTS_PER_SOL);
+    }
+
+    #[test]
+    fn close_meme_without_signer() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
+
+        let mut ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            destination,
+            solana_system_interface::program::ID,
+        );
+        ix.accounts[0].is_signer = false;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn close_meme_with_wrong_authority() {
+        let Setup {
+            mut fixture,
+            destination,
+            meme,
+            ..
+        } = setup();
+
+        let attacker = fixture.funded_payer();
+
+        let ix = close_meme_ix(
+            attacker.pubkey(),
+            meme,
+            destination,
+            solana_system_interface::program::ID,
+        );
+
+        fixture
+            .send_signed(&[ix], &[&attacker])
+            .assert_error(CloseAccountError::AuthorityMismatch);
+    }
+
+    #[test]
+    fn close_meme_not_owned_by_program() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
+
+        let mut account = fixture.account(&meme).unwrap();
+        account.owner = Keypair::new().pubkey();
+        fixture.set_account(meme, account);
+
+        let ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            destination,
+            solana_system_interface::program::ID,
+        );
+
+        fixture
+            .send_signed(&[ix], &[&authority])
+            .assert_error(CloseAccountError::InvalidMemeOwner);
+    }
+
+    #[test]
+    fn close_meme_with_wrong_address() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
+
+        let fake_meme = Keypair::new().pubkey();
+        let account = fixture.account(&meme).unwrap();
+        fixture.set_account(fake_meme, account);
+
+        let ix = close_meme_ix(
+            authority.pubkey(),
+            fake_meme,
+            destination,
+            solana_system_interface::program::ID,
+        );
+
+        fixture
+            .send_signed(&[ix], &[&authority])
+            .assert_error(CloseAccountError::InvalidMemeAddress);
+    }
+
+    #[test]
+    fn close_meme_with_wrong_system_program() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
+
+        let ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            destination,
+            Keypair::new().pubkey(),
+        );
+
+        fixture
+            .send_signed(&[ix], &[&authority])
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn close_meme_into_itself() {
+        let Setup {
+            mut fixture,
+            authority,
+            meme,
+            ..
+        } = setup();
+
+        let ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            meme,
+            solana_system_interface::program::ID,
+        );
+
+        fixture
+            .send_signed(&[ix], &[&authority])
+            .assert_error(CloseAccountError::InvalidDestination);
+    }
+
+    #[test]
+    fn close_meme_with_wrong_discriminator() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
+
+        let mut account = fixture.account(&meme).unwrap();
+        account.data[..DISCRIMINATOR_LEN].fill(0);
+        fixture.set_account(meme, account);
+
+        let ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            destination,
+            solana_system_interface::program::ID,
+        );
+
+        fixture
+            .send_signed(&[ix], &[&authority])
+            .assert_error(CloseAccountError::AccountDiscriminatorMismatch);
+    }
+
+    #[test]
+    fn close_meme_with_missing_accounts() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
+
+        let mut ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            destination,
+            solana_system_interface::program::ID,
+        );
+        ix.accounts.pop();
+
+        fixture
+            .send_signed(&[ix], &[&authority])
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+
+    #[test]
+    fn close_meme_with_invalid_data() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
+
+        let mut account = fixture.account(&meme).unwrap();
+        account.data.push(0);
+        fixture.set_account(meme, account);
+
+        let ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            destination,
+            solana_system_interface::program::ID,
         );
 
-        let result = svm.send_transaction(tx).unwrap();
+        fixture
+            .send_signed(&[ix], &[&authority])
+            .assert_error(CloseAccountError::InvalidMemeData);
+    }
 
-        std::println!("Program executed successfully!");
-        std::println!("Transaction logs: {:#?}", result.logs);
+    #[test]
+    fn close_meme_overflowing_destination() {
+        let Setup {
+            mut fixture,
+            authority,
+            destination,
+            meme,
+        } = setup();
 
-        let meme = svm.get_account(&meme);
+        fixture.set_account(
+            destination,
+            Account {
+                lamports: u64::MAX,
+                data: Vec::new(),
+                owner: solana_system_interface::program::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let ix = close_meme_ix(
+            authority.pubkey(),
+            meme,
+            destination,
+            solana_system_interface::program::ID,
+        );
 
-        assert!(meme.is_none());
+        fixture
+            .send_signed(&[ix], &[&authority])
+            .assert_error(ProgramError::ArithmeticOverflow);
     }
 }
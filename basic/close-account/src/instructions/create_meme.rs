@@ -1,13 +1,11 @@
-use borsh::BorshSerialize;
-use pinocchio::{
-    cpi::{Seed, Signer},
-    error::ProgramError,
-    sysvars::{rent::Rent, Sysvar},
-    AccountView, Address, ProgramResult,
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shared::{
+    account::ZeroCopy,
+    syscalls,
+    system::{self, CreateAccount},
 };
-use pinocchio_system::instructions::CreateAccount;
 
-use crate::accounts::Meme;
+use crate::{accounts::Meme, errors::CloseAccountError};
 
 pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
     let [payer, meme, system_program] = accounts else {
@@ -21,26 +19,22 @@ pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult
     let (pda, bump) = Meme::derive();
 
     if meme.address().ne(&pda) {
-        return Err(ProgramError::InvalidSeeds);
+        return Err(CloseAccountError::InvalidMemeAddress.into());
     }
 
     if meme.lamports().ne(&0) || meme.data_len().ne(&0) {
-        return Err(ProgramError::AccountAlreadyInitialized);
+        return Err(CloseAccountError::MemeAlreadyInitialized.into());
     }
 
-    if system_program.address().ne(&pinocchio_system::ID) {
+    if system_program.address().ne(&system::ID) {
         return Err(ProgramError::IncorrectProgramId);
     };
 
-    let meme_data = Meme { bump, address: pda };
-
     let account_span = Meme::SPACE;
-    let lamports_required = Rent::get()?.minimum_balance_unchecked(account_span);
+    let lamports_required = syscalls::rent()?.minimum_balance_unchecked(account_span);
 
     let bump_bytes = &[bump];
-    let seeds = [Seed::from(Meme::SEED_PREFIX), Seed::from(bump_bytes)];
-
-    let signers = Signer::from(&seeds);
+    let seeds: [&[u8]; 2] = [Meme::SEED_PREFIX, bump_bytes];
 
     CreateAccount {
         from: payer,
@@ -49,11 +43,15 @@ pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult
         space: account_span as u64,
         owner: program_id,
     }
-    .invoke_signed(&[signers])?;
+    .invoke_signed(&[&seeds])?;
+
+    let mut data = meme.try_borrow_mut()?;
 
-    meme_data
-        .serialize(&mut meme.try_borrow_mut()?.as_mut())
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let meme_data = Meme::initialize(&mut data).map_err(|_| CloseAccountError::InvalidMemeData)?;
+
+    meme_data.address = pda;
+    meme_data.authority = *payer.address();
+    meme_data.bump = bump;
 
     Ok(())
 }
@@ -62,61 +60,154 @@ pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult
 mod test {
     extern crate std;
 
-    use borsh::BorshDeserialize;
-    use litesvm::LiteSVM;
-    use pinocchio::Address;
+    use std::vec::Vec;
+
+    use pinocchio::{error::ProgramError, Address};
     use solana_sdk::{
-        instruction::Instruction, message::AccountMeta, native_token::LAMPORTS_PER_SOL,
-        signature::Keypair, signer::Signer, transaction::Transaction,
+        account::Account, instruction::Instruction, message::AccountMeta,
+        native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer,
     };
+    use test_utils::Fixture;
 
-    use crate::{accounts::Meme, CloseAccountInstruction, ID};
+    use crate::{accounts::Meme, errors::CloseAccountError, CloseAccountInstruction, ID};
+
+    fn create_meme_ix(payer: Address, meme: Address, system_program: Address) -> Instruction {
+        Instruction {
+            program_id: ID.into(),
+            accounts: [
+                AccountMeta::new(payer, true),
+                AccountMeta::new(meme, false),
+                AccountMeta::new_readonly(system_program, false),
+            ]
+            .to_vec(),
+            data: borsh::to_vec(&CloseAccountInstruction::CreateMeme).unwrap(),
+        }
+    }
 
     #[test]
     fn create_meme() {
-        let mut svm = LiteSVM::new();
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
+        let payer = fixture.payer.pubkey();
 
-        let payer = Keypair::new();
+        let (meme_addr, bump) = Meme::derive();
 
-        let program_id = Address::new_from_array(ID);
+        fixture
+            .send(create_meme_ix(
+                payer,
+                meme_addr,
+                solana_system_interface::program::ID,
+            ))
+            .assert_success();
 
-        svm.add_program_from_file(program_id, "../../target/deploy/close_account.so")
-            .unwrap();
+        let meme_data: Meme = fixture.borsh_account(&meme_addr);
 
-        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        assert_eq!(meme_data.bump, bump);
+        assert_eq!(meme_data.address, meme_addr);
+        assert_eq!(meme_data.authority, payer);
+    }
 
-        let (meme_addr, bump) = Meme::derive();
+    #[test]
+    fn create_meme_without_signer() {
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
 
-        let ix_data = CloseAccountInstruction::CreateMeme;
+        let mut ix = create_meme_ix(
+            fixture.funded_payer().pubkey(),
+            Meme::derive().0,
+            solana_system_interface::program::ID,
+        );
+        ix.accounts[0].is_signer = false;
 
-        let ix = Instruction {
-            program_id,
-            accounts: [
-                AccountMeta::new(payer.pubkey(), true),
-                AccountMeta::new(meme_addr, false),
-                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
-            ]
-            .to_vec(),
-            data: borsh::to_vec(&ix_data).unwrap(),
-        };
-
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&payer.pubkey()),
-            &[&payer],
-            svm.latest_blockhash(),
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn create_meme_with_wrong_address() {
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
+
+        fixture
+            .send(create_meme_ix(
+                fixture.payer.pubkey(),
+                Keypair::new().pubkey(),
+                solana_system_interface::program::ID,
+            ))
+            .assert_error(CloseAccountError::InvalidMemeAddress);
+    }
+
+    #[test]
+    fn create_meme_already_initialized() {
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
+
+        let (meme, _) = Meme::derive();
+
+        // Only funded: lamports alone make the address unusable for
+        // `CreateAccount`.
+        fixture.set_account(
+            meme,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data: Vec::new(),
+                owner: solana_system_interface::program::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
         );
 
-        let result = svm.send_transaction(tx).unwrap();
+        fixture
+            .send(create_meme_ix(
+                fixture.payer.pubkey(),
+                meme,
+                solana_system_interface::program::ID,
+            ))
+            .assert_error(CloseAccountError::MemeAlreadyInitialized);
+    }
 
-        std::println!("Program executed successfully!");
-        std::println!("Transaction logs: {:#?}", result.logs);
+    #[test]
+    fn create_meme_with_wrong_system_program() {
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
+
+        fixture
+            .send(create_meme_ix(
+                fixture.payer.pubkey(),
+                Meme::derive().0,
+                Keypair::new().pubkey(),
+            ))
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn create_meme_with_missing_accounts() {
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
 
-        let meme = svm.get_account(&meme_addr).unwrap();
+        let mut ix = create_meme_ix(
+            fixture.payer.pubkey(),
+            Meme::derive().0,
+            solana_system_interface::program::ID,
+        );
+        ix.accounts.pop();
 
-        let meme_data = Meme::deserialize(&mut meme.data.as_ref()).unwrap();
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
 
-        assert_eq!(meme_data.bump, bump);
-        assert_eq!(meme_data.address, meme_addr)
+    #[test]
+    fn invalid_instruction_data() {
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
+
+        // No instruction tag, then a tag past the last instruction.
+        for data in [Vec::new(), [2].to_vec()] {
+            let mut ix = create_meme_ix(
+                fixture.payer.pubkey(),
+                Meme::derive().0,
+                solana_system_interface::program::ID,
+            );
+            ix.data = data;
+
+            fixture
+                .send(ix)
+                .assert_error(ProgramError::InvalidInstructionData);
+        }
     }
 }
@@ -3,8 +3,7 @@
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use pinocchio::{
-    default_panic_handler, error::ProgramError, no_allocator, program_entrypoint, AccountView,
-    Address, ProgramResult,
+    default_panic_handler, error::ProgramError, no_allocator, AccountView, Address, ProgramResult,
 };
 use pinocchio_pubkey::declare_id;
 use shank::ShankInstruction;
@@ -12,9 +11,13 @@ use shank::ShankInstruction;
 use crate::instructions::{close_meme, create_meme};
 
 mod accounts;
+mod errors;
+#[cfg(any(test, feature = "fuzz"))]
+pub mod fuzz;
 mod instructions;
 
-program_entrypoint!(process);
+#[cfg(target_os = "solana")]
+pinocchio::program_entrypoint!(process);
 no_allocator!();
 default_panic_handler!();
 
@@ -22,14 +25,15 @@ declare_id!("2HXWQuEjgRDbNcMx3X32C1aw4fftVMHyUf9KXYyTiPiD");
 
 #[derive(BorshDeserialize, BorshSerialize, ShankInstruction)]
 pub enum CloseAccountInstruction {
-    #[account(0, sig, name = "payer")]
+    #[account(0, mut, sig, name = "payer")]
     #[account(1, mut, name = "meme")]
     #[account(2, name = "system_program")]
     CreateMeme,
 
-    #[account(0, sig, name = "payer")]
+    #[account(0, sig, name = "authority")]
     #[account(1, mut, name = "meme")]
-    #[account(2, name = "system_program")]
+    #[account(2, mut, name = "destination")]
+    #[account(3, name = "system_program")]
     CloseMeme,
 }
 
@@ -49,3 +53,44 @@ pub fn process(
         CloseAccountInstruction::CloseMeme => close_meme::process(program_id, accounts),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::instruction::Instruction;
+    use test_utils::Fixture;
+
+    use crate::ID;
+
+    #[test]
+    fn process_under_other_program_id() {
+        let other_id = Address::new_from_array([7; 32]);
+
+        let mut fixture = Fixture::new(other_id, "close-account", crate::process);
+
+        let ix = Instruction {
+            program_id: other_id,
+            accounts: [].to_vec(),
+            data: [0].to_vec(),
+        };
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn process_unknown_instruction() {
+        let mut fixture = Fixture::new(ID, "close-account", crate::process);
+
+        let ix = Instruction {
+            program_id: ID.into(),
+            accounts: [].to_vec(),
+            data: [0xff].to_vec(),
+        };
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::InvalidInstructionData);
+    }
+}
@@ -1,22 +1,90 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use pinocchio::Address;
+use pinocchio::{error::ProgramError, Address};
 use shank::ShankAccount;
+use shared::{
+    account::ZeroCopy,
+    discriminator::{discriminator, Discriminated, DISCRIMINATOR_LEN},
+};
 
-use crate::ID;
+use crate::{errors::CounterError, ID};
 
+/// On-chain layout (25 bytes, no padding):
+///
+/// | offset | size | field                       |
+/// |--------|------|-----------------------------|
+/// | 0      | 8    | discriminator               |
+/// | 8      | 1    | bump                        |
+/// | 9      | 8    | count (u64, little end)     |
+/// | 17     | 8    | max_count (u64, little end) |
+#[repr(C)]
 #[derive(BorshDeserialize, BorshSerialize, ShankAccount)]
 pub struct Counter {
+    pub discriminator: [u8; 8],
     pub bump: u8,
-    pub count: u64,
+    #[idl_type("u64")]
+    pub(crate) count: [u8; 8],
+    /// The highest count allowed, `u64::MAX` when the counter has no cap.
+    #[idl_type("u64")]
+    pub(crate) max_count: [u8; 8],
 }
 
 impl Counter {
-    pub const SPACE: usize = 1  // bump
-        + 8; //count
+    pub const SPACE: usize = DISCRIMINATOR_LEN // discriminator
+        + 1  // bump
+        + 8  // count
+        + 8; // max_count
 
     pub const SEED_PREFIX: &[u8; 7] = b"counter";
 
     pub fn derive() -> (Address, u8) {
         Address::find_program_address(&[Self::SEED_PREFIX], &ID.into())
     }
+
+    pub fn count(&self) -> u64 {
+        u64::from_le_bytes(self.count)
+    }
+
+    pub fn set_count(&mut self, count: u64) {
+        self.count = count.to_le_bytes();
+    }
+
+    pub fn max_count(&self) -> u64 {
+        u64::from_le_bytes(self.max_count)
+    }
+
+    pub fn set_max_count(&mut self, max_count: u64) {
+        self.max_count = max_count.to_le_bytes();
+    }
+
+    /// The count plus `amount`, unless that overflows or exceeds the maximum.
+    pub fn checked_increase(&self, amount: u64) -> Result<u64, ProgramError> {
+        let count = self
+            .count()
+            .checked_add(amount)
+            .ok_or(CounterError::CountOverflow)?;
+
+        if count > self.max_count() {
+            return Err(CounterError::MaxCountExceeded.into());
+        }
+
+        Ok(count)
+    }
+
+    /// The count minus `amount`, unless that goes below zero.
+    pub fn checked_decrease(&self, amount: u64) -> Result<u64, ProgramError> {
+        let count = self
+            .count()
+            .checked_sub(amount)
+            .ok_or(CounterError::CountUnderflow)?;
+
+        Ok(count)
+    }
 }
+
+impl Discriminated for Counter {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] = discriminator(b"account:Counter");
+}
+
+unsafe impl ZeroCopy for Counter {}
+
+const _: () = assert!(Counter::LEN == Counter::SPACE);
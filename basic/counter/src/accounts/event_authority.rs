@@ -0,0 +1,18 @@
+use pinocchio::Address;
+use shared::event::cpi::EVENT_AUTHORITY_SEED;
+
+use crate::ID;
+
+/// Signer of the self-CPIs that carry events when built with `cpi-events`.
+///
+/// Holds no data; its only job is proving that an event instruction came from
+/// this program.
+pub struct EventAuthority;
+
+impl EventAuthority {
+    pub const SEED_PREFIX: &[u8; 17] = EVENT_AUTHORITY_SEED;
+
+    pub fn derive() -> (Address, u8) {
+        Address::find_program_address(&[Self::SEED_PREFIX], &ID.into())
+    }
+}
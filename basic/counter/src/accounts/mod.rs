@@ -1,5 +1,57 @@
 mod counter;
 mod counter_authority;
+#[cfg(feature = "cpi-events")]
+mod event_authority;
+mod program_data;
 
 pub use counter::*;
 pub use counter_authority::*;
+#[cfg(feature = "cpi-events")]
+pub use event_authority::*;
+pub use program_data::*;
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use core::str;
+
+    use serde_json::{json, Value};
+
+    use super::{Counter, CounterAuthority};
+    use crate::test_helpers::sync_idl;
+
+    /// Seeds of each PDA account, in Shank's IDL format.
+    fn seeds(account: &str) -> Value {
+        let constant = |seed: &[u8]| {
+            json!({
+                "kind": "constant",
+                "type": "string",
+                "value": str::from_utf8(seed).unwrap(),
+            })
+        };
+
+        match account {
+            "Counter" => json!([constant(Counter::SEED_PREFIX)]),
+            "CounterAuthority" => json!([
+                constant(CounterAuthority::SEED_PREFIX),
+                {
+                    "kind": "variable",
+                    "name": "authority",
+                    "description": "The authority of the counter",
+                    "type": "publicKey",
+                },
+            ]),
+            _ => panic!("no seeds for account {account}"),
+        }
+    }
+
+    #[test]
+    fn idl_lists_account_seeds() {
+        sync_idl(|idl| {
+            for account in idl["accounts"].as_array_mut().unwrap() {
+                account["seeds"] = seeds(account["name"].as_str().unwrap());
+            }
+        });
+    }
+}
@@ -0,0 +1,45 @@
+use pinocchio::Address;
+
+use crate::ID;
+
+/// The upgradeable loader account holding this program's code, whose upgrade
+/// authority is the program admin.
+///
+/// On-chain layout of the part read here (bincode, no padding):
+///
+/// | offset | size | field                                  |
+/// |--------|------|----------------------------------------|
+/// | 0      | 4    | loader state tag (u32, `3`)            |
+/// | 4      | 8    | deployment slot (u64, little end)      |
+/// | 12     | 1    | upgrade authority option tag           |
+/// | 13     | 32   | upgrade authority, when the tag is `1` |
+pub struct ProgramData;
+
+impl ProgramData {
+    pub const LOADER: Address =
+        Address::from_str_const("BPFLoaderUpgradeab1e11111111111111111111111");
+
+    const TAG: [u8; 4] = 3u32.to_le_bytes();
+
+    pub fn derive() -> (Address, u8) {
+        Address::find_program_address(&[ID.as_ref()], &Self::LOADER)
+    }
+
+    /// The upgrade authority stored in `data`, `None` for an immutable program
+    /// or data that is not a program data account.
+    pub fn upgrade_authority(data: &[u8]) -> Option<Address> {
+        let (tag, rest) = data.split_first_chunk::<4>()?;
+
+        if *tag != Self::TAG {
+            return None;
+        }
+
+        match rest.get(8..)? {
+            [1, authority @ ..] => authority
+                .first_chunk()
+                .copied()
+                .map(Address::new_from_array),
+            _ => None,
+        }
+    }
+}
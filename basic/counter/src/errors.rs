@@ -0,0 +1,45 @@
+use shared::error::ErrorCode;
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error, ErrorCode)]
+#[repr(u32)]
+pub enum CounterError {
+    #[error("Counter address does not match the expected PDA")]
+    InvalidCounterAddress = 6000,
+
+    #[error("Counter is already initialized")]
+    CounterAlreadyInitialized = 6001,
+
+    #[error("Counter is not initialized")]
+    CounterNotInitialized = 6002,
+
+    #[error("Counter data could not be deserialized")]
+    InvalidCounterData = 6003,
+
+    #[error("Signer is not the counter authority")]
+    AuthorityMismatch = 6004,
+
+    #[error("Account discriminator does not match the expected type")]
+    AccountDiscriminatorMismatch = 6005,
+
+    #[error("Event authority address does not match the expected PDA")]
+    InvalidEventAuthority = 6006,
+
+    #[error("Counter would overflow")]
+    CountOverflow = 6007,
+
+    #[error("Count would exceed the counter maximum")]
+    MaxCountExceeded = 6008,
+
+    #[error("Counter would go below zero")]
+    CountUnderflow = 6009,
+
+    #[error("Signer is not the program admin")]
+    AdminMismatch = 6010,
+
+    #[error("Program data address does not match the program")]
+    InvalidProgramData = 6011,
+
+    #[error("Destination cannot be the closed counter")]
+    InvalidDestination = 6012,
+}
@@ -0,0 +1,18 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::Address;
+use shank::ShankType;
+use shared::event::Event;
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType, Event)]
+pub struct CounterClosed {
+    #[idl_type("[u8;32]")]
+    pub counter: Address,
+    #[idl_type("[u8;32]")]
+    pub admin: Address,
+    #[idl_type("[u8;32]")]
+    pub destination: Address,
+    /// The rent refunded to `destination`.
+    pub lamports: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,17 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::Address;
+use shank::ShankType;
+use shared::event::Event;
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType, Event)]
+pub struct CounterAuthorityClosed {
+    #[idl_type("[u8;32]")]
+    pub counter: Address,
+    #[idl_type("[u8;32]")]
+    pub authority: Address,
+    #[idl_type("[u8;32]")]
+    pub destination: Address,
+    pub lamports: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
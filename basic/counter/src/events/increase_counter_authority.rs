@@ -1,7 +1,15 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::Address;
 use shank::ShankType;
+use shared::event::Event;
 
-#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+#[derive(BorshSerialize, BorshDeserialize, ShankType, Event)]
 pub struct CounterAuthorityIncreased {
+    #[idl_type("[u8;32]")]
+    pub counter: Address,
+    #[idl_type("[u8;32]")]
+    pub authority: Address,
     pub new_count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
 }
@@ -1,7 +1,16 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::Address;
 use shank::ShankType;
+use shared::event::Event;
 
-#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+#[derive(BorshSerialize, BorshDeserialize, ShankType, Event)]
 pub struct CounterInitialized {
+    #[idl_type("[u8;32]")]
+    pub counter: Address,
+    /// The global counter has no authority; this is the account that paid for it.
+    #[idl_type("[u8;32]")]
+    pub payer: Address,
     pub count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
 }
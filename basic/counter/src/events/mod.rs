@@ -1,21 +1,283 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use shank::ShankType;
+#[cfg(feature = "cpi-events")]
+use pinocchio::error::ProgramError;
+use pinocchio::{AccountView, Address, ProgramResult};
+use shared::event::{Event, EventSet};
 
+#[cfg(feature = "cpi-events")]
+use crate::{accounts::EventAuthority, errors::CounterError};
+
+mod close_counter;
+mod close_counter_authority;
+mod decrease_counter;
+mod decrease_counter_authority;
 mod increase_counter;
 mod increase_counter_authority;
 mod init_counter;
 mod init_counter_authority;
+mod reset_counter;
+mod reset_counter_authority;
 
+pub use close_counter::*;
+pub use close_counter_authority::*;
+pub use decrease_counter::*;
+pub use decrease_counter_authority::*;
 pub use increase_counter::*;
 pub use increase_counter_authority::*;
 pub use init_counter::*;
 pub use init_counter_authority::*;
+pub use reset_counter::*;
+pub use reset_counter_authority::*;
 
-#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+/// Every event the program emits, for decoding. On the wire each event is
+/// identified by its own discriminator, not by its position in this enum.
+#[derive(EventSet)]
 #[allow(clippy::enum_variant_names)]
 pub enum CounterEvent {
     CounterInitialized(CounterInitialized),
     CounterIncreased(CounterIncreased),
     CounterAuthorityInitialized(CounterAuthorityInitialized),
     CounterAuthorityIncreased(CounterAuthorityIncreased),
+    CounterDecreased(CounterDecreased),
+    CounterReset(CounterReset),
+    CounterAuthorityDecreased(CounterAuthorityDecreased),
+    CounterAuthorityReset(CounterAuthorityReset),
+    CounterClosed(CounterClosed),
+    CounterAuthorityClosed(CounterAuthorityClosed),
+}
+
+/// Emits `event` through the transport selected at build time.
+///
+/// `event_accounts` are the instruction's trailing accounts: ignored for log
+/// events, `[event_authority, program]` with `cpi-events`.
+#[cfg(not(feature = "cpi-events"))]
+pub fn emit_event(
+    _program_id: &Address,
+    _event_accounts: &[AccountView],
+    event: &impl Event,
+) -> ProgramResult {
+    shared::event::emit(event)
+}
+
+/// Emits `event` through the transport selected at build time.
+///
+/// `event_accounts` are the instruction's trailing accounts: ignored for log
+/// events, `[event_authority, program]` with `cpi-events`.
+#[cfg(feature = "cpi-events")]
+pub fn emit_event(
+    program_id: &Address,
+    event_accounts: &[AccountView],
+    event: &impl Event,
+) -> ProgramResult {
+    let [event_authority, program] = event_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if program.address().ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (pda, bump) = EventAuthority::derive();
+
+    if event_authority.address().ne(&pda) {
+        return Err(CounterError::InvalidEventAuthority.into());
+    }
+
+    shared::event::cpi::emit_cpi(program_id, event_authority, bump, event)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use serde_json::{json, Value};
+    use shared::event::EventSet;
+
+    use super::CounterEvent;
+    use crate::test_helpers::sync_idl;
+
+    #[test]
+    fn idl_lists_every_event() {
+        let events: Vec<Value> = CounterEvent::EVENTS
+            .iter()
+            .map(|event| {
+                json!({
+                    "name": event.name,
+                    "discriminator": event.discriminator,
+                    "version": event.version,
+                })
+            })
+            .collect();
+
+        sync_idl(|idl| idl["events"] = Value::Array(events));
+    }
+}
+
+#[cfg(all(test, not(feature = "cpi-events")))]
+mod logs_test {
+    extern crate std;
+
+    use shared::event::logs::parse_logs;
+    use solana_sdk::{message::AccountMeta, signer::Signer};
+
+    use super::CounterEvent;
+    use crate::{
+        accounts::Counter,
+        instructions::InitCounterArgs,
+        test_helpers::{counter_ix, fixture},
+        CounterInstruction, ID,
+    };
+
+    #[test]
+    fn decodes_events_of_every_instruction_in_order() {
+        let mut fixture = fixture();
+
+        let (counter, _) = Counter::derive();
+
+        let init = counter_ix(
+            &CounterInstruction::InitCounter(InitCounterArgs {
+                count: 19,
+                max_count: None,
+            }),
+            &[
+                AccountMeta::new(fixture.payer.pubkey(), true),
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+        );
+        let increase = counter_ix(
+            &CounterInstruction::IncreaseCounter,
+            &[AccountMeta::new(counter, false)],
+        );
+
+        let outcome = fixture.send_signed(&[init, increase.clone(), increase], &[]);
+        outcome.assert_success();
+
+        // The system program's `CreateAccount` CPI sits between the init
+        // instruction's logs without taking any events with it.
+        let logged = parse_logs(&ID.into(), outcome.logs()).unwrap();
+
+        assert_eq!(logged.len(), 3);
+        assert!(logged.iter().all(|event| event.depth == 1));
+
+        let events: std::vec::Vec<CounterEvent> = outcome.events(&ID.into());
+
+        let [CounterEvent::CounterInitialized(init), CounterEvent::CounterIncreased(first), CounterEvent::CounterIncreased(second)] =
+            &events[..]
+        else {
+            panic!("expected CounterInitialized then two CounterIncreased events");
+        };
+
+        assert_eq!(init.count, 19);
+        assert_eq!(first.new_count, 20);
+        assert_eq!(second.new_count, 21);
+        assert!([init.counter, first.counter, second.counter]
+            .iter()
+            .all(|address| *address == counter));
+    }
+}
+
+#[cfg(all(test, feature = "cpi-events"))]
+mod cpi_test {
+    use pinocchio::error::ProgramError;
+    use shared::event::cpi::EVENT_IX_TAG;
+    use solana_sdk::{
+        instruction::Instruction, message::AccountMeta, signature::Keypair, signer::Signer,
+    };
+    use test_utils::Fixture;
+
+    use crate::{
+        accounts::{Counter, EventAuthority},
+        errors::CounterError,
+        test_helpers::{counter_ix, fixture},
+        CounterInstruction, InitCounterArgs, ID,
+    };
+
+    fn send_forged_event(signer_is_event_authority: bool) {
+        let mut fixture = fixture();
+
+        // Without the program's signature the event authority can only be
+        // passed as a non-signer; the alternative is signing with another key.
+        let first_account = if signer_is_event_authority {
+            AccountMeta::new_readonly(EventAuthority::derive().0, false)
+        } else {
+            AccountMeta::new_readonly(fixture.payer.pubkey(), true)
+        };
+
+        let mut data = EVENT_IX_TAG.to_vec();
+        data.extend_from_slice(&[0; 16]);
+
+        let ix = Instruction {
+            program_id: ID.into(),
+            accounts: [first_account].to_vec(),
+            data,
+        };
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn forged_event_without_event_authority_signature() {
+        send_forged_event(true);
+    }
+
+    #[test]
+    fn forged_event_signed_by_other_key() {
+        send_forged_event(false);
+    }
+
+    /// An `InitCounter` instruction, ending with the event authority and the
+    /// program accounts.
+    fn init_counter_ix(fixture: &Fixture) -> Instruction {
+        counter_ix(
+            &CounterInstruction::InitCounter(InitCounterArgs {
+                count: 19,
+                max_count: None,
+            }),
+            &[
+                AccountMeta::new(fixture.payer.pubkey(), true),
+                AccountMeta::new(Counter::derive().0, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+        )
+    }
+
+    #[test]
+    fn emit_event_without_event_accounts() {
+        let mut fixture = fixture();
+
+        let mut ix = init_counter_ix(&fixture);
+        ix.accounts.truncate(3);
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+
+    #[test]
+    fn emit_event_with_wrong_program() {
+        let mut fixture = fixture();
+
+        let mut ix = init_counter_ix(&fixture);
+        ix.accounts[4].pubkey = Keypair::new().pubkey();
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn emit_event_with_wrong_event_authority() {
+        let mut fixture = fixture();
+
+        let mut ix = init_counter_ix(&fixture);
+        ix.accounts[3].pubkey = Keypair::new().pubkey();
+
+        fixture
+            .send(ix)
+            .assert_error(CounterError::InvalidEventAuthority);
+    }
 }
@@ -0,0 +1,381 @@
+//! The counter as a [`test_utils::fuzz`] target, with the invariants of its
+//! counts.
+
+extern crate std;
+
+use std::vec::Vec;
+
+use borsh::BorshDeserialize;
+use pinocchio::Address;
+use proptest::prelude::*;
+use shared::discriminator::Discriminated;
+use solana_sdk::{
+    account::Account, instruction::Instruction, message::AccountMeta, signer::Signer,
+};
+use test_utils::{
+    fuzz::{signer, Run, Target, SIGNERS},
+    Fixture,
+};
+
+#[cfg(feature = "cpi-events")]
+use crate::accounts::EventAuthority;
+use crate::{
+    accounts::{Counter, CounterAuthority, ProgramData},
+    CounterInstruction, DecreaseCounterArgs, DecreaseCounterAuthorityArgs,
+    IncreaseCounterAuthorityByArgs, IncreaseCounterByArgs, InitCounterArgs,
+    InitCounterAuthorityArgs, ResetCounterArgs, ResetCounterAuthorityArgs, ID,
+};
+
+/// The counter PDA, the counter authority PDAs of the fuzz signers, and the
+/// accounts and instructions over them.
+pub fn target() -> Target {
+    let mut addresses = std::vec![Counter::derive().0];
+    addresses.extend((0..SIGNERS).map(|index| counter_authority(index).0));
+
+    #[cfg(feature = "cpi-events")]
+    addresses.push(EventAuthority::derive().0);
+
+    Target {
+        program_id: ID.into(),
+        addresses,
+        account_data: account_data(),
+        instructions: instructions(),
+    }
+}
+
+/// The signer the fixture records as the program admin.
+pub const ADMIN: u8 = 0;
+
+/// Makes [`ADMIN`] the upgrade authority of the program, in a program data
+/// account no case replaces, so run it once on the fixture the cases share.
+pub fn set_up(fixture: &mut Fixture) {
+    fixture.set_upgrade_authority(&ID.into(), Some(signer(ADMIN).pubkey()));
+}
+
+fn counter_authority(index: u8) -> (Address, u8) {
+    CounterAuthority::derive(&signer(index).pubkey())
+}
+
+fn account_data() -> BoxedStrategy<Vec<u8>> {
+    // Any count, with `u64::MAX` drawn often enough for the increases to
+    // meet it and reject the overflow.
+    let count = prop_oneof![any::<u64>(), Just(u64::MAX)];
+
+    // Uncapped counters, and caps anywhere, including below the count.
+    let max_count = prop_oneof![Just(u64::MAX), any::<u64>()];
+
+    prop_oneof![
+        (any::<u8>(), count.clone(), max_count.clone()).prop_map(|(bump, count, max_count)| {
+            borsh::to_vec(&Counter {
+                discriminator: Counter::DISCRIMINATOR,
+                bump,
+                count: count.to_le_bytes(),
+                max_count: max_count.to_le_bytes(),
+            })
+            .unwrap()
+        }),
+        (0..SIGNERS, any::<u8>(), count, max_count).prop_map(
+            |(authority, bump, count, max_count)| {
+                borsh::to_vec(&CounterAuthority {
+                    discriminator: CounterAuthority::DISCRIMINATOR,
+                    authority: signer(authority).pubkey(),
+                    bump,
+                    count: count.to_le_bytes(),
+                    max_count: max_count.to_le_bytes(),
+                })
+                .unwrap()
+            },
+        ),
+    ]
+    .boxed()
+}
+
+fn instructions() -> BoxedStrategy<Instruction> {
+    let system_program = AccountMeta::new_readonly(solana_system_interface::program::ID, false);
+    let counter = AccountMeta::new(Counter::derive().0, false);
+
+    prop_oneof![
+        (0..SIGNERS, any::<u64>(), prop::option::of(any::<u64>())).prop_map({
+            let (counter, system_program) = (counter.clone(), system_program.clone());
+
+            move |(payer, count, max_count)| {
+                instruction(
+                    &CounterInstruction::InitCounter(InitCounterArgs { count, max_count }),
+                    [
+                        AccountMeta::new(signer(payer).pubkey(), true),
+                        counter.clone(),
+                        system_program.clone(),
+                    ],
+                )
+            }
+        }),
+        Just(instruction(
+            &CounterInstruction::IncreaseCounter,
+            [counter.clone()]
+        )),
+        any::<u64>().prop_map({
+            let counter = counter.clone();
+
+            move |amount| {
+                instruction(
+                    &CounterInstruction::IncreaseCounterBy(IncreaseCounterByArgs { amount }),
+                    [counter.clone()],
+                )
+            }
+        }),
+        // Any signer as the admin, not only the upgrade authority.
+        (0..SIGNERS, any::<bool>(), any::<u64>()).prop_map({
+            let counter = counter.clone();
+
+            move |(admin, decrease, amount)| {
+                let counter_instruction = if decrease {
+                    CounterInstruction::DecreaseCounter(DecreaseCounterArgs { amount })
+                } else {
+                    CounterInstruction::ResetCounter(ResetCounterArgs { count: amount })
+                };
+
+                instruction(
+                    &counter_instruction,
+                    [
+                        AccountMeta::new_readonly(signer(admin).pubkey(), true),
+                        counter.clone(),
+                        AccountMeta::new_readonly(ProgramData::derive().0, false),
+                    ],
+                )
+            }
+        }),
+        (0..SIGNERS, any::<u64>(), prop::option::of(any::<u64>())).prop_map(
+            move |(payer, count, max_count)| {
+                instruction(
+                    &CounterInstruction::InitCounterAuhthority(InitCounterAuthorityArgs {
+                        count,
+                        max_count,
+                    }),
+                    [
+                        AccountMeta::new(signer(payer).pubkey(), true),
+                        AccountMeta::new(counter_authority(payer).0, false),
+                        system_program.clone(),
+                    ],
+                )
+            },
+        ),
+        // Any signer against any counter authority, not only its own.
+        (0..SIGNERS, 0..SIGNERS).prop_map(|(authority, counter)| {
+            instruction(
+                &CounterInstruction::IncreaseCounterAuthority,
+                [
+                    AccountMeta::new_readonly(signer(authority).pubkey(), true),
+                    AccountMeta::new(counter_authority(counter).0, false),
+                ],
+            )
+        }),
+        (0..SIGNERS, 0..SIGNERS, 0..3usize, any::<u64>()).prop_map(
+            |(authority, counter, kind, amount)| {
+                let counter_instruction =
+                    match kind {
+                        0 => CounterInstruction::IncreaseCounterAuthorityBy(
+                            IncreaseCounterAuthorityByArgs { amount },
+                        ),
+                        1 => CounterInstruction::DecreaseCounterAuthority(
+                            DecreaseCounterAuthorityArgs { amount },
+                        ),
+                        _ => CounterInstruction::ResetCounterAuthority(ResetCounterAuthorityArgs {
+                            count: amount,
+                        }),
+                    };
+
+                instruction(
+                    &counter_instruction,
+                    [
+                        AccountMeta::new_readonly(signer(authority).pubkey(), true),
+                        AccountMeta::new(counter_authority(counter).0, false),
+                    ],
+                )
+            },
+        ),
+        // Any signer as the admin, signing or not, refunding a signer or the
+        // counter itself.
+        (0..SIGNERS, any::<bool>(), any::<bool>()).prop_map({
+            let counter = counter.clone();
+
+            move |(admin, signed, into_counter)| {
+                let destination = if into_counter {
+                    counter.pubkey
+                } else {
+                    signer(admin).pubkey()
+                };
+
+                instruction(
+                    &CounterInstruction::CloseCounter,
+                    [
+                        AccountMeta::new_readonly(signer(admin).pubkey(), signed),
+                        counter.clone(),
+                        AccountMeta::new(destination, false),
+                        AccountMeta::new_readonly(ProgramData::derive().0, false),
+                    ],
+                )
+            }
+        }),
+        // Refunds go to a signer, or to a counter authority to close it into.
+        (0..SIGNERS, 0..SIGNERS, any::<bool>()).prop_map(|(authority, counter, into_counter)| {
+            let destination = if into_counter {
+                counter_authority(counter).0
+            } else {
+                signer(authority).pubkey()
+            };
+
+            instruction(
+                &CounterInstruction::CloseCounterAuthority,
+                [
+                    AccountMeta::new_readonly(signer(authority).pubkey(), true),
+                    AccountMeta::new(counter_authority(counter).0, false),
+                    AccountMeta::new(destination, false),
+                ],
+            )
+        }),
+    ]
+    .boxed()
+}
+
+/// `instruction` over `accounts`, followed by the event authority and the
+/// program with `cpi-events`.
+fn instruction(
+    instruction: &CounterInstruction,
+    accounts: impl IntoIterator<Item = AccountMeta>,
+) -> Instruction {
+    #[cfg(feature = "cpi-events")]
+    let accounts = accounts.into_iter().chain([
+        AccountMeta::new_readonly(EventAuthority::derive().0, false),
+        AccountMeta::new_readonly(ID.into(), false),
+    ]);
+
+    Instruction {
+        program_id: ID.into(),
+        accounts: accounts.into_iter().collect(),
+        data: borsh::to_vec(instruction).unwrap(),
+    }
+}
+
+/// Asserts the invariants of every program, and that counts only move by what
+/// the instruction asks, never above their maximum, while counter authorities
+/// keep their authority, every counter its maximum, and only the close
+/// instructions close them, the counter only when the admin signs.
+#[track_caller]
+pub fn assert_invariants(run: &Run) {
+    run.assert_invariants(&ID.into());
+
+    let instruction = CounterInstruction::try_from_slice(&run.case.instruction.data).ok();
+
+    let close = matches!(
+        instruction,
+        Some(CounterInstruction::CloseCounter | CounterInstruction::CloseCounterAuthority)
+    );
+
+    for (address, before) in &run.before {
+        let Some((count, max_count, authority)) = state(before) else {
+            continue;
+        };
+
+        let Some((new_count, new_max_count, new_authority)) = state(&run.after[address]) else {
+            assert!(close, "{address} stopped being a counter: {:?}", run.case);
+
+            if authority.is_none() {
+                let admin = &run.case.instruction.accounts[0];
+
+                assert!(
+                    admin.is_signer && admin.pubkey == signer(ADMIN).pubkey(),
+                    "{address} closed without the admin: {:?}",
+                    run.case
+                );
+            }
+            continue;
+        };
+
+        assert!(
+            new_count == count || updated(instruction.as_ref(), count) == Some(new_count),
+            "count of {address} went from {count} to {new_count}: {:?}",
+            run.case
+        );
+
+        // Counts preset above their maximum may still come down.
+        assert!(
+            new_count <= count || new_count <= max_count,
+            "count of {address} went above its maximum of {max_count}: {:?}",
+            run.case
+        );
+
+        assert_eq!(
+            new_max_count, max_count,
+            "maximum count of {address} changed: {:?}",
+            run.case
+        );
+
+        assert_eq!(
+            new_authority, authority,
+            "authority of {address} changed: {:?}",
+            run.case
+        );
+    }
+}
+
+/// The count `instruction` moves `count` to, if any.
+fn updated(instruction: Option<&CounterInstruction>, count: u64) -> Option<u64> {
+    match instruction? {
+        CounterInstruction::IncreaseCounter | CounterInstruction::IncreaseCounterAuthority => {
+            count.checked_add(1)
+        }
+        CounterInstruction::IncreaseCounterBy(IncreaseCounterByArgs { amount })
+        | CounterInstruction::IncreaseCounterAuthorityBy(IncreaseCounterAuthorityByArgs {
+            amount,
+        }) => count.checked_add(*amount),
+        CounterInstruction::DecreaseCounter(DecreaseCounterArgs { amount })
+        | CounterInstruction::DecreaseCounterAuthority(DecreaseCounterAuthorityArgs { amount }) => {
+            count.checked_sub(*amount)
+        }
+        CounterInstruction::ResetCounter(ResetCounterArgs { count })
+        | CounterInstruction::ResetCounterAuthority(ResetCounterAuthorityArgs { count }) => {
+            Some(*count)
+        }
+        _ => None,
+    }
+}
+
+/// The count, the maximum count and, for a counter authority, the authority
+/// of a counter account of the program.
+fn state(account: &Option<Account>) -> Option<(u64, u64, Option<Address>)> {
+    let account = account
+        .as_ref()
+        .filter(|account| account.owner == Address::from(ID))?;
+
+    if Counter::is_discriminated(&account.data) {
+        let counter = Counter::try_from_slice(&account.data).ok()?;
+
+        Some((counter.count(), counter.max_count(), None))
+    } else if CounterAuthority::is_discriminated(&account.data) {
+        let counter = CounterAuthority::try_from_slice(&account.data).ok()?;
+
+        Some((
+            counter.count(),
+            counter.max_count(),
+            Some(counter.authority),
+        ))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_utils::fuzz::{cases, check_cases};
+
+    use super::{assert_invariants, set_up, target};
+    use crate::test_helpers::fixture;
+
+    #[test]
+    fn keeps_invariants() {
+        let mut fixture = fixture();
+        set_up(&mut fixture);
+
+        check_cases(fixture, cases(target()), assert_invariants);
+    }
+}
@@ -0,0 +1,486 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shared::{account::close, discriminator::Discriminated, syscalls};
+
+use crate::{
+    accounts::{Counter, ProgramData},
+    errors::CounterError,
+    events::{emit_event, CounterClosed},
+};
+
+/// Closes the counter for the program admin, the program's upgrade authority,
+/// refunding its rent to `destination`.
+pub fn close_counter(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [admin, counter, destination, program_data, event_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_admin(admin, program_data)?;
+
+    if counter.lamports().eq(&0) {
+        return Err(CounterError::CounterNotInitialized.into());
+    }
+
+    let (pda, _) = Counter::derive();
+
+    if counter.address().ne(&pda) {
+        return Err(CounterError::InvalidCounterAddress.into());
+    }
+
+    if !Counter::is_discriminated(&counter.try_borrow()?) {
+        return Err(CounterError::AccountDiscriminatorMismatch.into());
+    }
+
+    let lamports = close(counter, destination, CounterError::InvalidDestination)?;
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterClosed {
+        counter: *counter.address(),
+        admin: *admin.address(),
+        destination: *destination.address(),
+        lamports,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
+
+    Ok(())
+}
+
+/// Checks that `admin` signed and is the upgrade authority recorded in
+/// `program_data`, the program data account of this program. Shared by the
+/// instructions reserved to the program admin, whose tests of these checks
+/// are here.
+pub(super) fn check_admin(admin: &AccountView, program_data: &AccountView) -> ProgramResult {
+    if !admin.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (pda, _) = ProgramData::derive();
+
+    if program_data.address().ne(&pda) {
+        return Err(CounterError::InvalidProgramData.into());
+    }
+
+    if !program_data.owned_by(&ProgramData::LOADER) {
+        return Err(CounterError::InvalidProgramData.into());
+    }
+
+    if ProgramData::upgrade_authority(&program_data.try_borrow()?) != Some(*admin.address()) {
+        return Err(CounterError::AdminMismatch.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::{
+        account::Account, instruction::Instruction, message::AccountMeta, signature::Keypair,
+        signer::Signer,
+    };
+    use test_utils::Fixture;
+
+    use shared::discriminator::Discriminated;
+
+    use crate::{
+        accounts::{Counter, CounterAuthority, ProgramData},
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{
+            counter_ix, decode_events, fixture, set_counter, set_program_admin, SLOT, TIMESTAMP,
+        },
+        CounterInstruction, InitCounterArgs, ID,
+    };
+
+    struct Setup {
+        fixture: Fixture,
+        admin: Keypair,
+        counter: Address,
+        destination: Address,
+    }
+
+    /// A counter at 19 of 30, and an admin who is not the fixture payer, so
+    /// tests can leave its signature out.
+    fn setup() -> Setup {
+        let mut fixture = fixture();
+
+        let admin = fixture.funded_payer();
+        set_program_admin(&mut fixture, Some(admin.pubkey()));
+
+        let counter = set_counter(&mut fixture, 19, 30);
+
+        Setup {
+            fixture,
+            admin,
+            counter,
+            destination: Keypair::new().pubkey(),
+        }
+    }
+
+    fn close_counter_ix(admin: Address, counter: Address, destination: Address) -> Instruction {
+        counter_ix(
+            &CounterInstruction::CloseCounter,
+            &[
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(counter, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(ProgramData::derive().0, false),
+            ],
+        )
+    }
+
+    fn init_counter_ix(payer: Address, count: u64) -> Instruction {
+        counter_ix(
+            &CounterInstruction::InitCounter(InitCounterArgs {
+                count,
+                max_count: None,
+            }),
+            &[
+                AccountMeta::new(payer, true),
+                AccountMeta::new(Counter::derive().0, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+        )
+    }
+
+    #[test]
+    fn close_counter() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        let refund = fixture.balance(&counter);
+
+        let outcome = fixture.send_signed(
+            &[close_counter_ix(admin.pubkey(), counter, destination)],
+            &[&admin],
+        );
+        outcome.assert_success();
+
+        let [CounterEvent::CounterClosed(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterClosed event");
+        };
+
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.admin, admin.pubkey());
+        assert_eq!(event.destination, destination);
+        assert_eq!(event.lamports, refund);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        assert!(fixture.account(&counter).is_none());
+        assert_eq!(fixture.balance(&destination), refund);
+    }
+
+    #[test]
+    fn close_counter_then_init_counter() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(admin.pubkey(), counter, destination)],
+                &[&admin],
+            )
+            .assert_success();
+
+        let payer = fixture.payer.pubkey();
+
+        fixture.send(init_counter_ix(payer, 3)).assert_success();
+
+        // Nothing of the closed counter survives, its maximum included.
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 3);
+        assert_eq!(counter_data.max_count(), u64::MAX);
+    }
+
+    #[test]
+    fn close_counter_and_init_counter_in_one_transaction() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        let payer = fixture.payer.pubkey();
+
+        fixture
+            .send_signed(
+                &[
+                    close_counter_ix(admin.pubkey(), counter, destination),
+                    init_counter_ix(payer, 3),
+                ],
+                &[&admin],
+            )
+            .assert_success();
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 3);
+        assert_eq!(counter_data.max_count(), u64::MAX);
+    }
+
+    #[test]
+    fn close_counter_twice() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        let ix = || close_counter_ix(admin.pubkey(), counter, destination);
+
+        fixture.send_signed(&[ix()], &[&admin]).assert_success();
+
+        fixture
+            .send_signed(&[ix()], &[&admin])
+            .assert_error(CounterError::CounterNotInitialized);
+    }
+
+    #[test]
+    fn close_counter_without_signer() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        let mut ix = close_counter_ix(admin.pubkey(), counter, destination);
+        ix.accounts[0].is_signer = false;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn close_counter_by_another_signer() {
+        let Setup {
+            mut fixture,
+            counter,
+            destination,
+            ..
+        } = setup();
+
+        let attacker = fixture.funded_payer();
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(attacker.pubkey(), counter, destination)],
+                &[&attacker],
+            )
+            .assert_error(CounterError::AdminMismatch);
+    }
+
+    #[test]
+    fn close_counter_of_immutable_program() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        set_program_admin(&mut fixture, None);
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(admin.pubkey(), counter, destination)],
+                &[&admin],
+            )
+            .assert_error(CounterError::AdminMismatch);
+    }
+
+    #[test]
+    fn close_counter_with_wrong_program_data() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        // The upgrade authority of another program.
+        let other = Keypair::new().pubkey();
+        let account = fixture.account(&ProgramData::derive().0).unwrap();
+        fixture.set_account(other, account);
+
+        let mut ix = close_counter_ix(admin.pubkey(), counter, destination);
+        ix.accounts[3].pubkey = other;
+
+        fixture
+            .send_signed(&[ix], &[&admin])
+            .assert_error(CounterError::InvalidProgramData);
+    }
+
+    #[test]
+    fn close_counter_with_program_data_not_owned_by_loader() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        let program_data = ProgramData::derive().0;
+        let mut account = fixture.account(&program_data).unwrap();
+        account.owner = Keypair::new().pubkey();
+        fixture.set_account(program_data, account);
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(admin.pubkey(), counter, destination)],
+                &[&admin],
+            )
+            .assert_error(CounterError::InvalidProgramData);
+    }
+
+    #[test]
+    fn close_counter_not_initialized() {
+        let Setup {
+            mut fixture,
+            admin,
+            destination,
+            ..
+        } = setup();
+
+        let counter = Keypair::new().pubkey();
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(admin.pubkey(), counter, destination)],
+                &[&admin],
+            )
+            .assert_error(CounterError::CounterNotInitialized);
+    }
+
+    #[test]
+    fn close_counter_with_wrong_address() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        let fake_counter = Keypair::new().pubkey();
+        let account = fixture.account(&counter).unwrap();
+        fixture.set_account(fake_counter, account);
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(admin.pubkey(), fake_counter, destination)],
+                &[&admin],
+            )
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn close_counter_with_counter_authority_data() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        fixture.set_borsh_account(
+            counter,
+            ID.into(),
+            &CounterAuthority {
+                discriminator: CounterAuthority::DISCRIMINATOR,
+                authority: admin.pubkey(),
+                bump: Counter::derive().1,
+                count: 19u64.to_le_bytes(),
+                max_count: u64::MAX.to_le_bytes(),
+            },
+        );
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(admin.pubkey(), counter, destination)],
+                &[&admin],
+            )
+            .assert_error(CounterError::AccountDiscriminatorMismatch);
+    }
+
+    #[test]
+    fn close_counter_into_itself() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            ..
+        } = setup();
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(admin.pubkey(), counter, counter)],
+                &[&admin],
+            )
+            .assert_error(CounterError::InvalidDestination);
+    }
+
+    #[test]
+    fn close_counter_overflowing_destination() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        fixture.set_account(
+            destination,
+            Account {
+                lamports: u64::MAX,
+                data: Vec::new(),
+                owner: solana_system_interface::program::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        fixture
+            .send_signed(
+                &[close_counter_ix(admin.pubkey(), counter, destination)],
+                &[&admin],
+            )
+            .assert_error(ProgramError::ArithmeticOverflow);
+    }
+
+    #[test]
+    fn close_counter_with_missing_accounts() {
+        let Setup {
+            mut fixture,
+            admin,
+            counter,
+            destination,
+        } = setup();
+
+        let mut ix = close_counter_ix(admin.pubkey(), counter, destination);
+        ix.accounts.truncate(3);
+
+        fixture
+            .send_signed(&[ix], &[&admin])
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+}
@@ -0,0 +1,346 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shared::{
+    account::{close, ZeroCopy},
+    discriminator::Discriminated,
+    syscalls,
+};
+
+use crate::{
+    accounts::CounterAuthority,
+    errors::CounterError,
+    events::{emit_event, CounterAuthorityClosed},
+};
+
+/// Closes the counter of `authority`, refunding its rent to `destination`.
+pub fn close_counter_authority(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, counter, destination, event_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if counter.lamports().eq(&0) {
+        return Err(CounterError::CounterNotInitialized.into());
+    }
+
+    let (pda, _) = CounterAuthority::derive(authority.address());
+
+    if counter.address().ne(&pda) {
+        return Err(CounterError::InvalidCounterAddress.into());
+    }
+
+    let data = counter.try_borrow()?;
+
+    if !CounterAuthority::is_discriminated(&data) {
+        return Err(CounterError::AccountDiscriminatorMismatch.into());
+    }
+
+    let counter_data =
+        CounterAuthority::load(&data).map_err(|_| CounterError::InvalidCounterData)?;
+
+    if counter_data.authority.ne(authority.address()) {
+        return Err(CounterError::AuthorityMismatch.into());
+    }
+
+    drop(data);
+
+    let lamports = close(counter, destination, CounterError::InvalidDestination)?;
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterAuthorityClosed {
+        counter: *counter.address(),
+        authority: *authority.address(),
+        destination: *destination.address(),
+        lamports,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::{
+        instruction::Instruction, message::AccountMeta, signature::Keypair, signer::Signer,
+    };
+
+    use shared::discriminator::Discriminated;
+
+    use crate::{
+        accounts::{Counter, CounterAuthority},
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{
+            counter_ix, decode_events, fixture, set_counter_authority, SLOT, TIMESTAMP,
+        },
+        CounterInstruction, IncreaseCounterAuthorityByArgs, InitCounterAuthorityArgs, ID,
+    };
+
+    fn close_counter_authority_ix(
+        authority: Address,
+        counter: Address,
+        destination: Address,
+    ) -> Instruction {
+        counter_ix(
+            &CounterInstruction::CloseCounterAuthority,
+            &[
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new(counter, false),
+                AccountMeta::new(destination, false),
+            ],
+        )
+    }
+
+    fn init_counter_authority_ix(payer: Address, count: u64) -> Instruction {
+        counter_ix(
+            &CounterInstruction::InitCounterAuhthority(InitCounterAuthorityArgs {
+                count,
+                max_count: None,
+            }),
+            &[
+                AccountMeta::new(payer, true),
+                AccountMeta::new(CounterAuthority::derive(&payer).0, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+        )
+    }
+
+    #[test]
+    fn close_counter_authority() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+        let destination = Keypair::new().pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+        let refund = fixture.balance(&counter);
+
+        let outcome = fixture.send(close_counter_authority_ix(payer, counter, destination));
+        outcome.assert_success();
+
+        let [CounterEvent::CounterAuthorityClosed(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterAuthorityClosed event");
+        };
+
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.authority, payer);
+        assert_eq!(event.destination, destination);
+        assert_eq!(event.lamports, refund);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        assert!(fixture.account(&counter).is_none());
+        assert_eq!(fixture.balance(&destination), refund);
+    }
+
+    #[test]
+    fn close_counter_authority_then_init_counter_authority() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, 30);
+
+        fixture
+            .send(close_counter_authority_ix(payer, counter, payer))
+            .assert_success();
+
+        fixture
+            .send(init_counter_authority_ix(payer, 3))
+            .assert_success();
+
+        // Nothing of the closed counter survives, its maximum included.
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.authority, payer);
+        assert_eq!(counter_data.count(), 3);
+        assert_eq!(counter_data.max_count(), u64::MAX);
+    }
+
+    #[test]
+    fn close_counter_authority_and_init_counter_authority_in_one_transaction() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, 30);
+
+        fixture
+            .send_signed(
+                &[
+                    close_counter_authority_ix(payer, counter, payer),
+                    init_counter_authority_ix(payer, 3),
+                ],
+                &[],
+            )
+            .assert_success();
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 3);
+        assert_eq!(counter_data.max_count(), u64::MAX);
+    }
+
+    #[test]
+    fn close_counter_authority_then_increase() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let increase_ix = counter_ix(
+            &CounterInstruction::IncreaseCounterAuthorityBy(IncreaseCounterAuthorityByArgs {
+                amount: 1,
+            }),
+            &[
+                AccountMeta::new_readonly(payer, true),
+                AccountMeta::new(counter, false),
+            ],
+        );
+
+        fixture
+            .send_signed(
+                &[
+                    close_counter_authority_ix(payer, counter, payer),
+                    increase_ix,
+                ],
+                &[],
+            )
+            .assert_error(CounterError::CounterNotInitialized);
+
+        // The failed transaction left the counter open.
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn close_counter_authority_without_signer() {
+        let mut fixture = fixture();
+        let authority = Keypair::new().pubkey();
+
+        let counter = set_counter_authority(&mut fixture, authority, 19, u64::MAX);
+
+        let mut ix = close_counter_authority_ix(authority, counter, authority);
+        ix.accounts[0].is_signer = false;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn close_counter_authority_not_initialized() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let (counter, _) = CounterAuthority::derive(&payer);
+
+        fixture
+            .send(close_counter_authority_ix(payer, counter, payer))
+            .assert_error(CounterError::CounterNotInitialized);
+    }
+
+    #[test]
+    fn close_counter_authority_by_another_signer() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+        let other = Keypair::new();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        // The counter is not the PDA of the signer.
+        fixture
+            .send_signed(
+                &[close_counter_authority_ix(
+                    other.pubkey(),
+                    counter,
+                    other.pubkey(),
+                )],
+                &[&other],
+            )
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn close_counter_authority_with_counter_data() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let (counter, bump) = CounterAuthority::derive(&payer);
+
+        fixture.set_borsh_account(
+            counter,
+            ID.into(),
+            &Counter {
+                discriminator: Counter::DISCRIMINATOR,
+                bump,
+                count: 19u64.to_le_bytes(),
+                max_count: u64::MAX.to_le_bytes(),
+            },
+        );
+
+        fixture
+            .send(close_counter_authority_ix(payer, counter, payer))
+            .assert_error(CounterError::AccountDiscriminatorMismatch);
+    }
+
+    #[test]
+    fn close_counter_authority_with_invalid_data() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let mut account = fixture.account(&counter).unwrap();
+        account.data.push(0);
+        fixture.set_account(counter, account);
+
+        fixture
+            .send(close_counter_authority_ix(payer, counter, payer))
+            .assert_error(CounterError::InvalidCounterData);
+    }
+
+    #[test]
+    fn close_counter_authority_with_wrong_authority() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let (counter, bump) = CounterAuthority::derive(&payer);
+
+        fixture.set_borsh_account(
+            counter,
+            ID.into(),
+            &CounterAuthority {
+                discriminator: CounterAuthority::DISCRIMINATOR,
+                authority: Keypair::new().pubkey(),
+                bump,
+                count: 19u64.to_le_bytes(),
+                max_count: u64::MAX.to_le_bytes(),
+            },
+        );
+
+        fixture
+            .send(close_counter_authority_ix(payer, counter, payer))
+            .assert_error(CounterError::AuthorityMismatch);
+    }
+
+    #[test]
+    fn close_counter_authority_with_missing_accounts() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let mut ix = close_counter_authority_ix(payer, counter, payer);
+        ix.accounts.truncate(2);
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+}
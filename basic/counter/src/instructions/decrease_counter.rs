@@ -0,0 +1,150 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shank::ShankType;
+use shared::syscalls;
+
+use super::{close_counter::check_admin, increase_counter::update_counter};
+use crate::events::{emit_event, CounterDecreased};
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct DecreaseCounterArgs {
+    pub amount: u64,
+}
+
+/// Lowers the count for the program admin, failing rather than going below
+/// zero.
+pub fn decrease_counter(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: DecreaseCounterArgs,
+) -> ProgramResult {
+    let [admin, counter, program_data, event_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_admin(admin, program_data)?;
+
+    let new_count = update_counter(counter, |counter_data| {
+        counter_data.checked_decrease(args.amount)
+    })?;
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterDecreased {
+        counter: *counter.address(),
+        new_count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::{
+        instruction::Instruction, message::AccountMeta, signature::Keypair, signer::Signer,
+    };
+
+    use crate::{
+        accounts::{Counter, ProgramData},
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{
+            counter_ix, decode_events, fixture, set_counter, set_program_admin, SLOT, TIMESTAMP,
+        },
+        CounterInstruction,
+    };
+
+    use super::DecreaseCounterArgs;
+
+    fn decrease_counter_ix(admin: Address, counter: Address, amount: u64) -> Instruction {
+        counter_ix(
+            &CounterInstruction::DecreaseCounter(DecreaseCounterArgs { amount }),
+            &[
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(ProgramData::derive().0, false),
+            ],
+        )
+    }
+
+    #[test]
+    fn decrease_counter() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        set_program_admin(&mut fixture, Some(payer));
+        let counter = set_counter(&mut fixture, 19, u64::MAX);
+
+        let outcome = fixture.send(decrease_counter_ix(payer, counter, 19));
+        outcome.assert_success();
+
+        let [CounterEvent::CounterDecreased(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterDecreased event");
+        };
+
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.new_count, 0);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 0);
+    }
+
+    #[test]
+    fn decrease_counter_below_zero() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        set_program_admin(&mut fixture, Some(payer));
+        let counter = set_counter(&mut fixture, 19, u64::MAX);
+
+        fixture
+            .send(decrease_counter_ix(payer, counter, 20))
+            .assert_error(CounterError::CountUnderflow);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn decrease_counter_by_another_signer() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+        let other = Keypair::new();
+
+        set_program_admin(&mut fixture, Some(payer));
+        let counter = set_counter(&mut fixture, 19, u64::MAX);
+
+        fixture
+            .send_signed(
+                &[decrease_counter_ix(other.pubkey(), counter, 1)],
+                &[&other],
+            )
+            .assert_error(CounterError::AdminMismatch);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn decrease_counter_with_missing_accounts() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let mut ix = decrease_counter_ix(payer, Counter::derive().0, 1);
+        ix.accounts.truncate(2);
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+}
@@ -0,0 +1,148 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shank::ShankType;
+use shared::syscalls;
+
+use super::increase_counter_authority::update_counter_authority;
+use crate::events::{emit_event, CounterAuthorityDecreased};
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct DecreaseCounterAuthorityArgs {
+    pub amount: u64,
+}
+
+/// Lowers the count, failing rather than going below zero.
+pub fn decrease_counter_authority(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: DecreaseCounterAuthorityArgs,
+) -> ProgramResult {
+    let [authority, counter, event_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let new_count = update_counter_authority(authority, counter, |counter_data| {
+        counter_data.checked_decrease(args.amount)
+    })?;
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterAuthorityDecreased {
+        counter: *counter.address(),
+        authority: *authority.address(),
+        new_count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::{
+        instruction::Instruction, message::AccountMeta, signature::Keypair, signer::Signer,
+    };
+
+    use crate::{
+        accounts::CounterAuthority,
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{
+            counter_ix, decode_events, fixture, set_counter_authority, SLOT, TIMESTAMP,
+        },
+        CounterInstruction,
+    };
+
+    use super::DecreaseCounterAuthorityArgs;
+
+    fn decrease_counter_authority_ix(
+        authority: Address,
+        counter: Address,
+        amount: u64,
+    ) -> Instruction {
+        counter_ix(
+            &CounterInstruction::DecreaseCounterAuthority(DecreaseCounterAuthorityArgs { amount }),
+            &[
+                AccountMeta::new(authority, true),
+                AccountMeta::new(counter, false),
+            ],
+        )
+    }
+
+    #[test]
+    fn decrease_counter_authority() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let outcome = fixture.send(decrease_counter_authority_ix(payer, counter, 4));
+        outcome.assert_success();
+
+        let [CounterEvent::CounterAuthorityDecreased(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterAuthorityDecreased event");
+        };
+
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.authority, payer);
+        assert_eq!(event.new_count, 15);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 15);
+    }
+
+    #[test]
+    fn decrease_counter_authority_below_zero() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        fixture
+            .send(decrease_counter_authority_ix(payer, counter, 20))
+            .assert_error(CounterError::CountUnderflow);
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn decrease_counter_authority_by_another_signer() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+        let other = Keypair::new();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        // The counter is not the PDA of the signer.
+        fixture
+            .send_signed(
+                &[decrease_counter_authority_ix(other.pubkey(), counter, 1)],
+                &[&other],
+            )
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn decrease_counter_authority_with_missing_accounts() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let mut ix = decrease_counter_authority_ix(payer, counter, 1);
+        ix.accounts.truncate(1);
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+}
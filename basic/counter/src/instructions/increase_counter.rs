@@ -1,108 +1,357 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shank::ShankType;
+#[cfg(not(feature = "borsh-accounts"))]
+use shared::account::ZeroCopy;
+use shared::{discriminator::Discriminated, syscalls};
 
-use crate::accounts::Counter;
+use crate::{
+    accounts::Counter,
+    errors::CounterError,
+    events::{emit_event, CounterIncreased},
+};
 
-pub fn increase_counter(_program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
-    let [counter] = accounts else {
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct IncreaseCounterByArgs {
+    pub amount: u64,
+}
+
+pub fn increase_counter(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    increase_counter_by(program_id, accounts, IncreaseCounterByArgs { amount: 1 })
+}
+
+pub fn increase_counter_by(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: IncreaseCounterByArgs,
+) -> ProgramResult {
+    let [counter, event_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    let new_count = update_counter(counter, |counter_data| {
+        counter_data.checked_increase(args.amount)
+    })?;
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterIncreased {
+        counter: *counter.address(),
+        new_count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
+
+    Ok(())
+}
+
+/// Checks that `counter` is the initialized counter PDA, then sets its count
+/// to the one `update` returns for it. Shared by the instructions changing
+/// the count, whose tests of these checks are here.
+pub(super) fn update_counter(
+    counter: &AccountView,
+    update: impl FnOnce(&Counter) -> Result<u64, ProgramError>,
+) -> Result<u64, ProgramError> {
     if counter.lamports().eq(&0) {
-        return Err(ProgramError::UninitializedAccount);
+        return Err(CounterError::CounterNotInitialized.into());
     }
 
     let (pda, _) = Counter::derive();
 
     if counter.address().ne(&pda) {
-        return Err(ProgramError::InvalidSeeds);
+        return Err(CounterError::InvalidCounterAddress.into());
     }
 
-    let mut counter_data = Counter::try_from_slice(&counter.try_borrow()?)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let mut data = counter.try_borrow_mut()?;
 
-    counter_data.count += 1;
+    if !Counter::is_discriminated(&data) {
+        return Err(CounterError::AccountDiscriminatorMismatch.into());
+    }
 
-    counter_data
-        .serialize(&mut counter.try_borrow_mut()?.as_mut())
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    #[cfg(not(feature = "borsh-accounts"))]
+    let new_count = {
+        let counter_data =
+            Counter::load_mut(&mut data).map_err(|_| CounterError::InvalidCounterData)?;
 
-    Ok(())
+        let new_count = update(counter_data)?;
+        counter_data.set_count(new_count);
+        new_count
+    };
+
+    // Borsh round-trip, only built as the baseline for `increase_counter_cost_against_borsh`.
+    #[cfg(feature = "borsh-accounts")]
+    let new_count = {
+        let mut counter_data =
+            Counter::try_from_slice(&data).map_err(|_| CounterError::InvalidCounterData)?;
+
+        let new_count = update(&counter_data)?;
+        counter_data.set_count(new_count);
+
+        counter_data
+            .serialize(&mut &mut data[..])
+            .map_err(|_| CounterError::InvalidCounterData)?;
+
+        new_count
+    };
+
+    Ok(new_count)
 }
 
 #[cfg(test)]
 mod test {
     extern crate std;
 
-    use borsh::BorshDeserialize;
-    use litesvm::LiteSVM;
-    use pinocchio::Address;
+    use pinocchio::{error::ProgramError, Address};
     use solana_sdk::{
-        account::Account,
-        message::{AccountMeta, Instruction},
-        native_token::LAMPORTS_PER_SOL,
+        instruction::{Instruction, InstructionError},
+        message::AccountMeta,
         signature::Keypair,
         signer::Signer,
-        transaction::Transaction,
+    };
+    #[cfg(feature = "sbf")]
+    use test_utils::{Fixture, StackUsage};
+
+    use shared::discriminator::Discriminated;
+
+    use crate::{
+        accounts::{Counter, CounterAuthority},
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{counter_ix, decode_events, fixture, set_counter, SLOT, TIMESTAMP},
+        CounterInstruction, IncreaseCounterByArgs, ID,
     };
 
-    use crate::{accounts::Counter, CounterInstruction, ID};
+    fn increase_counter_ix(counter: Address) -> Instruction {
+        counter_ix(
+            &CounterInstruction::IncreaseCounter,
+            &[AccountMeta::new(counter, false)],
+        )
+    }
 
     #[test]
     fn increase_counter() {
-        let mut svm = LiteSVM::new();
+        let mut fixture = fixture();
+
+        let counter = set_counter(&mut fixture, 19, u64::MAX);
+
+        let outcome = fixture.send(increase_counter_ix(counter));
+        outcome.assert_success();
+
+        let [CounterEvent::CounterIncreased(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterIncreased event");
+        };
+
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.new_count, 20);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 20);
+    }
+
+    #[test]
+    fn increase_counter_by() {
+        let mut fixture = fixture();
+
+        let counter = set_counter(&mut fixture, 19, 30);
+
+        let ix = counter_ix(
+            &CounterInstruction::IncreaseCounterBy(IncreaseCounterByArgs { amount: 11 }),
+            &[AccountMeta::new(counter, false)],
+        );
+
+        let outcome = fixture.send(ix);
+        outcome.assert_success();
+
+        let [CounterEvent::CounterIncreased(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterIncreased event");
+        };
+
+        assert_eq!(event.new_count, 30);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 30);
+    }
+
+    #[test]
+    fn increase_counter_at_u64_max() {
+        let mut fixture = fixture();
+
+        let counter = set_counter(&mut fixture, u64::MAX, u64::MAX);
+
+        fixture
+            .send(increase_counter_ix(counter))
+            .assert_error(CounterError::CountOverflow);
 
-        let payer = Keypair::new();
+        let counter_data: Counter = fixture.borsh_account(&counter);
 
-        let program_id = Address::new_from_array(ID);
+        assert_eq!(counter_data.count(), u64::MAX);
+    }
+
+    #[test]
+    fn increase_counter_at_max_count() {
+        let mut fixture = fixture();
+
+        let counter = set_counter(&mut fixture, 19, 19);
 
-        svm.add_program_from_file(program_id, "../../target/deploy/counter.so")
-            .unwrap();
+        fixture
+            .send(increase_counter_ix(counter))
+            .assert_error(CounterError::MaxCountExceeded);
 
-        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn increase_counter_not_initialized() {
+        let mut fixture = fixture();
 
         let (counter, _) = Counter::derive();
 
-        svm.set_account(
+        fixture
+            .send(increase_counter_ix(counter))
+            .assert_error(CounterError::CounterNotInitialized);
+    }
+
+    #[test]
+    fn increase_counter_with_counter_authority_data() {
+        let mut fixture = fixture();
+
+        let (counter, bump) = Counter::derive();
+
+        fixture.set_borsh_account(
             counter,
-            Account {
-                data: borsh::to_vec(&Counter {
-                    count: 19,
-                    bump: 254,
-                })
-                .unwrap(),
-                executable: false,
-                lamports: LAMPORTS_PER_SOL,
-                owner: ID.into(),
-                rent_epoch: 0,
+            ID.into(),
+            &CounterAuthority {
+                discriminator: CounterAuthority::DISCRIMINATOR,
+                authority: fixture.payer.pubkey(),
+                bump,
+                count: 19u64.to_le_bytes(),
+                max_count: u64::MAX.to_le_bytes(),
             },
-        )
-        .unwrap();
+        );
+
+        fixture
+            .send(increase_counter_ix(counter))
+            .assert_error(CounterError::AccountDiscriminatorMismatch);
+    }
 
-        let ix_data = CounterInstruction::IncreaseCounter;
+    /// Runs one `IncreaseCounter` against the counter built with `features`
+    /// on top of those of this test run, returning the CU and stack it used.
+    #[cfg(feature = "sbf")]
+    fn increase_counter_cost(features: &[&'static str]) -> (u64, StackUsage) {
+        let mut all = crate::test_helpers::features();
+        all.extend(features);
 
-        let ix = Instruction {
-            program_id,
-            accounts: [AccountMeta::new(counter, false)].to_vec(),
-            data: borsh::to_vec(&ix_data).unwrap(),
-        };
+        let mut fixture = Fixture::builder()
+            .program_with_features(ID, "counter", crate::process, &all)
+            .build();
+
+        let counter = set_counter(&mut fixture, 19, u64::MAX);
+
+        let outcome = fixture.send(increase_counter_ix(counter));
+        outcome.assert_success();
+
+        (outcome.compute_units(), outcome.stack_usage())
+    }
+
+    #[test]
+    #[cfg(all(feature = "sbf", not(feature = "borsh-accounts")))]
+    fn increase_counter_cost_against_borsh() {
+        let (zero_copy_cu, zero_copy_stack) = increase_counter_cost(&[]);
+        let (borsh_cu, borsh_stack) = increase_counter_cost(&["borsh-accounts"]);
+
+        std::println!("IncreaseCounter zero-copy: {zero_copy_cu} CU, {zero_copy_stack:?}");
+        std::println!("IncreaseCounter borsh: {borsh_cu} CU, {borsh_stack:?}");
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&payer.pubkey()),
-            &[&payer],
-            svm.latest_blockhash(),
+        assert!(
+            zero_copy_cu < borsh_cu,
+            "zero-copy ({zero_copy_cu} CU) should be cheaper than borsh ({borsh_cu} CU)"
         );
+        assert!(zero_copy_stack.peak <= borsh_stack.peak);
+    }
+
+    #[test]
+    #[cfg(all(feature = "sbf", not(feature = "base64-events")))]
+    fn increase_counter_cost_log_data_against_base64() {
+        let (log_data_cu, log_data_stack) = increase_counter_cost(&[]);
+        let (base64_cu, base64_stack) = increase_counter_cost(&["base64-events"]);
+
+        std::println!("IncreaseCounter sol_log_data: {log_data_cu} CU, {log_data_stack:?}");
+        std::println!("IncreaseCounter base64: {base64_cu} CU, {base64_stack:?}");
 
-        let result = svm.send_transaction(tx).unwrap();
+        assert!(
+            log_data_cu < base64_cu,
+            "sol_log_data ({log_data_cu} CU) should be cheaper than base64 ({base64_cu} CU)"
+        );
+        assert!(
+            log_data_stack.peak < base64_stack.peak,
+            "sol_log_data ({log_data_stack:?}) should use less stack than base64 ({base64_stack:?})"
+        );
+    }
 
-        std::println!("Program executed successfully!");
-        std::println!("Transaction logs: {:#?}", result.logs);
+    #[test]
+    fn increase_counter_with_missing_accounts() {
+        let mut fixture = fixture();
+
+        let mut ix = increase_counter_ix(Counter::derive().0);
+        ix.accounts.clear();
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+
+    #[test]
+    fn increase_counter_with_wrong_address() {
+        let mut fixture = fixture();
+
+        let counter = set_counter(&mut fixture, 19, u64::MAX);
+        let fake_counter = Keypair::new().pubkey();
+
+        let account = fixture.account(&counter).unwrap();
+        fixture.set_account(fake_counter, account);
+
+        fixture
+            .send(increase_counter_ix(fake_counter))
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn increase_counter_with_invalid_data() {
+        let mut fixture = fixture();
+
+        let counter = set_counter(&mut fixture, 19, u64::MAX);
+
+        let mut account = fixture.account(&counter).unwrap();
+        account.data.push(0);
+        fixture.set_account(counter, account);
+
+        fixture
+            .send(increase_counter_ix(counter))
+            .assert_error(CounterError::InvalidCounterData);
+    }
+
+    #[test]
+    fn increase_counter_not_owned_by_program() {
+        let mut fixture = fixture();
 
-        let counter = svm.get_account(&counter).unwrap();
+        let counter = set_counter(&mut fixture, 19, u64::MAX);
 
-        let counter_data = Counter::deserialize(&mut counter.data.as_ref()).unwrap();
+        let mut account = fixture.account(&counter).unwrap();
+        account.owner = Keypair::new().pubkey();
+        fixture.set_account(counter, account);
 
-        assert_eq!(counter_data.count, 20);
+        // The counter trusts the runtime to reject writes to accounts it
+        // does not own.
+        fixture
+            .send(increase_counter_ix(counter))
+            .assert_instruction_error(InstructionError::ExternalAccountDataModified);
     }
 }
@@ -1,134 +1,407 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
-use shared::event::emit;
+use shank::ShankType;
+#[cfg(not(feature = "borsh-accounts"))]
+use shared::account::ZeroCopy;
+use shared::{discriminator::Discriminated, syscalls};
 
 use crate::{
     accounts::CounterAuthority,
-    events::{CounterAuthorityIncreased, CounterEvent},
+    errors::CounterError,
+    events::{emit_event, CounterAuthorityIncreased},
 };
 
-pub fn increase_counter_authority(
-    _program_id: &Address,
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct IncreaseCounterAuthorityByArgs {
+    pub amount: u64,
+}
+
+pub fn increase_counter_authority(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    increase_counter_authority_by(
+        program_id,
+        accounts,
+        IncreaseCounterAuthorityByArgs { amount: 1 },
+    )
+}
+
+pub fn increase_counter_authority_by(
+    program_id: &Address,
     accounts: &[AccountView],
+    args: IncreaseCounterAuthorityByArgs,
 ) -> ProgramResult {
-    let [authority, counter] = accounts else {
+    let [authority, counter, event_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    let new_count = update_counter_authority(authority, counter, |counter_data| {
+        counter_data.checked_increase(args.amount)
+    })?;
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterAuthorityIncreased {
+        counter: *counter.address(),
+        authority: *authority.address(),
+        new_count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
+
+    Ok(())
+}
+
+/// Checks that `counter` is the initialized counter authority PDA of
+/// `authority`, which signed and is the one it stores, then sets its count to
+/// the one `update` returns for it. Shared by the instructions changing the
+/// count, whose tests of these checks are here.
+pub(super) fn update_counter_authority(
+    authority: &AccountView,
+    counter: &AccountView,
+    update: impl FnOnce(&CounterAuthority) -> Result<u64, ProgramError>,
+) -> Result<u64, ProgramError> {
     if !authority.is_signer() {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
     if counter.lamports().eq(&0) {
-        return Err(ProgramError::UninitializedAccount);
+        return Err(CounterError::CounterNotInitialized.into());
     }
 
     let (pda, _) = CounterAuthority::derive(authority.address());
 
     if counter.address().ne(&pda) {
-        return Err(ProgramError::InvalidSeeds);
+        return Err(CounterError::InvalidCounterAddress.into());
     }
 
-    let mut counter_data = CounterAuthority::try_from_slice(&counter.try_borrow()?)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let mut data = counter.try_borrow_mut()?;
 
-    if counter_data.authority.ne(authority.address()) {
-        return Err(ProgramError::IllegalOwner);
+    if !CounterAuthority::is_discriminated(&data) {
+        return Err(CounterError::AccountDiscriminatorMismatch.into());
     }
 
-    counter_data.count += 1;
+    #[cfg(not(feature = "borsh-accounts"))]
+    let new_count = {
+        let counter_data =
+            CounterAuthority::load_mut(&mut data).map_err(|_| CounterError::InvalidCounterData)?;
+
+        if counter_data.authority.ne(authority.address()) {
+            return Err(CounterError::AuthorityMismatch.into());
+        }
 
-    counter_data
-        .serialize(&mut counter.try_borrow_mut()?.as_mut())
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        let new_count = update(counter_data)?;
+        counter_data.set_count(new_count);
+        new_count
+    };
 
-    let event = CounterEvent::CounterAuthorityIncreased(CounterAuthorityIncreased {
-        new_count: counter_data.count,
-    });
+    // Borsh round-trip, only built as the baseline for `increase_counter_cost_against_borsh`.
+    #[cfg(feature = "borsh-accounts")]
+    let new_count = {
+        let mut counter_data = CounterAuthority::try_from_slice(&data)
+            .map_err(|_| CounterError::InvalidCounterData)?;
 
-    emit(&event)?;
+        if counter_data.authority.ne(authority.address()) {
+            return Err(CounterError::AuthorityMismatch.into());
+        }
 
-    Ok(())
+        let new_count = update(&counter_data)?;
+        counter_data.set_count(new_count);
+
+        counter_data
+            .serialize(&mut &mut data[..])
+            .map_err(|_| CounterError::InvalidCounterData)?;
+
+        new_count
+    };
+
+    Ok(new_count)
 }
 
 #[cfg(test)]
 mod test {
-    extern crate std;
-
-    use borsh::BorshDeserialize;
-    use litesvm::LiteSVM;
-    use pinocchio::Address;
+    use pinocchio::{error::ProgramError, Address};
     use solana_sdk::{
-        account::Account,
-        message::{AccountMeta, Instruction},
-        native_token::LAMPORTS_PER_SOL,
-        signature::Keypair,
-        signer::Signer,
-        transaction::Transaction,
+        account::Account, instruction::Instruction, message::AccountMeta,
+        native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer,
     };
 
-    use crate::{accounts::CounterAuthority, CounterInstruction, ID};
+    use shared::discriminator::Discriminated;
+
+    use crate::{
+        accounts::{Counter, CounterAuthority},
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{
+            counter_ix, decode_events, fixture, set_counter_authority, SLOT, TIMESTAMP,
+        },
+        CounterInstruction, IncreaseCounterAuthorityByArgs, ID,
+    };
+
+    fn increase_counter_authority_ix(authority: Address, counter: Address) -> Instruction {
+        counter_ix(
+            &CounterInstruction::IncreaseCounterAuthority,
+            &[
+                AccountMeta::new(authority, true),
+                AccountMeta::new(counter, false),
+            ],
+        )
+    }
 
     #[test]
     fn increase_counter_authority() {
-        let mut svm = LiteSVM::new();
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
 
-        let payer = Keypair::new();
+        let (counter, _) = CounterAuthority::derive(&payer);
 
-        let program_id = Address::new_from_array(ID);
+        fixture.set_borsh_account(
+            counter,
+            ID.into(),
+            &CounterAuthority {
+                discriminator: CounterAuthority::DISCRIMINATOR,
+                count: 19u64.to_le_bytes(),
+                bump: 254,
+                authority: payer,
+                max_count: u64::MAX.to_le_bytes(),
+            },
+        );
+
+        let outcome = fixture.send(increase_counter_authority_ix(payer, counter));
+        outcome.assert_success();
+
+        let [CounterEvent::CounterAuthorityIncreased(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterAuthorityIncreased event");
+        };
+
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.authority, payer);
+        assert_eq!(event.new_count, 20);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 20);
+    }
+
+    #[test]
+    fn increase_counter_authority_with_wrong_authority() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let (counter, bump) = CounterAuthority::derive(&payer);
 
-        svm.add_program_from_file(program_id, "../../target/deploy/counter.so")
-            .unwrap();
+        fixture.set_borsh_account(
+            counter,
+            ID.into(),
+            &CounterAuthority {
+                discriminator: CounterAuthority::DISCRIMINATOR,
+                count: 19u64.to_le_bytes(),
+                bump,
+                authority: Keypair::new().pubkey(),
+                max_count: u64::MAX.to_le_bytes(),
+            },
+        );
 
-        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        fixture
+            .send(increase_counter_authority_ix(payer, counter))
+            .assert_error(CounterError::AuthorityMismatch);
+    }
 
-        let (counter, _) = CounterAuthority::derive(&payer.pubkey());
+    #[test]
+    fn increase_counter_authority_with_counter_data() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let (counter, bump) = CounterAuthority::derive(&payer);
 
-        svm.set_account(
+        // A `Counter` is a prefix-compatible blob: without the discriminator its
+        // bytes would be read as an authority-less `CounterAuthority`.
+        let mut data = borsh::to_vec(&Counter {
+            discriminator: Counter::DISCRIMINATOR,
+            bump,
+            count: 19u64.to_le_bytes(),
+            max_count: u64::MAX.to_le_bytes(),
+        })
+        .unwrap();
+        data.resize(CounterAuthority::SPACE, 0);
+
+        fixture.set_account(
             counter,
             Account {
-                data: borsh::to_vec(&CounterAuthority {
-                    count: 19,
-                    bump: 254,
-                    authority: payer.pubkey(),
-                })
-                .unwrap(),
+                data,
                 executable: false,
                 lamports: LAMPORTS_PER_SOL,
                 owner: ID.into(),
                 rent_epoch: 0,
             },
-        )
-        .unwrap();
+        );
+
+        fixture
+            .send(increase_counter_authority_ix(payer, counter))
+            .assert_error(CounterError::AccountDiscriminatorMismatch);
+    }
 
-        let ix_data = CounterInstruction::IncreaseCounterAuthority;
+    #[test]
+    fn increase_counter_authority_by() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, 30);
 
-        let ix = Instruction {
-            program_id,
-            accounts: [
-                AccountMeta::new(payer.pubkey(), true),
+        let ix = counter_ix(
+            &CounterInstruction::IncreaseCounterAuthorityBy(IncreaseCounterAuthorityByArgs {
+                amount: 11,
+            }),
+            &[
+                AccountMeta::new(payer, true),
                 AccountMeta::new(counter, false),
-            ]
-            .to_vec(),
-            data: borsh::to_vec(&ix_data).unwrap(),
+            ],
+        );
+
+        let outcome = fixture.send(ix);
+        outcome.assert_success();
+
+        let [CounterEvent::CounterAuthorityIncreased(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterAuthorityIncreased event");
         };
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&payer.pubkey()),
-            &[&payer],
-            svm.latest_blockhash(),
+        assert_eq!(event.authority, payer);
+        assert_eq!(event.new_count, 30);
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 30);
+    }
+
+    #[test]
+    fn increase_counter_authority_by_another_signer() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+        let other = Keypair::new();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let ix = counter_ix(
+            &CounterInstruction::IncreaseCounterAuthorityBy(IncreaseCounterAuthorityByArgs {
+                amount: 1,
+            }),
+            &[
+                AccountMeta::new(other.pubkey(), true),
+                AccountMeta::new(counter, false),
+            ],
         );
 
-        let result = svm.send_transaction(tx).unwrap();
+        // The counter is not the PDA of the signer.
+        fixture
+            .send_signed(&[ix], &[&other])
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn increase_counter_authority_at_u64_max() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, u64::MAX, u64::MAX);
+
+        fixture
+            .send(increase_counter_authority_ix(payer, counter))
+            .assert_error(CounterError::CountOverflow);
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), u64::MAX);
+    }
+
+    #[test]
+    fn increase_counter_authority_at_max_count() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, 19);
+
+        fixture
+            .send(increase_counter_authority_ix(payer, counter))
+            .assert_error(CounterError::MaxCountExceeded);
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
 
-        std::println!("Program executed successfully!");
-        std::println!("Transaction logs: {:#?}", result.logs);
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn increase_counter_authority_without_signer() {
+        let mut fixture = fixture();
+        let authority = Keypair::new().pubkey();
+
+        let counter = set_counter_authority(&mut fixture, authority, 19, u64::MAX);
+
+        let mut ix = increase_counter_authority_ix(authority, counter);
+        ix.accounts[0].is_signer = false;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn increase_counter_authority_not_initialized() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let (counter, _) = CounterAuthority::derive(&payer);
+
+        fixture
+            .send(increase_counter_authority_ix(payer, counter))
+            .assert_error(CounterError::CounterNotInitialized);
+    }
+
+    #[test]
+    fn increase_counter_authority_with_wrong_address() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+        let fake_counter = Keypair::new().pubkey();
+
+        let account = fixture.account(&counter).unwrap();
+        fixture.set_account(fake_counter, account);
+
+        fixture
+            .send(increase_counter_authority_ix(payer, fake_counter))
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn increase_counter_authority_with_invalid_data() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let mut account = fixture.account(&counter).unwrap();
+        account.data.push(0);
+        fixture.set_account(counter, account);
+
+        fixture
+            .send(increase_counter_authority_ix(payer, counter))
+            .assert_error(CounterError::InvalidCounterData);
+    }
+
+    #[test]
+    fn increase_counter_authority_with_missing_accounts() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
 
-        let counter = svm.get_account(&counter).unwrap();
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
 
-        let counter_data = CounterAuthority::deserialize(&mut counter.data.as_ref()).unwrap();
+        let mut ix = increase_counter_authority_ix(payer, counter);
+        ix.accounts.truncate(1);
 
-        assert_eq!(counter_data.count, 20);
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
     }
 }
@@ -1,18 +1,23 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use pinocchio::{
-    cpi::{Seed, Signer},
-    error::ProgramError,
-    sysvars::{rent::Rent, Sysvar},
-    AccountView, Address, ProgramResult,
-};
-use pinocchio_system::instructions::CreateAccount;
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
 use shank::ShankType;
+use shared::{
+    account::ZeroCopy,
+    syscalls,
+    system::{self, CreateAccount},
+};
 
-use crate::accounts::Counter;
+use crate::{
+    accounts::Counter,
+    errors::CounterError,
+    events::{emit_event, CounterInitialized},
+};
 
 #[derive(BorshSerialize, BorshDeserialize, ShankType)]
 pub struct InitCounterArgs {
     pub count: u64,
+    /// The highest count the counter may reach, uncapped when `None`.
+    pub max_count: Option<u64>,
 }
 
 pub fn init_counter(
@@ -20,7 +25,7 @@ pub fn init_counter(
     accounts: &[AccountView],
     args: InitCounterArgs,
 ) -> ProgramResult {
-    let [payer, counter, system_program] = accounts else {
+    let [payer, counter, system_program, event_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -31,29 +36,28 @@ pub fn init_counter(
     let (pda, bump) = Counter::derive();
 
     if counter.address().ne(&pda) {
-        return Err(ProgramError::InvalidSeeds);
+        return Err(CounterError::InvalidCounterAddress.into());
     }
 
     if counter.lamports().ne(&0) {
-        return Err(ProgramError::AccountAlreadyInitialized);
+        return Err(CounterError::CounterAlreadyInitialized.into());
     }
 
-    if system_program.address().ne(&pinocchio_system::ID) {
+    if system_program.address().ne(&system::ID) {
         return Err(ProgramError::IncorrectProgramId);
     };
 
-    let counter_data = Counter {
-        bump,
-        count: args.count,
-    };
+    let max_count = args.max_count.unwrap_or(u64::MAX);
+
+    if args.count > max_count {
+        return Err(CounterError::MaxCountExceeded.into());
+    }
 
     let account_span = Counter::SPACE;
-    let lamports_required = Rent::get()?.minimum_balance_unchecked(account_span);
+    let lamports_required = syscalls::rent()?.minimum_balance_unchecked(account_span);
 
     let bump_bytes = &[bump];
-    let seeds = [Seed::from(Counter::SEED_PREFIX), Seed::from(bump_bytes)];
-
-    let signers = Signer::from(&seeds);
+    let seeds: [&[u8]; 2] = [Counter::SEED_PREFIX, bump_bytes];
 
     CreateAccount {
         from: payer,
@@ -62,72 +66,189 @@ pub fn init_counter(
         space: account_span as u64,
         owner: program_id,
     }
-    .invoke_signed(&[signers])?;
+    .invoke_signed(&[&seeds])?;
+
+    let mut data = counter.try_borrow_mut()?;
+
+    let counter_data =
+        Counter::initialize(&mut data).map_err(|_| CounterError::InvalidCounterData)?;
+
+    counter_data.bump = bump;
+    counter_data.set_count(args.count);
+    counter_data.set_max_count(max_count);
 
-    counter_data
-        .serialize(&mut counter.try_borrow_mut()?.as_mut())
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    drop(data);
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterInitialized {
+        counter: *counter.address(),
+        payer: *payer.address(),
+        count: args.count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
 
     Ok(())
 }
 
 #[cfg(test)]
 mod test {
-    extern crate std;
-
-    use borsh::BorshDeserialize;
-    use litesvm::LiteSVM;
-    use pinocchio::Address;
+    use pinocchio::{error::ProgramError, Address};
     use solana_sdk::{
-        instruction::Instruction, message::AccountMeta, native_token::LAMPORTS_PER_SOL,
-        signature::Keypair, signer::Signer, transaction::Transaction,
+        instruction::Instruction, message::AccountMeta, signature::Keypair, signer::Signer,
     };
 
-    use crate::{accounts::Counter, CounterInstruction, ID};
+    use shared::discriminator::Discriminated;
 
-    #[test]
-    fn init_counter() {
-        let mut svm = LiteSVM::new();
+    use crate::{
+        accounts::Counter,
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{counter_ix, decode_events, fixture, SLOT, TIMESTAMP},
+        CounterInstruction, ID,
+    };
 
-        let payer = Keypair::new();
+    use super::InitCounterArgs;
 
-        let program_id = Address::new_from_array(ID);
+    fn init_counter_ix(payer: Address, counter: Address) -> Instruction {
+        counter_ix(
+            &CounterInstruction::InitCounter(InitCounterArgs {
+                count: 19,
+                max_count: None,
+            }),
+            &[
+                AccountMeta::new(payer, true),
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+        )
+    }
 
-        svm.add_program_from_file(program_id, "../../target/deploy/counter.so")
-            .unwrap();
-        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+    #[test]
+    fn init_counter() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
 
         let (counter, _) = Counter::derive();
 
-        let ix_data = CounterInstruction::InitCounter(super::InitCounterArgs { count: 19 });
+        let outcome = fixture.send(init_counter_ix(payer, counter));
+        outcome.assert_success();
 
-        let ix = Instruction {
-            program_id,
-            accounts: [
-                AccountMeta::new(payer.pubkey(), true),
-                AccountMeta::new(counter, false),
-                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
-            ]
-            .to_vec(),
-            data: borsh::to_vec(&ix_data).unwrap(),
+        let [CounterEvent::CounterInitialized(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterInitialized event");
         };
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&payer.pubkey()),
-            &[&payer],
-            svm.latest_blockhash(),
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.payer, payer);
+        assert_eq!(event.count, 19);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+        assert_eq!(counter_data.max_count(), u64::MAX);
+    }
+
+    #[test]
+    fn init_counter_above_max_count() {
+        let mut fixture = fixture();
+
+        let mut ix = init_counter_ix(fixture.payer.pubkey(), Counter::derive().0);
+        ix.data = borsh::to_vec(&CounterInstruction::InitCounter(InitCounterArgs {
+            count: 19,
+            max_count: Some(18),
+        }))
+        .unwrap();
+
+        fixture
+            .send(ix)
+            .assert_error(CounterError::MaxCountExceeded);
+    }
+
+    #[test]
+    fn init_counter_already_initialized() {
+        let mut fixture = fixture();
+
+        let (counter, bump) = Counter::derive();
+
+        fixture.set_borsh_account(
+            counter,
+            ID.into(),
+            &Counter {
+                discriminator: Counter::DISCRIMINATOR,
+                bump,
+                count: 1u64.to_le_bytes(),
+                max_count: u64::MAX.to_le_bytes(),
+            },
         );
 
-        let result = svm.send_transaction(tx).unwrap();
+        fixture
+            .send(init_counter_ix(fixture.payer.pubkey(), counter))
+            .assert_error(CounterError::CounterAlreadyInitialized);
+    }
+
+    #[test]
+    fn init_counter_with_wrong_address() {
+        let mut fixture = fixture();
+
+        fixture
+            .send(init_counter_ix(
+                fixture.payer.pubkey(),
+                Keypair::new().pubkey(),
+            ))
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn init_counter_without_signer() {
+        let mut fixture = fixture();
 
-        std::println!("Program executed successfully!");
-        std::println!("Transaction logs: {:#?}", result.logs);
+        let mut ix = init_counter_ix(Keypair::new().pubkey(), Counter::derive().0);
+        ix.accounts[0].is_signer = false;
 
-        let counter = svm.get_account(&counter).unwrap();
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn init_counter_with_wrong_system_program() {
+        let mut fixture = fixture();
+
+        let mut ix = init_counter_ix(fixture.payer.pubkey(), Counter::derive().0);
+        ix.accounts[2].pubkey = Keypair::new().pubkey();
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn init_counter_with_missing_accounts() {
+        let mut fixture = fixture();
+
+        let mut ix = init_counter_ix(fixture.payer.pubkey(), Counter::derive().0);
+        ix.accounts.truncate(2);
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+
+    #[test]
+    fn init_counter_with_truncated_data() {
+        let mut fixture = fixture();
 
-        let counter_data = Counter::deserialize(&mut counter.data.as_ref()).unwrap();
+        let mut ix = init_counter_ix(fixture.payer.pubkey(), Counter::derive().0);
+        // The instruction tag and half of the count.
+        ix.data.truncate(5);
 
-        assert_eq!(counter_data.count, 19);
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::InvalidInstructionData);
     }
 }
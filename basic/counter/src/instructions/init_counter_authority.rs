@@ -1,18 +1,23 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use pinocchio::{
-    cpi::{Seed, Signer},
-    error::ProgramError,
-    sysvars::{rent::Rent, Sysvar},
-    AccountView, Address, ProgramResult,
-};
-use pinocchio_system::instructions::CreateAccount;
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
 use shank::ShankType;
+use shared::{
+    account::ZeroCopy,
+    syscalls,
+    system::{self, CreateAccount},
+};
 
-use crate::accounts::CounterAuthority;
+use crate::{
+    accounts::CounterAuthority,
+    errors::CounterError,
+    events::{emit_event, CounterAuthorityInitialized},
+};
 
 #[derive(BorshSerialize, BorshDeserialize, ShankType)]
 pub struct InitCounterAuthorityArgs {
     pub count: u64,
+    /// The highest count the counter may reach, uncapped when `None`.
+    pub max_count: Option<u64>,
 }
 
 pub fn init_counter_authority(
@@ -20,7 +25,7 @@ pub fn init_counter_authority(
     accounts: &[AccountView],
     args: InitCounterAuthorityArgs,
 ) -> ProgramResult {
-    let [payer, counter_authority, system_program] = accounts else {
+    let [payer, counter_authority, system_program, event_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -31,35 +36,33 @@ pub fn init_counter_authority(
     let (pda, bump) = CounterAuthority::derive(payer.address());
 
     if counter_authority.address().ne(&pda) {
-        return Err(ProgramError::InvalidSeeds);
+        return Err(CounterError::InvalidCounterAddress.into());
     }
 
     if counter_authority.lamports().ne(&0) {
-        return Err(ProgramError::AccountAlreadyInitialized);
+        return Err(CounterError::CounterAlreadyInitialized.into());
     }
 
-    if system_program.address().ne(&pinocchio_system::ID) {
+    if system_program.address().ne(&system::ID) {
         return Err(ProgramError::IncorrectProgramId);
     };
 
-    let counter_data = CounterAuthority {
-        bump,
-        count: args.count,
-        authority: payer.address().clone(),
-    };
+    let max_count = args.max_count.unwrap_or(u64::MAX);
+
+    if args.count > max_count {
+        return Err(CounterError::MaxCountExceeded.into());
+    }
 
     let account_span = CounterAuthority::SPACE;
-    let lamports_required = Rent::get()?.minimum_balance_unchecked(account_span);
+    let lamports_required = syscalls::rent()?.minimum_balance_unchecked(account_span);
 
     let bump_bytes = &[bump];
-    let seeds = [
-        Seed::from(CounterAuthority::SEED_PREFIX),
-        Seed::from(payer.address().as_array()),
-        Seed::from(bump_bytes),
+    let seeds: [&[u8]; 3] = [
+        CounterAuthority::SEED_PREFIX,
+        payer.address().as_ref(),
+        bump_bytes,
     ];
 
-    let signers = Signer::from(&seeds);
-
     CreateAccount {
         from: payer,
         to: counter_authority,
@@ -67,74 +70,199 @@ pub fn init_counter_authority(
         space: account_span as u64,
         owner: program_id,
     }
-    .invoke_signed(&[signers])?;
+    .invoke_signed(&[&seeds])?;
 
-    counter_data
-        .serialize(&mut counter_authority.try_borrow_mut()?.as_mut())
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let mut data = counter_authority.try_borrow_mut()?;
+
+    let counter_data =
+        CounterAuthority::initialize(&mut data).map_err(|_| CounterError::InvalidCounterData)?;
+
+    counter_data.bump = bump;
+    counter_data.authority = *payer.address();
+    counter_data.set_count(args.count);
+    counter_data.set_max_count(max_count);
+
+    drop(data);
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterAuthorityInitialized {
+        counter: *counter_authority.address(),
+        authority: *payer.address(),
+        count: args.count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
 
     Ok(())
 }
 #[cfg(test)]
 mod test {
-    extern crate std;
-
-    use borsh::BorshDeserialize;
-    use litesvm::LiteSVM;
-    use pinocchio::Address;
+    use pinocchio::{error::ProgramError, Address};
     use solana_sdk::{
-        instruction::Instruction, message::AccountMeta, native_token::LAMPORTS_PER_SOL,
-        signature::Keypair, signer::Signer, transaction::Transaction,
+        account::Account, instruction::Instruction, message::AccountMeta,
+        native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer,
+    };
+
+    use crate::{
+        accounts::CounterAuthority,
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{counter_ix, decode_events, fixture, SLOT, TIMESTAMP},
+        CounterInstruction, ID,
     };
 
-    use crate::{accounts::CounterAuthority, CounterInstruction, ID};
+    use super::InitCounterAuthorityArgs;
+
+    fn init_counter_authority_ix(payer: Address, counter: Address) -> Instruction {
+        counter_ix(
+            &CounterInstruction::InitCounterAuhthority(InitCounterAuthorityArgs {
+                count: 19,
+                max_count: None,
+            }),
+            &[
+                AccountMeta::new(payer, true),
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+        )
+    }
 
     #[test]
     fn init_counter_authority() {
-        let mut svm = LiteSVM::new();
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
 
-        let payer = Keypair::new();
+        let (counter, _) = CounterAuthority::derive(&payer);
 
-        let program_id = Address::new_from_array(ID);
+        let outcome = fixture.send(init_counter_authority_ix(payer, counter));
+        outcome.assert_success();
 
-        svm.add_program_from_file(program_id, "../../target/deploy/counter.so")
-            .unwrap();
-        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let [CounterEvent::CounterAuthorityInitialized(event)] = &decode_events(&outcome)[..]
+        else {
+            panic!("expected a single CounterAuthorityInitialized event");
+        };
 
-        let (counter, _) = CounterAuthority::derive(&payer.pubkey());
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.authority, payer);
+        assert_eq!(event.count, 19);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
 
-        let ix_data = CounterInstruction::InitCounterAuhthority(super::InitCounterAuthorityArgs {
-            count: 19,
-        });
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
 
-        let ix = Instruction {
-            program_id,
-            accounts: [
-                AccountMeta::new(payer.pubkey(), true),
-                AccountMeta::new(counter, false),
-                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
-            ]
-            .to_vec(),
-            data: borsh::to_vec(&ix_data).unwrap(),
-        };
+        assert_eq!(counter_data.count(), 19);
+        assert_eq!(counter_data.authority, payer);
+        assert_eq!(counter_data.max_count(), u64::MAX);
+    }
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ix],
-            Some(&payer.pubkey()),
-            &[&payer],
-            svm.latest_blockhash(),
+    #[test]
+    fn init_counter_authority_above_max_count() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let mut ix = init_counter_authority_ix(payer, CounterAuthority::derive(&payer).0);
+        ix.data = borsh::to_vec(&CounterInstruction::InitCounterAuhthority(
+            InitCounterAuthorityArgs {
+                count: 19,
+                max_count: Some(18),
+            },
+        ))
+        .unwrap();
+
+        fixture
+            .send(ix)
+            .assert_error(CounterError::MaxCountExceeded);
+    }
+
+    #[test]
+    fn init_counter_authority_already_initialized() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let (counter, _) = CounterAuthority::derive(&payer);
+
+        fixture.set_account(
+            counter,
+            Account {
+                data: [0; CounterAuthority::SPACE].to_vec(),
+                executable: false,
+                lamports: LAMPORTS_PER_SOL,
+                owner: ID.into(),
+                rent_epoch: 0,
+            },
         );
 
-        let result = svm.send_transaction(tx).unwrap();
+        fixture
+            .send(init_counter_authority_ix(payer, counter))
+            .assert_error(CounterError::CounterAlreadyInitialized);
+    }
+
+    #[test]
+    fn init_counter_authority_with_wrong_address() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        // Another authority's counter.
+        let (counter, _) = CounterAuthority::derive(&Keypair::new().pubkey());
+
+        fixture
+            .send(init_counter_authority_ix(payer, counter))
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn init_counter_authority_without_signer() {
+        let mut fixture = fixture();
+        let payer = Keypair::new().pubkey();
+
+        let mut ix = init_counter_authority_ix(payer, CounterAuthority::derive(&payer).0);
+        ix.accounts[0].is_signer = false;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn init_counter_authority_with_wrong_system_program() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let mut ix = init_counter_authority_ix(payer, CounterAuthority::derive(&payer).0);
+        ix.accounts[2].pubkey = Keypair::new().pubkey();
 
-        std::println!("Program executed successfully!");
-        std::println!("Transaction logs: {:#?}", result.logs);
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn init_counter_authority_with_missing_accounts() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let mut ix = init_counter_authority_ix(payer, CounterAuthority::derive(&payer).0);
+        ix.accounts.truncate(2);
 
-        let counter = svm.get_account(&counter).unwrap();
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+
+    #[test]
+    fn init_counter_authority_with_truncated_data() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
 
-        let counter_data = CounterAuthority::deserialize(&mut counter.data.as_ref()).unwrap();
+        let mut ix = init_counter_authority_ix(payer, CounterAuthority::derive(&payer).0);
+        // The instruction tag alone.
+        ix.data.truncate(1);
 
-        assert_eq!(counter_data.count, 19);
-        assert_eq!(counter_data.authority, payer.pubkey());
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::InvalidInstructionData);
     }
 }
@@ -1,9 +1,21 @@
+mod close_counter;
+mod close_counter_authority;
+mod decrease_counter;
+mod decrease_counter_authority;
 mod increase_counter;
 mod increase_counter_authority;
 mod init_counter;
 mod init_counter_authority;
+mod reset_counter;
+mod reset_counter_authority;
 
+pub use close_counter::*;
+pub use close_counter_authority::*;
+pub use decrease_counter::*;
+pub use decrease_counter_authority::*;
 pub use increase_counter::*;
 pub use increase_counter_authority::*;
 pub use init_counter::*;
 pub use init_counter_authority::*;
+pub use reset_counter::*;
+pub use reset_counter_authority::*;
@@ -0,0 +1,173 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shank::ShankType;
+use shared::syscalls;
+
+use super::{close_counter::check_admin, increase_counter::update_counter};
+use crate::{
+    errors::CounterError,
+    events::{emit_event, CounterReset},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct ResetCounterArgs {
+    /// The new count, `0` for a plain reset.
+    pub count: u64,
+}
+
+/// Sets the count for the program admin, up to the counter's maximum.
+pub fn reset_counter(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: ResetCounterArgs,
+) -> ProgramResult {
+    let [admin, counter, program_data, event_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_admin(admin, program_data)?;
+
+    let new_count = update_counter(counter, |counter_data| {
+        if args.count > counter_data.max_count() {
+            return Err(CounterError::MaxCountExceeded.into());
+        }
+
+        Ok(args.count)
+    })?;
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterReset {
+        counter: *counter.address(),
+        new_count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::{
+        instruction::Instruction, message::AccountMeta, signature::Keypair, signer::Signer,
+    };
+
+    use crate::{
+        accounts::{Counter, ProgramData},
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{
+            counter_ix, decode_events, fixture, set_counter, set_program_admin, SLOT, TIMESTAMP,
+        },
+        CounterInstruction,
+    };
+
+    use super::ResetCounterArgs;
+
+    fn reset_counter_ix(admin: Address, counter: Address, count: u64) -> Instruction {
+        counter_ix(
+            &CounterInstruction::ResetCounter(ResetCounterArgs { count }),
+            &[
+                AccountMeta::new_readonly(admin, true),
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(ProgramData::derive().0, false),
+            ],
+        )
+    }
+
+    #[test]
+    fn reset_counter() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        set_program_admin(&mut fixture, Some(payer));
+        let counter = set_counter(&mut fixture, 19, 30);
+
+        let outcome = fixture.send(reset_counter_ix(payer, counter, 30));
+        outcome.assert_success();
+
+        let [CounterEvent::CounterReset(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterReset event");
+        };
+
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.new_count, 30);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 30);
+        assert_eq!(counter_data.max_count(), 30);
+    }
+
+    #[test]
+    fn reset_counter_above_max_count() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        set_program_admin(&mut fixture, Some(payer));
+        let counter = set_counter(&mut fixture, 19, 30);
+
+        fixture
+            .send(reset_counter_ix(payer, counter, 31))
+            .assert_error(CounterError::MaxCountExceeded);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn reset_counter_by_another_signer() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+        let other = Keypair::new();
+
+        set_program_admin(&mut fixture, Some(payer));
+        let counter = set_counter(&mut fixture, 19, 30);
+
+        fixture
+            .send_signed(&[reset_counter_ix(other.pubkey(), counter, 0)], &[&other])
+            .assert_error(CounterError::AdminMismatch);
+
+        let counter_data: Counter = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn reset_counter_without_signer() {
+        let mut fixture = fixture();
+
+        // Not the fee payer, whose signature is always there.
+        let admin = fixture.funded_payer().pubkey();
+
+        set_program_admin(&mut fixture, Some(admin));
+        let counter = set_counter(&mut fixture, 19, 30);
+
+        let mut ix = reset_counter_ix(admin, counter, 0);
+        ix.accounts[0].is_signer = false;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn reset_counter_with_missing_accounts() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let mut ix = reset_counter_ix(payer, Counter::derive().0, 0);
+        ix.accounts.truncate(2);
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+}
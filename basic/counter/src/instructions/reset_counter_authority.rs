@@ -0,0 +1,152 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use shank::ShankType;
+use shared::syscalls;
+
+use super::increase_counter_authority::update_counter_authority;
+use crate::{
+    errors::CounterError,
+    events::{emit_event, CounterAuthorityReset},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct ResetCounterAuthorityArgs {
+    /// The new count, `0` for a plain reset.
+    pub count: u64,
+}
+
+/// Sets the count, up to the counter's maximum.
+pub fn reset_counter_authority(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: ResetCounterAuthorityArgs,
+) -> ProgramResult {
+    let [authority, counter, event_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let new_count = update_counter_authority(authority, counter, |counter_data| {
+        if args.count > counter_data.max_count() {
+            return Err(CounterError::MaxCountExceeded.into());
+        }
+
+        Ok(args.count)
+    })?;
+
+    let clock = syscalls::clock()?;
+
+    let event = CounterAuthorityReset {
+        counter: *counter.address(),
+        authority: *authority.address(),
+        new_count,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    };
+
+    emit_event(program_id, event_accounts, &event)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::{
+        instruction::Instruction, message::AccountMeta, signature::Keypair, signer::Signer,
+    };
+
+    use crate::{
+        accounts::CounterAuthority,
+        errors::CounterError,
+        events::CounterEvent,
+        test_helpers::{
+            counter_ix, decode_events, fixture, set_counter_authority, SLOT, TIMESTAMP,
+        },
+        CounterInstruction,
+    };
+
+    use super::ResetCounterAuthorityArgs;
+
+    fn reset_counter_authority_ix(authority: Address, counter: Address, count: u64) -> Instruction {
+        counter_ix(
+            &CounterInstruction::ResetCounterAuthority(ResetCounterAuthorityArgs { count }),
+            &[
+                AccountMeta::new(authority, true),
+                AccountMeta::new(counter, false),
+            ],
+        )
+    }
+
+    #[test]
+    fn reset_counter_authority() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let outcome = fixture.send(reset_counter_authority_ix(payer, counter, 0));
+        outcome.assert_success();
+
+        let [CounterEvent::CounterAuthorityReset(event)] = &decode_events(&outcome)[..] else {
+            panic!("expected a single CounterAuthorityReset event");
+        };
+
+        assert_eq!(event.counter, counter);
+        assert_eq!(event.authority, payer);
+        assert_eq!(event.new_count, 0);
+        assert_eq!(event.slot, SLOT);
+        assert_eq!(event.timestamp, TIMESTAMP);
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 0);
+    }
+
+    #[test]
+    fn reset_counter_authority_above_max_count() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, 30);
+
+        fixture
+            .send(reset_counter_authority_ix(payer, counter, 31))
+            .assert_error(CounterError::MaxCountExceeded);
+
+        let counter_data: CounterAuthority = fixture.borsh_account(&counter);
+
+        assert_eq!(counter_data.count(), 19);
+    }
+
+    #[test]
+    fn reset_counter_authority_by_another_signer() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+        let other = Keypair::new();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        // The counter is not the PDA of the signer.
+        fixture
+            .send_signed(
+                &[reset_counter_authority_ix(other.pubkey(), counter, 0)],
+                &[&other],
+            )
+            .assert_error(CounterError::InvalidCounterAddress);
+    }
+
+    #[test]
+    fn reset_counter_authority_with_missing_accounts() {
+        let mut fixture = fixture();
+        let payer = fixture.payer.pubkey();
+
+        let counter = set_counter_authority(&mut fixture, payer, 19, u64::MAX);
+
+        let mut ix = reset_counter_authority_ix(payer, counter, 0);
+        ix.accounts.truncate(1);
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+}
@@ -3,30 +3,47 @@
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use pinocchio::{
-    default_panic_handler, error::ProgramError, no_allocator, program_entrypoint, AccountView,
-    Address, ProgramResult,
+    default_panic_handler, error::ProgramError, no_allocator, AccountView, Address, ProgramResult,
 };
 use pinocchio_pubkey::declare_id;
 use shank::ShankInstruction;
 
 use crate::instructions::{
-    increase_counter, increase_counter_authority, init_counter, init_counter_authority,
-    InitCounterArgs, InitCounterAuthorityArgs,
+    close_counter, close_counter_authority, decrease_counter, decrease_counter_authority,
+    increase_counter, increase_counter_authority, increase_counter_authority_by,
+    increase_counter_by, init_counter, init_counter_authority, reset_counter,
+    reset_counter_authority,
+};
+pub use crate::instructions::{
+    DecreaseCounterArgs, DecreaseCounterAuthorityArgs, IncreaseCounterAuthorityByArgs,
+    IncreaseCounterByArgs, InitCounterArgs, InitCounterAuthorityArgs, ResetCounterArgs,
+    ResetCounterAuthorityArgs,
 };
 
-mod accounts;
-mod events;
+pub mod accounts;
+pub mod errors;
+pub mod events;
+#[cfg(any(test, feature = "fuzz"))]
+pub mod fuzz;
 mod instructions;
 
-program_entrypoint!(process);
+#[cfg(test)]
+mod test_helpers;
+
+#[cfg(target_os = "solana")]
+pinocchio::program_entrypoint!(process);
 no_allocator!();
 default_panic_handler!();
 
 declare_id!("8F1XtWR4wTs37nnutBvd2MWpCTfb7XAciFYkw5XHaENj");
 
+// With the `cpi-events` feature every instruction also expects the event
+// authority PDA and this program as two trailing accounts (see
+// `events::emit_event`). They are left out of the IDL, clients pass them as
+// remaining accounts.
 #[derive(ShankInstruction, BorshDeserialize, BorshSerialize)]
 pub enum CounterInstruction {
-    #[account(0, sig, name = "payer")]
+    #[account(0, mut, sig, name = "payer")]
     #[account(1, mut, name = "counter")]
     #[account(2, name = "system_program")]
     InitCounter(InitCounterArgs),
@@ -34,7 +51,7 @@ pub enum CounterInstruction {
     #[account(0, mut, name = "counter")]
     IncreaseCounter,
 
-    #[account(0, sig, name = "payer")]
+    #[account(0, mut, sig, name = "payer")]
     #[account(1, mut, name = "counter_authority")]
     #[account(2, name = "system_program")]
     InitCounterAuhthority(InitCounterAuthorityArgs),
@@ -42,6 +59,42 @@ pub enum CounterInstruction {
     #[account(0, sig, name = "authority")]
     #[account(1, mut, name = "counter_authority")]
     IncreaseCounterAuthority,
+
+    #[account(0, mut, name = "counter")]
+    IncreaseCounterBy(IncreaseCounterByArgs),
+
+    #[account(0, sig, name = "admin")]
+    #[account(1, mut, name = "counter")]
+    #[account(2, name = "program_data")]
+    DecreaseCounter(DecreaseCounterArgs),
+
+    #[account(0, sig, name = "admin")]
+    #[account(1, mut, name = "counter")]
+    #[account(2, name = "program_data")]
+    ResetCounter(ResetCounterArgs),
+
+    #[account(0, sig, name = "authority")]
+    #[account(1, mut, name = "counter_authority")]
+    IncreaseCounterAuthorityBy(IncreaseCounterAuthorityByArgs),
+
+    #[account(0, sig, name = "authority")]
+    #[account(1, mut, name = "counter_authority")]
+    DecreaseCounterAuthority(DecreaseCounterAuthorityArgs),
+
+    #[account(0, sig, name = "authority")]
+    #[account(1, mut, name = "counter_authority")]
+    ResetCounterAuthority(ResetCounterAuthorityArgs),
+
+    #[account(0, sig, name = "admin")]
+    #[account(1, mut, name = "counter")]
+    #[account(2, mut, name = "destination")]
+    #[account(3, name = "program_data")]
+    CloseCounter,
+
+    #[account(0, sig, name = "authority")]
+    #[account(1, mut, name = "counter_authority")]
+    #[account(2, mut, name = "destination")]
+    CloseCounterAuthority,
 }
 
 pub fn process(
@@ -53,6 +106,15 @@ pub fn process(
         return Err(ProgramError::IncorrectProgramId);
     }
 
+    // Self-CPI events are no-ops, but anyone else sending the reserved tag is
+    // trying to forge one.
+    #[cfg(feature = "cpi-events")]
+    if shared::event::cpi::is_event_instruction(instruction_data) {
+        let (event_authority, _) = crate::accounts::EventAuthority::derive();
+
+        return shared::event::cpi::check_event_authority(accounts, &event_authority);
+    }
+
     match CounterInstruction::try_from_slice(instruction_data)
         .map_err(|_| ProgramError::InvalidInstructionData)?
     {
@@ -64,5 +126,77 @@ pub fn process(
         CounterInstruction::IncreaseCounterAuthority => {
             increase_counter_authority(program_id, accounts)
         }
+        CounterInstruction::IncreaseCounterBy(params) => {
+            increase_counter_by(program_id, accounts, params)
+        }
+        CounterInstruction::DecreaseCounter(params) => {
+            decrease_counter(program_id, accounts, params)
+        }
+        CounterInstruction::ResetCounter(params) => reset_counter(program_id, accounts, params),
+        CounterInstruction::IncreaseCounterAuthorityBy(params) => {
+            increase_counter_authority_by(program_id, accounts, params)
+        }
+        CounterInstruction::DecreaseCounterAuthority(params) => {
+            decrease_counter_authority(program_id, accounts, params)
+        }
+        CounterInstruction::ResetCounterAuthority(params) => {
+            reset_counter_authority(program_id, accounts, params)
+        }
+        CounterInstruction::CloseCounter => close_counter(program_id, accounts),
+        CounterInstruction::CloseCounterAuthority => close_counter_authority(program_id, accounts),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::{instruction::Instruction, message::AccountMeta, signer::Signer};
+    use test_utils::Fixture;
+
+    use crate::{
+        accounts::Counter,
+        test_helpers::{counter_ix, features, fixture},
+        CounterInstruction, InitCounterArgs, ID,
+    };
+
+    #[test]
+    fn process_under_other_program_id() {
+        let other_id = Address::new_from_array([7; 32]);
+
+        let mut fixture = Fixture::builder()
+            .program_with_features(other_id, "counter", crate::process, &features())
+            .build();
+
+        let mut ix = counter_ix(
+            &CounterInstruction::InitCounter(InitCounterArgs {
+                count: 19,
+                max_count: None,
+            }),
+            &[
+                AccountMeta::new(fixture.payer.pubkey(), true),
+                AccountMeta::new(Counter::derive().0, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+        );
+        ix.program_id = other_id;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn process_unknown_instruction() {
+        let mut fixture = fixture();
+
+        let ix = Instruction {
+            program_id: ID.into(),
+            accounts: [].to_vec(),
+            data: [0xff].to_vec(),
+        };
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::InvalidInstructionData);
     }
 }
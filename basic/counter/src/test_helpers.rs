@@ -0,0 +1,162 @@
+extern crate std;
+
+use std::{string::String, vec::Vec};
+
+use pinocchio::Address;
+use serde_json::Value;
+use shared::discriminator::Discriminated;
+use solana_sdk::{clock::Clock, instruction::Instruction, message::AccountMeta};
+use test_utils::{Fixture, Outcome};
+
+#[cfg(feature = "cpi-events")]
+use crate::accounts::EventAuthority;
+use crate::accounts::{Counter, CounterAuthority};
+use crate::events::CounterEvent;
+use crate::{CounterInstruction, ID};
+
+const IDL_PATH: &str = "../../idl/counter.json";
+
+pub const SLOT: u64 = 42;
+pub const TIMESTAMP: i64 = 1_700_000_000;
+
+/// The counter features this test run is built with, which the binary under
+/// test must be built with too.
+pub fn features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+
+    if cfg!(feature = "cpi-events") {
+        features.push("cpi-events");
+    }
+    if cfg!(feature = "base64-events") {
+        features.push("base64-events");
+    }
+    if cfg!(feature = "borsh-accounts") {
+        features.push("borsh-accounts");
+    }
+
+    features
+}
+
+/// The counter program, with the clock pinned so emitted events carry a known
+/// slot and timestamp.
+pub fn fixture() -> Fixture {
+    Fixture::builder()
+        .program_with_features(ID, "counter", crate::process, &features())
+        .clock(Clock {
+            slot: SLOT,
+            unix_timestamp: TIMESTAMP,
+            ..Clock::default()
+        })
+        .build()
+}
+
+/// Trailing accounts every instruction needs for the configured event
+/// transport: none for log events, the event authority and the program for
+/// `cpi-events`.
+pub fn event_accounts() -> Vec<AccountMeta> {
+    #[cfg(feature = "cpi-events")]
+    {
+        let (event_authority, _) = EventAuthority::derive();
+
+        std::vec![
+            AccountMeta::new_readonly(event_authority, false),
+            AccountMeta::new_readonly(ID.into(), false),
+        ]
+    }
+
+    #[cfg(not(feature = "cpi-events"))]
+    Vec::new()
+}
+
+/// Presets the counter PDA, initialized with `count` and `max_count`.
+pub fn set_counter(fixture: &mut Fixture, count: u64, max_count: u64) -> Address {
+    let (counter, bump) = Counter::derive();
+
+    fixture.set_borsh_account(
+        counter,
+        ID.into(),
+        &Counter {
+            discriminator: Counter::DISCRIMINATOR,
+            bump,
+            count: count.to_le_bytes(),
+            max_count: max_count.to_le_bytes(),
+        },
+    );
+
+    counter
+}
+
+/// Presets the counter authority PDA of `authority`, initialized with
+/// `count` and `max_count`.
+pub fn set_counter_authority(
+    fixture: &mut Fixture,
+    authority: Address,
+    count: u64,
+    max_count: u64,
+) -> Address {
+    let (counter, bump) = CounterAuthority::derive(&authority);
+
+    fixture.set_borsh_account(
+        counter,
+        ID.into(),
+        &CounterAuthority {
+            discriminator: CounterAuthority::DISCRIMINATOR,
+            authority,
+            bump,
+            count: count.to_le_bytes(),
+            max_count: max_count.to_le_bytes(),
+        },
+    );
+
+    counter
+}
+
+/// Presets the program data account of the program, with `admin` as its
+/// upgrade authority, or none for an immutable program.
+pub fn set_program_admin(fixture: &mut Fixture, admin: Option<Address>) {
+    fixture.set_upgrade_authority(&ID.into(), admin);
+}
+
+/// `instruction` over `accounts`, followed by the [`event_accounts`].
+pub fn counter_ix(instruction: &CounterInstruction, accounts: &[AccountMeta]) -> Instruction {
+    Instruction {
+        program_id: ID.into(),
+        accounts: accounts.iter().cloned().chain(event_accounts()).collect(),
+        data: borsh::to_vec(instruction).unwrap(),
+    }
+}
+
+/// Decodes every `CounterEvent` the transaction emitted, in order, from the
+/// logs or from the self-CPIs depending on the configured transport.
+pub fn decode_events(outcome: &Outcome) -> Vec<CounterEvent> {
+    #[cfg(feature = "cpi-events")]
+    {
+        outcome.cpi_events(&ID.into(), &EventAuthority::derive().0)
+    }
+
+    #[cfg(not(feature = "cpi-events"))]
+    outcome.events(&ID.into())
+}
+
+/// Shank knows nothing about events or PDA seeds, so their IDL entries are
+/// kept in sync by tests: `update` applies the expected entries to the IDL,
+/// which must then be unchanged. Run with `UPDATE_IDL=1` (see `pnpm gen:idl`)
+/// to write them instead.
+pub fn sync_idl(update: impl FnOnce(&mut Value)) {
+    let idl: Value = serde_json::from_str(&std::fs::read_to_string(IDL_PATH).unwrap()).unwrap();
+
+    let mut expected = idl.clone();
+    update(&mut expected);
+
+    if std::env::var_os("UPDATE_IDL").is_some() {
+        let mut out = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(b"\t");
+        let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+        serde::Serialize::serialize(&expected, &mut serializer).unwrap();
+        out.push(b'\n');
+
+        std::fs::write(IDL_PATH, String::from_utf8(out).unwrap()).unwrap();
+    } else {
+        assert_eq!(idl, expected, "IDL is stale, run `pnpm gen:idl`");
+    }
+}
@@ -0,0 +1,354 @@
+//! Compute unit, binary size and stack reports of the example programs, and
+//! their comparison against a checked-in baseline.
+//!
+//! The `bench` binary, built with the `sbf` feature, measures the programs as
+//! SBF binaries in LiteSVM and writes a [`Report`]; the report and the
+//! comparison live here so they are tested without a Solana toolchain, as
+//! are the [`programs`] instructions, which run natively there.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub mod programs;
+
+#[derive(Debug, Error)]
+pub enum BenchError {
+    #[error("Could not access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid report {path}: {source}")]
+    InvalidReport {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// The size of a program's `.so`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Binary {
+    pub program: String,
+    pub size: u64,
+}
+
+/// What an instruction cost, sent alone in a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub program: String,
+    pub instruction: String,
+    pub compute_units: u64,
+    /// The most stack bytes the live call frames used together.
+    pub stack_peak: u64,
+    /// The most stack bytes a single call frame used, out of the 4 KiB the
+    /// runtime gives each.
+    pub stack_max_frame: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub binaries: Vec<Binary>,
+    pub instructions: Vec<Measurement>,
+}
+
+/// The metrics a baseline caps. Stack usage is reported as a hint only, it
+/// moves with the compiler more than with the code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    ComputeUnits,
+    BinarySize,
+}
+
+/// A metric above its baseline by more than the allowed margin, or missing
+/// from the baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    /// `program` for a binary, `program instruction` for an instruction.
+    pub name: String,
+    pub metric: Metric,
+    /// `None` when the baseline has no entry for the metric.
+    pub baseline: Option<u64>,
+    pub current: u64,
+}
+
+impl std::fmt::Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let metric = match self.metric {
+            Metric::ComputeUnits => "compute units",
+            Metric::BinarySize => "binary size",
+        };
+
+        match self.baseline {
+            Some(baseline) => write!(
+                f,
+                "{} {metric}: {} over the baseline of {baseline} ({})",
+                self.name,
+                self.current,
+                change(self.current, Some(baseline))
+            ),
+            None => write!(
+                f,
+                "{} {metric}: {} with no baseline, record it with --update-baseline",
+                self.name, self.current
+            ),
+        }
+    }
+}
+
+impl Report {
+    pub fn read(path: &Path) -> Result<Self, BenchError> {
+        let json = fs::read_to_string(path).map_err(|source| BenchError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        serde_json::from_str(&json).map_err(|source| BenchError::InvalidReport {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Writes the report as pretty JSON, creating its directory.
+    pub fn write(&self, path: &Path) -> Result<(), BenchError> {
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+
+        write_file(path, &json)
+    }
+
+    fn binary(&self, program: &str) -> Option<&Binary> {
+        self.binaries
+            .iter()
+            .find(|binary| binary.program == program)
+    }
+
+    fn instruction(&self, program: &str, instruction: &str) -> Option<&Measurement> {
+        self.instructions.iter().find(|measurement| {
+            measurement.program == program && measurement.instruction == instruction
+        })
+    }
+
+    /// The metrics above their `baseline` by more than `margin` percent, and
+    /// those the baseline has no entry for, which would otherwise never be
+    /// checked.
+    pub fn regressions(&self, baseline: &Report, margin: f64) -> Vec<Regression> {
+        let exceeds = |current: u64, baseline: Option<u64>| {
+            baseline
+                .is_none_or(|baseline| current as f64 > baseline as f64 * (1.0 + margin / 100.0))
+        };
+
+        let binaries = self.binaries.iter().filter_map(|binary| {
+            let base = baseline.binary(&binary.program).map(|base| base.size);
+
+            exceeds(binary.size, base).then(|| Regression {
+                name: binary.program.clone(),
+                metric: Metric::BinarySize,
+                baseline: base,
+                current: binary.size,
+            })
+        });
+
+        let instructions = self.instructions.iter().filter_map(|measurement| {
+            let base = baseline
+                .instruction(&measurement.program, &measurement.instruction)
+                .map(|base| base.compute_units);
+
+            exceeds(measurement.compute_units, base).then(|| Regression {
+                name: format!("{} {}", measurement.program, measurement.instruction),
+                metric: Metric::ComputeUnits,
+                baseline: base,
+                current: measurement.compute_units,
+            })
+        });
+
+        binaries.chain(instructions).collect()
+    }
+
+    /// The report as Markdown tables, each metric next to its `baseline`.
+    pub fn markdown(&self, baseline: &Report) -> String {
+        let mut markdown = String::new();
+
+        markdown.push_str("## Binaries\n\n");
+        markdown.push_str("| Program | Size (bytes) | Baseline | Change |\n");
+        markdown.push_str("| --- | ---: | ---: | ---: |\n");
+
+        for binary in &self.binaries {
+            let base = baseline.binary(&binary.program).map(|base| base.size);
+
+            writeln!(
+                markdown,
+                "| {} | {} | {} | {} |",
+                binary.program,
+                binary.size,
+                or_dash(base),
+                change(binary.size, base)
+            )
+            .unwrap();
+        }
+
+        markdown.push_str("\n## Instructions\n\n");
+        markdown.push_str(
+            "| Program | Instruction | Compute units | Baseline | Change | Stack peak | Largest frame |\n",
+        );
+        markdown.push_str("| --- | --- | ---: | ---: | ---: | ---: | ---: |\n");
+
+        for measurement in &self.instructions {
+            let base = baseline
+                .instruction(&measurement.program, &measurement.instruction)
+                .map(|base| base.compute_units);
+
+            writeln!(
+                markdown,
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                measurement.program,
+                measurement.instruction,
+                measurement.compute_units,
+                or_dash(base),
+                change(measurement.compute_units, base),
+                measurement.stack_peak,
+                measurement.stack_max_frame
+            )
+            .unwrap();
+        }
+
+        markdown
+    }
+}
+
+/// Writes `contents` to `path`, creating its directory.
+pub fn write_file(path: &Path, contents: &str) -> Result<(), BenchError> {
+    let io = |source| BenchError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io)?;
+    }
+
+    fs::write(path, contents).map_err(io)
+}
+
+fn or_dash(value: Option<u64>) -> String {
+    value.map_or_else(|| "-".to_string(), |value| value.to_string())
+}
+
+/// The relative change from `baseline` to `current`, e.g. `+2.5%`.
+fn change(current: u64, baseline: Option<u64>) -> String {
+    match baseline {
+        None => "new".to_string(),
+        Some(0) if current == 0 => "+0.0%".to_string(),
+        Some(0) => "+inf".to_string(),
+        Some(baseline) => format!(
+            "{:+.1}%",
+            (current as f64 - baseline as f64) / baseline as f64 * 100.0
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Binary, Measurement, Metric, Regression, Report};
+
+    fn report(size: u64, compute_units: u64) -> Report {
+        Report {
+            binaries: [Binary {
+                program: "counter".to_string(),
+                size,
+            }]
+            .to_vec(),
+            instructions: [Measurement {
+                program: "counter".to_string(),
+                instruction: "increase_counter".to_string(),
+                compute_units,
+                stack_peak: 96,
+                stack_max_frame: 96,
+            }]
+            .to_vec(),
+        }
+    }
+
+    #[test]
+    fn flags_metrics_above_the_margin() {
+        let baseline = report(10_000, 400);
+
+        assert_eq!(report(10_400, 420).regressions(&baseline, 5.0), []);
+
+        assert_eq!(
+            report(10_600, 421).regressions(&baseline, 5.0),
+            [
+                Regression {
+                    name: "counter".to_string(),
+                    metric: Metric::BinarySize,
+                    baseline: Some(10_000),
+                    current: 10_600,
+                },
+                Regression {
+                    name: "counter increase_counter".to_string(),
+                    metric: Metric::ComputeUnits,
+                    baseline: Some(400),
+                    current: 421,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_metrics_missing_from_the_baseline() {
+        let regressions = report(10_000, 400).regressions(&Report::default(), 5.0);
+
+        assert_eq!(
+            regressions,
+            [
+                Regression {
+                    name: "counter".to_string(),
+                    metric: Metric::BinarySize,
+                    baseline: None,
+                    current: 10_000,
+                },
+                Regression {
+                    name: "counter increase_counter".to_string(),
+                    metric: Metric::ComputeUnits,
+                    baseline: None,
+                    current: 400,
+                },
+            ]
+        );
+
+        assert_eq!(
+            regressions[1].to_string(),
+            "counter increase_counter compute units: 400 with no baseline, record it with \
+             --update-baseline"
+        );
+    }
+
+    #[test]
+    fn renders_changes_against_the_baseline() {
+        let markdown = report(10_250, 380).markdown(&report(10_000, 400));
+
+        assert!(markdown.contains("| counter | 10250 | 10000 | +2.5% |"));
+        assert!(markdown.contains("| counter | increase_counter | 380 | 400 | -5.0% | 96 | 96 |"));
+
+        let markdown = report(10_250, 380).markdown(&Report::default());
+
+        assert!(markdown.contains("| counter | increase_counter | 380 | - | new | 96 | 96 |"));
+    }
+
+    #[test]
+    fn reads_back_what_it_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        // In a directory that does not exist yet.
+        let path = dir.path().join("bench/report.json");
+
+        report(10_000, 400).write(&path).unwrap();
+
+        assert_eq!(Report::read(&path).unwrap(), report(10_000, 400));
+    }
+}
@@ -0,0 +1,109 @@
+//! `bench`, which measures every instruction of the example programs as SBF
+//! binaries in LiteSVM: compute units, the size of each `.so` and stack
+//! usage. Writes `report.json` and `report.md`, and fails when a metric
+//! exceeds the checked-in baseline by more than the margin.
+//!
+//! ```bash
+//! cargo run -p bench --features sbf
+//! cargo run -p bench --features sbf -- --update-baseline
+//! ```
+
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use bench::{programs::PROGRAMS, write_file, BenchError, Binary, Measurement, Report};
+use clap::Parser;
+use test_utils::{program_path, Fixture};
+
+#[derive(Parser)]
+#[command(about = "Measure the example programs against their baseline")]
+struct Cli {
+    /// Baseline the measurements are compared against.
+    #[arg(long, value_name = "FILE", default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/baseline.json"))]
+    baseline: PathBuf,
+
+    /// How far above its baseline, in percent, a metric may go.
+    #[arg(long, default_value_t = 5.0)]
+    margin: f64,
+
+    /// Directory `report.json` and `report.md` are written to.
+    #[arg(long, value_name = "DIR", default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../target/bench"))]
+    out_dir: PathBuf,
+
+    /// Records the measurements as the new baseline instead.
+    #[arg(long)]
+    update_baseline: bool,
+}
+
+fn measure() -> Report {
+    let mut report = Report::default();
+
+    for program in PROGRAMS {
+        let path = program_path(program.crate_name, &[]);
+        let size = fs::metadata(&path)
+            .unwrap_or_else(|error| panic!("could not read {}: {error}", path.display()))
+            .len();
+
+        report.binaries.push(Binary {
+            program: program.crate_name.to_string(),
+            size,
+        });
+
+        for (instruction, bench) in program.instructions {
+            let mut fixture = Fixture::new(program.id, program.crate_name, program.process);
+
+            let outcome = bench(&mut fixture);
+            outcome.assert_success();
+
+            let stack = outcome.stack_usage();
+
+            report.instructions.push(Measurement {
+                program: program.crate_name.to_string(),
+                instruction: instruction.to_string(),
+                compute_units: outcome.compute_units(),
+                stack_peak: stack.peak,
+                stack_max_frame: stack.max_frame,
+            });
+        }
+    }
+
+    report
+}
+
+/// Whether every metric is within the margin of its baseline.
+fn run(cli: Cli) -> Result<bool, BenchError> {
+    let report = measure();
+
+    if cli.update_baseline {
+        report.write(&cli.baseline)?;
+        println!("Baseline written to {}", cli.baseline.display());
+
+        return Ok(true);
+    }
+
+    let baseline = Report::read(&cli.baseline)?;
+    let markdown = report.markdown(&baseline);
+
+    report.write(&cli.out_dir.join("report.json"))?;
+    write_file(&cli.out_dir.join("report.md"), &markdown)?;
+
+    println!("{markdown}");
+
+    let regressions = report.regressions(&baseline, cli.margin);
+
+    for regression in &regressions {
+        eprintln!("{regression}");
+    }
+
+    Ok(regressions.is_empty())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
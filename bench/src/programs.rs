@@ -0,0 +1,453 @@
+//! The programs measured, and how to send each of their instructions.
+
+use generated_clients::{
+    close_account::{self, CloseMemeBuilder, CreateMemeBuilder},
+    counter::{
+        self, find_counter_authority_pda, CloseCounterAuthorityBuilder, CloseCounterBuilder,
+        DecreaseCounterArgs, DecreaseCounterAuthorityArgs, DecreaseCounterAuthorityBuilder,
+        DecreaseCounterBuilder, IncreaseCounterAuthorityBuilder, IncreaseCounterAuthorityByArgs,
+        IncreaseCounterAuthorityByBuilder, IncreaseCounterBuilder, IncreaseCounterByArgs,
+        IncreaseCounterByBuilder, InitCounterArgs, InitCounterAuhthorityBuilder,
+        InitCounterAuthorityArgs, InitCounterBuilder, ResetCounterArgs, ResetCounterAuthorityArgs,
+        ResetCounterAuthorityBuilder, ResetCounterBuilder,
+    },
+    create_mint::{self, CreateMintArgs, CreateMintBuilder, MintExtensions},
+    transfer_mint::{
+        self, DepositArgs, DepositBuilder, InitVaultBuilder, WithdrawArgs, WithdrawBuilder,
+        WithdrawCheckedArgs, WithdrawCheckedBuilder,
+    },
+};
+use pinocchio_token::state::{Mint, TokenAccount};
+use solana_address::Address;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer};
+use test_utils::{Fixture, Outcome, Processor};
+
+/// Sends what an instruction needs first, then the instruction alone, whose
+/// outcome is measured.
+pub type Bench = fn(&mut Fixture) -> Outcome;
+
+pub struct Program {
+    pub crate_name: &'static str,
+    pub id: Address,
+    pub process: Processor,
+    /// Each instruction is measured in a fixture of its own.
+    pub instructions: &'static [(&'static str, Bench)],
+}
+
+pub const PROGRAMS: &[Program] = &[
+    Program {
+        crate_name: "counter",
+        id: counter::PROGRAM_ID,
+        process: ::counter::process,
+        instructions: &[
+            ("init_counter", init_counter),
+            ("increase_counter", increase_counter),
+            ("increase_counter_by", increase_counter_by),
+            ("decrease_counter", decrease_counter),
+            ("reset_counter", reset_counter),
+            ("init_counter_authority", init_counter_authority),
+            ("increase_counter_authority", increase_counter_authority),
+            (
+                "increase_counter_authority_by",
+                increase_counter_authority_by,
+            ),
+            ("decrease_counter_authority", decrease_counter_authority),
+            ("reset_counter_authority", reset_counter_authority),
+            ("close_counter", close_counter),
+            ("close_counter_authority", close_counter_authority),
+        ],
+    },
+    Program {
+        crate_name: "close-account",
+        id: close_account::PROGRAM_ID,
+        process: ::close_account::process,
+        instructions: &[("create_meme", create_meme), ("close_meme", close_meme)],
+    },
+    Program {
+        crate_name: "create-mint",
+        id: create_mint::PROGRAM_ID,
+        process: ::create_mint::process,
+        instructions: &[("create_mint", create_mint)],
+    },
+    Program {
+        crate_name: "transfer-mint",
+        id: transfer_mint::PROGRAM_ID,
+        process: ::transfer_mint::process,
+        instructions: &[
+            ("init_vault", init_vault),
+            ("deposit", deposit),
+            ("withdraw", withdraw),
+            ("withdraw_checked", withdraw_checked),
+        ],
+    },
+];
+
+fn init_counter(fixture: &mut Fixture) -> Outcome {
+    let instruction = InitCounterBuilder::new()
+        .payer(fixture.payer.pubkey())
+        .init_counter_args(InitCounterArgs {
+            count: 0,
+            max_count: None,
+        })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn increase_counter(fixture: &mut Fixture) -> Outcome {
+    init_counter(fixture).assert_success();
+
+    fixture.send(IncreaseCounterBuilder::new().instruction())
+}
+
+fn increase_counter_by(fixture: &mut Fixture) -> Outcome {
+    init_counter(fixture).assert_success();
+
+    let instruction = IncreaseCounterByBuilder::new()
+        .increase_counter_by_args(IncreaseCounterByArgs { amount: 2 })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn decrease_counter(fixture: &mut Fixture) -> Outcome {
+    increase_counter_by(fixture).assert_success();
+
+    let admin = fixture.payer.pubkey();
+    let program_data = fixture.set_upgrade_authority(&counter::PROGRAM_ID, Some(admin));
+
+    let instruction = DecreaseCounterBuilder::new()
+        .admin(admin)
+        .program_data(program_data)
+        .decrease_counter_args(DecreaseCounterArgs { amount: 1 })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn reset_counter(fixture: &mut Fixture) -> Outcome {
+    increase_counter_by(fixture).assert_success();
+
+    let admin = fixture.payer.pubkey();
+    let program_data = fixture.set_upgrade_authority(&counter::PROGRAM_ID, Some(admin));
+
+    let instruction = ResetCounterBuilder::new()
+        .admin(admin)
+        .program_data(program_data)
+        .reset_counter_args(ResetCounterArgs { count: 0 })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn init_counter_authority(fixture: &mut Fixture) -> Outcome {
+    let payer = fixture.payer.pubkey();
+
+    let instruction = InitCounterAuhthorityBuilder::new()
+        .payer(payer)
+        .counter_authority(find_counter_authority_pda(&payer).0)
+        .init_counter_authority_args(InitCounterAuthorityArgs {
+            count: 0,
+            max_count: None,
+        })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn increase_counter_authority(fixture: &mut Fixture) -> Outcome {
+    init_counter_authority(fixture).assert_success();
+
+    let authority = fixture.payer.pubkey();
+
+    let instruction = IncreaseCounterAuthorityBuilder::new()
+        .authority(authority)
+        .counter_authority(find_counter_authority_pda(&authority).0)
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn increase_counter_authority_by(fixture: &mut Fixture) -> Outcome {
+    init_counter_authority(fixture).assert_success();
+
+    let authority = fixture.payer.pubkey();
+
+    let instruction = IncreaseCounterAuthorityByBuilder::new()
+        .authority(authority)
+        .counter_authority(find_counter_authority_pda(&authority).0)
+        .increase_counter_authority_by_args(IncreaseCounterAuthorityByArgs { amount: 2 })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn decrease_counter_authority(fixture: &mut Fixture) -> Outcome {
+    increase_counter_authority_by(fixture).assert_success();
+
+    let authority = fixture.payer.pubkey();
+
+    let instruction = DecreaseCounterAuthorityBuilder::new()
+        .authority(authority)
+        .counter_authority(find_counter_authority_pda(&authority).0)
+        .decrease_counter_authority_args(DecreaseCounterAuthorityArgs { amount: 1 })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn reset_counter_authority(fixture: &mut Fixture) -> Outcome {
+    increase_counter_authority_by(fixture).assert_success();
+
+    let authority = fixture.payer.pubkey();
+
+    let instruction = ResetCounterAuthorityBuilder::new()
+        .authority(authority)
+        .counter_authority(find_counter_authority_pda(&authority).0)
+        .reset_counter_authority_args(ResetCounterAuthorityArgs { count: 0 })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn close_counter(fixture: &mut Fixture) -> Outcome {
+    init_counter(fixture).assert_success();
+
+    let admin = fixture.payer.pubkey();
+    let program_data = fixture.set_upgrade_authority(&counter::PROGRAM_ID, Some(admin));
+
+    let instruction = CloseCounterBuilder::new()
+        .admin(admin)
+        .destination(admin)
+        .program_data(program_data)
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn close_counter_authority(fixture: &mut Fixture) -> Outcome {
+    init_counter_authority(fixture).assert_success();
+
+    let authority = fixture.payer.pubkey();
+
+    let instruction = CloseCounterAuthorityBuilder::new()
+        .authority(authority)
+        .counter_authority(find_counter_authority_pda(&authority).0)
+        .destination(authority)
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn create_meme(fixture: &mut Fixture) -> Outcome {
+    let instruction = CreateMemeBuilder::new()
+        .payer(fixture.payer.pubkey())
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn close_meme(fixture: &mut Fixture) -> Outcome {
+    create_meme(fixture).assert_success();
+
+    let authority = fixture.payer.pubkey();
+
+    let instruction = CloseMemeBuilder::new()
+        .authority(authority)
+        .destination(authority)
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn create_mint(fixture: &mut Fixture) -> Outcome {
+    let mint = Keypair::new();
+    let (mint_authority, _) = Address::find_program_address(
+        &[b"mint_authority", mint.pubkey().as_ref()],
+        &create_mint::PROGRAM_ID,
+    );
+
+    let instruction = CreateMintBuilder::new()
+        .payer(fixture.payer.pubkey())
+        .mint(mint.pubkey())
+        .mint_authority(mint_authority)
+        .token_program(pinocchio_token::ID)
+        .create_mint_args(CreateMintArgs {
+            decimals: 6,
+            extensions: MintExtensions {
+                metadata: None,
+                transfer_fee: None,
+                close_authority: false,
+                non_transferable: false,
+            },
+        })
+        .instruction();
+
+    fixture.send_signed(&[instruction], &[&mint])
+}
+
+/// The SPL Token accounts the transfer-mint instructions move tokens between,
+/// for the fixture payer as the owner.
+struct Vault {
+    owner: Address,
+    owner_token: Address,
+    vault: Address,
+    mint: Address,
+}
+
+const DECIMALS: u8 = 6;
+
+/// Presets a mint and a token account of the payer holding `amount`, then
+/// initializes the vault with `InitVault`.
+fn setup_vault(fixture: &mut Fixture, amount: u64) -> (Vault, Outcome) {
+    let owner = fixture.payer.pubkey();
+    let mint = Keypair::new().pubkey();
+    let owner_token = Keypair::new().pubkey();
+    let (vault, _) = Address::find_program_address(
+        &[b"vault", owner.as_ref(), mint.as_ref()],
+        &transfer_mint::PROGRAM_ID,
+    );
+
+    // COption<Pubkey> mint authority (none), supply, decimals, is_initialized,
+    // COption<Pubkey> freeze authority (none).
+    let mut data = Vec::with_capacity(Mint::LEN);
+    data.extend_from_slice(&[0; 36]);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(DECIMALS);
+    data.push(1);
+    data.extend_from_slice(&[0; 36]);
+    set_token_program_account(fixture, mint, data);
+
+    // mint, owner, amount, COption<Pubkey> delegate (none), state
+    // (initialized), COption<u64> is_native (none), delegated amount,
+    // COption<Pubkey> close authority (none).
+    let mut data = Vec::with_capacity(TokenAccount::LEN);
+    data.extend_from_slice(mint.as_ref());
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&[0; 36]);
+    data.push(1);
+    data.extend_from_slice(&[0; 12]);
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(&[0; 36]);
+    set_token_program_account(fixture, owner_token, data);
+
+    let instruction = InitVaultBuilder::new()
+        .owner(owner)
+        .vault(vault)
+        .mint(mint)
+        .token_program(pinocchio_token::ID)
+        .instruction();
+
+    let outcome = fixture.send(instruction);
+
+    let vault = Vault {
+        owner,
+        owner_token,
+        vault,
+        mint,
+    };
+
+    (vault, outcome)
+}
+
+fn set_token_program_account(fixture: &mut Fixture, address: Address, data: Vec<u8>) {
+    let lamports = fixture.svm.minimum_balance_for_rent_exemption(data.len());
+
+    fixture.set_account(
+        address,
+        Account {
+            lamports,
+            data,
+            owner: pinocchio_token::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+fn init_vault(fixture: &mut Fixture) -> Outcome {
+    setup_vault(fixture, 1_000).1
+}
+
+fn send_deposit(fixture: &mut Fixture, vault: &Vault, amount: u64) -> Outcome {
+    let instruction = DepositBuilder::new()
+        .owner(vault.owner)
+        .owner_token(vault.owner_token)
+        .vault(vault.vault)
+        .mint(vault.mint)
+        .token_program(pinocchio_token::ID)
+        .deposit_args(DepositArgs { amount })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn deposit(fixture: &mut Fixture) -> Outcome {
+    let (vault, outcome) = setup_vault(fixture, 1_000);
+    outcome.assert_success();
+
+    send_deposit(fixture, &vault, 1_000)
+}
+
+/// A vault holding the payer's 1 000 tokens.
+fn funded_vault(fixture: &mut Fixture) -> Vault {
+    let (vault, outcome) = setup_vault(fixture, 1_000);
+    outcome.assert_success();
+
+    send_deposit(fixture, &vault, 1_000).assert_success();
+
+    vault
+}
+
+fn withdraw(fixture: &mut Fixture) -> Outcome {
+    let vault = funded_vault(fixture);
+
+    let instruction = WithdrawBuilder::new()
+        .owner(vault.owner)
+        .owner_token(vault.owner_token)
+        .vault(vault.vault)
+        .mint(vault.mint)
+        .token_program(pinocchio_token::ID)
+        .withdraw_args(WithdrawArgs { amount: 1_000 })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+fn withdraw_checked(fixture: &mut Fixture) -> Outcome {
+    let vault = funded_vault(fixture);
+
+    let instruction = WithdrawCheckedBuilder::new()
+        .owner(vault.owner)
+        .owner_token(vault.owner_token)
+        .vault(vault.vault)
+        .mint(vault.mint)
+        .token_program(pinocchio_token::ID)
+        .withdraw_checked_args(WithdrawCheckedArgs {
+            amount: 1_000,
+            decimals: DECIMALS,
+        })
+        .instruction();
+
+    fixture.send(instruction)
+}
+
+#[cfg(test)]
+mod test {
+    use test_utils::Fixture;
+
+    use super::PROGRAMS;
+
+    #[test]
+    fn every_instruction_lands() {
+        for program in PROGRAMS {
+            for (instruction, bench) in program.instructions {
+                let mut fixture = Fixture::new(program.id, program.crate_name, program.process);
+
+                println!("{} {instruction}", program.crate_name);
+                bench(&mut fixture).assert_success();
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Where transactions go: a validator over JSON-RPC or an in-process LiteSVM
+//! sandbox whose accounts persist in a file between runs.
+
+mod rpc;
+mod sandbox;
+
+use std::path::Path;
+
+use generated_clients::counter;
+use solana_address::Address;
+use solana_sdk::{
+    account::Account, instruction::Instruction, signature::Keypair, signature::Signature,
+};
+
+use crate::CliError;
+
+pub use rpc::Rpc;
+pub use sandbox::Sandbox;
+
+const UPGRADEABLE_LOADER: Address =
+    Address::from_str_const("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// A landed transaction.
+pub struct Sent {
+    pub signature: Signature,
+    pub logs: Vec<String>,
+}
+
+pub trait Target {
+    /// Pays for and signs every transaction.
+    fn payer(&self) -> &Keypair;
+
+    /// Sends `instructions` in one transaction and waits for it to land.
+    fn send(&mut self, instructions: &[Instruction]) -> Result<Sent, CliError>;
+
+    fn account(&self, address: &Address) -> Result<Option<Account>, CliError>;
+}
+
+/// The program data account the upgradeable loader keeps for the counter
+/// program, recording its upgrade authority, the program admin.
+pub fn counter_program_data() -> Address {
+    Address::find_program_address(&[counter::PROGRAM_ID.as_ref()], &UPGRADEABLE_LOADER).0
+}
+
+/// Reads a keypair file as written by `solana-keygen`, a JSON array of the 64
+/// secret and public key bytes.
+pub fn read_keypair(path: &Path) -> Result<Keypair, CliError> {
+    let bytes: Vec<u8> = serde_json::from_str(&std::fs::read_to_string(path)?)
+        .map_err(|_| CliError::InvalidKeypair(path.to_path_buf()))?;
+
+    Keypair::try_from(bytes.as_slice()).map_err(|_| CliError::InvalidKeypair(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::counter_program_data;
+
+    #[test]
+    fn derives_the_counter_program_data() {
+        assert_eq!(
+            counter_program_data(),
+            ::counter::accounts::ProgramData::derive().0
+        );
+    }
+}
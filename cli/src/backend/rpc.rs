@@ -0,0 +1,154 @@
+use std::{thread, time::Duration};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_address::Address;
+use solana_sdk::{
+    account::Account, hash::Hash, instruction::Instruction, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+use super::{Sent, Target};
+use crate::CliError;
+
+const COMMITMENT: &str = "confirmed";
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const POLL_ATTEMPTS: usize = 60;
+
+/// A validator reached over JSON-RPC, e.g. `solana-test-validator`.
+pub struct Rpc {
+    url: String,
+    payer: Keypair,
+}
+
+impl Rpc {
+    pub fn new(url: String, payer: Keypair) -> Self {
+        Self { url, payer }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value, CliError> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+
+        let mut response: Value = ureq::post(&self.url)
+            .send_json(&request)
+            .and_then(|mut response| response.body_mut().read_json())
+            .map_err(|error| CliError::Rpc(format!("{method}: {error}")))?;
+
+        if let Some(error) = response.get_mut("error") {
+            let message = error["message"]
+                .as_str()
+                .unwrap_or("unknown error")
+                .to_string();
+
+            // Preflight failures carry the simulation logs.
+            return Err(match error["data"]["logs"].take() {
+                Value::Array(logs) => CliError::TransactionFailed {
+                    error: message,
+                    logs: strings(logs),
+                },
+                _ => CliError::Rpc(format!("{method}: {message}")),
+            });
+        }
+
+        Ok(response["result"].take())
+    }
+}
+
+fn strings(values: Vec<Value>) -> Vec<String> {
+    values
+        .into_iter()
+        .filter_map(|value| value.as_str().map(str::to_string))
+        .collect()
+}
+
+fn invalid(method: &str) -> CliError {
+    CliError::Rpc(format!("{method}: unexpected response"))
+}
+
+impl Target for Rpc {
+    fn payer(&self) -> &Keypair {
+        &self.payer
+    }
+
+    fn send(&mut self, instructions: &[Instruction]) -> Result<Sent, CliError> {
+        let blockhash: Hash = self
+            .call("getLatestBlockhash", json!([{ "commitment": COMMITMENT }]))?["value"]
+            ["blockhash"]
+            .as_str()
+            .and_then(|blockhash| blockhash.parse().ok())
+            .ok_or_else(|| invalid("getLatestBlockhash"))?;
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+        let signature = transaction.signatures[0];
+        let wire = bincode::serialize(&transaction).expect("transaction serializes");
+
+        self.call(
+            "sendTransaction",
+            json!([
+                STANDARD.encode(wire),
+                { "encoding": "base64", "preflightCommitment": COMMITMENT },
+            ]),
+        )?;
+
+        for _ in 0..POLL_ATTEMPTS {
+            let mut landed = self.call(
+                "getTransaction",
+                json!([
+                    signature.to_string(),
+                    { "encoding": "json", "commitment": COMMITMENT, "maxSupportedTransactionVersion": 0 },
+                ]),
+            )?;
+
+            if landed.is_null() {
+                thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+
+            let meta = &mut landed["meta"];
+            let logs = match meta["logMessages"].take() {
+                Value::Array(logs) => strings(logs),
+                _ => Vec::new(),
+            };
+
+            if !meta["err"].is_null() {
+                return Err(CliError::TransactionFailed {
+                    error: meta["err"].to_string(),
+                    logs,
+                });
+            }
+
+            return Ok(Sent { signature, logs });
+        }
+
+        Err(CliError::Rpc(format!("{signature} did not land")))
+    }
+
+    fn account(&self, address: &Address) -> Result<Option<Account>, CliError> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": COMMITMENT }]),
+        )?;
+
+        let account = &result["value"];
+        if account.is_null() {
+            return Ok(None);
+        }
+
+        let decode = || {
+            Some(Account {
+                lamports: account["lamports"].as_u64()?,
+                data: STANDARD.decode(account["data"][0].as_str()?).ok()?,
+                owner: account["owner"].as_str()?.parse().ok()?,
+                executable: account["executable"].as_bool()?,
+                rent_epoch: account["rentEpoch"].as_u64().unwrap_or_default(),
+            })
+        };
+
+        decode().map(Some).ok_or_else(|| invalid("getAccountInfo"))
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use generated_clients::{close_account, counter};
+use litesvm::LiteSVM;
+use serde::{Deserialize, Serialize};
+use solana_address::Address;
+use solana_sdk::{
+    account::Account, instruction::Instruction, native_token::LAMPORTS_PER_SOL, signature::Keypair,
+    signer::Signer, transaction::Transaction,
+};
+
+use super::{counter_program_data, Sent, Target, UPGRADEABLE_LOADER};
+use crate::CliError;
+
+const AIRDROP: u64 = 10 * LAMPORTS_PER_SOL;
+
+/// Every account a transaction left behind, keyed by base58 address, plus the
+/// payer. Programs are loaded from their binaries on each run instead.
+#[derive(Default, Serialize, Deserialize)]
+struct State {
+    payer: Vec<u8>,
+    accounts: BTreeMap<String, StoredAccount>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredAccount {
+    lamports: u64,
+    owner: String,
+    /// Base64.
+    data: String,
+}
+
+/// LiteSVM with the counter and close-account programs, restored from and
+/// saved to a state file around each transaction.
+pub struct Sandbox {
+    svm: LiteSVM,
+    payer: Keypair,
+    path: PathBuf,
+    state: State,
+}
+
+impl Sandbox {
+    /// Opens the sandbox saved at `path`, or a fresh one with a funded payer
+    /// when the file does not exist yet. `keypair` replaces the saved payer.
+    pub fn open(path: &Path, programs: &Path, keypair: Option<Keypair>) -> Result<Self, CliError> {
+        let mut svm = LiteSVM::new();
+
+        for (program_id, name) in [
+            (counter::PROGRAM_ID, "counter.so"),
+            (close_account::PROGRAM_ID, "close_account.so"),
+        ] {
+            let file = programs.join(name);
+            svm.add_program_from_file(program_id, &file)
+                .map_err(|error| CliError::InvalidProgram {
+                    path: file,
+                    reason: error.to_string(),
+                })?;
+        }
+
+        Self::restore(svm, path, keypair)
+    }
+
+    /// Opens the sandbox saved at `path` on `svm`, which has the programs
+    /// loaded already.
+    fn restore(mut svm: LiteSVM, path: &Path, keypair: Option<Keypair>) -> Result<Self, CliError> {
+        let invalid = |reason: String| CliError::InvalidState {
+            path: path.to_path_buf(),
+            reason,
+        };
+
+        let mut state: State = match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|error| invalid(error.to_string()))?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => State::default(),
+            Err(error) => return Err(error.into()),
+        };
+
+        let payer = match keypair {
+            Some(keypair) => keypair,
+            None if state.payer.is_empty() => Keypair::new(),
+            None => Keypair::try_from(state.payer.as_slice())
+                .map_err(|_| invalid("invalid payer".to_string()))?,
+        };
+        state.payer = payer.to_bytes().to_vec();
+
+        for (address, stored) in &state.accounts {
+            let account = decode_account(stored)
+                .ok_or_else(|| invalid(format!("invalid account {address}")))?;
+            let address = address
+                .parse()
+                .map_err(|_| invalid(format!("invalid address {address}")))?;
+
+            svm.set_account(address, account)
+                .map_err(|error| invalid(error.to_string()))?;
+        }
+
+        let mut sandbox = Self {
+            svm,
+            payer,
+            path: path.to_path_buf(),
+            state,
+        };
+
+        let payer = sandbox.payer.pubkey();
+        if sandbox.svm.get_account(&payer).is_none() {
+            sandbox
+                .svm
+                .airdrop(&payer, AIRDROP)
+                .map_err(|failed| CliError::TransactionFailed {
+                    error: failed.err.to_string(),
+                    logs: failed.meta.logs,
+                })?;
+            sandbox.persist(&[payer])?;
+        }
+
+        // The payer deploys the programs, so it is the upgrade authority of
+        // the counter program and its admin, as after `solana program deploy`.
+        let program_data = counter_program_data();
+        if sandbox.svm.get_account(&program_data).is_none() {
+            // Loader state tag, deployment slot, upgrade authority option.
+            let mut data = [3, 0, 0, 0].to_vec();
+            data.extend_from_slice(&0u64.to_le_bytes());
+            data.push(1);
+            data.extend_from_slice(payer.as_ref());
+
+            let account = Account {
+                lamports: sandbox.svm.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: UPGRADEABLE_LOADER,
+                executable: false,
+                rent_epoch: 0,
+            };
+
+            sandbox
+                .svm
+                .set_account(program_data, account)
+                .map_err(|error| invalid(error.to_string()))?;
+            sandbox.persist(&[program_data])?;
+        }
+
+        Ok(sandbox)
+    }
+
+    /// Records the accounts at `addresses`, dropping closed ones, and saves
+    /// the state file.
+    fn persist(&mut self, addresses: &[Address]) -> Result<(), CliError> {
+        for address in addresses {
+            match self.svm.get_account(address) {
+                Some(account) if !account.executable && account.lamports > 0 => {
+                    self.state.accounts.insert(
+                        address.to_string(),
+                        StoredAccount {
+                            lamports: account.lamports,
+                            owner: account.owner.to_string(),
+                            data: STANDARD.encode(&account.data),
+                        },
+                    );
+                }
+                Some(account) if account.executable => {}
+                _ => {
+                    self.state.accounts.remove(&address.to_string());
+                }
+            }
+        }
+
+        let json = serde_json::to_string_pretty(&self.state).expect("state serializes");
+        fs::write(&self.path, json)?;
+
+        Ok(())
+    }
+}
+
+fn decode_account(stored: &StoredAccount) -> Option<Account> {
+    Some(Account {
+        lamports: stored.lamports,
+        data: STANDARD.decode(&stored.data).ok()?,
+        owner: stored.owner.parse().ok()?,
+        executable: false,
+        rent_epoch: 0,
+    })
+}
+
+impl Target for Sandbox {
+    fn payer(&self) -> &Keypair {
+        &self.payer
+    }
+
+    fn send(&mut self, instructions: &[Instruction]) -> Result<Sent, CliError> {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            self.svm.latest_blockhash(),
+        );
+        let addresses = transaction.message.account_keys.clone();
+
+        let meta = self.svm.send_transaction(transaction).map_err(|failed| {
+            CliError::TransactionFailed {
+                error: failed.err.to_string(),
+                logs: failed.meta.logs,
+            }
+        })?;
+
+        self.persist(&addresses)?;
+
+        Ok(Sent {
+            signature: meta.signature,
+            logs: meta.logs,
+        })
+    }
+
+    fn account(&self, address: &Address) -> Result<Option<Account>, CliError> {
+        Ok(self.svm.get_account(address))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use generated_clients::{
+        close_account,
+        counter::{self, IncreaseCounterBuilder, InitCounterBuilder, ResetCounterBuilder},
+    };
+    use solana_sdk::signer::Signer;
+    use test_utils::Fixture;
+
+    use super::Sandbox;
+    use crate::backend::{counter_program_data, Target};
+
+    /// Opens the sandbox at `path` with the programs loaded like a
+    /// `test_utils::Fixture` loads them, natively by default, instead of from
+    /// `target/deploy`.
+    fn open(path: &Path) -> Sandbox {
+        let svm = Fixture::builder()
+            .program(counter::PROGRAM_ID, "counter", ::counter::process)
+            .program(
+                close_account::PROGRAM_ID,
+                "close-account",
+                ::close_account::process,
+            )
+            .into_svm();
+
+        Sandbox::restore(svm, path, None).unwrap()
+    }
+
+    fn count(sandbox: &Sandbox) -> u64 {
+        let account = sandbox
+            .account(&counter::find_counter_pda().0)
+            .unwrap()
+            .unwrap();
+
+        counter::Counter::from_bytes(&account.data).unwrap().count
+    }
+
+    #[test]
+    fn persists_accounts_between_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = dir.path().join("sandbox.json");
+
+        let mut sandbox = open(&state);
+        let payer = sandbox.payer().pubkey();
+
+        let instruction = InitCounterBuilder::new()
+            .payer(payer)
+            .init_counter_args(counter::InitCounterArgs {
+                count: 5,
+                max_count: None,
+            })
+            .instruction();
+        sandbox.send(&[instruction]).unwrap();
+        drop(sandbox);
+
+        let mut sandbox = open(&state);
+        assert_eq!(sandbox.payer().pubkey(), payer);
+        assert_eq!(count(&sandbox), 5);
+
+        sandbox
+            .send(&[IncreaseCounterBuilder::new().instruction()])
+            .unwrap();
+        drop(sandbox);
+
+        let sandbox = open(&state);
+        assert_eq!(count(&sandbox), 6);
+    }
+    #[test]
+    fn makes_the_payer_the_counter_admin() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut sandbox = open(&dir.path().join("sandbox.json"));
+        let payer = sandbox.payer().pubkey();
+
+        let init = InitCounterBuilder::new()
+            .payer(payer)
+            .init_counter_args(counter::InitCounterArgs {
+                count: 5,
+                max_count: None,
+            })
+            .instruction();
+        let reset = ResetCounterBuilder::new()
+            .admin(payer)
+            .program_data(counter_program_data())
+            .reset_counter_args(counter::ResetCounterArgs { count: 2 })
+            .instruction();
+        sandbox.send(&[init, reset]).unwrap();
+
+        assert_eq!(count(&sandbox), 2);
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use generated_clients::{
+    close_account::{self, CloseMemeBuilder, CreateMemeBuilder},
+    counter::{
+        self, find_counter_authority_pda, CloseCounterAuthorityBuilder, CloseCounterBuilder,
+        DecreaseCounterArgs, DecreaseCounterAuthorityArgs, DecreaseCounterAuthorityBuilder,
+        DecreaseCounterBuilder, IncreaseCounterAuthorityBuilder, IncreaseCounterAuthorityByArgs,
+        IncreaseCounterAuthorityByBuilder, IncreaseCounterBuilder, IncreaseCounterByArgs,
+        IncreaseCounterByBuilder, InitCounterArgs, InitCounterAuhthorityBuilder,
+        InitCounterAuthorityArgs, InitCounterBuilder, ResetCounterArgs, ResetCounterAuthorityArgs,
+        ResetCounterAuthorityBuilder, ResetCounterBuilder,
+    },
+};
+use shared::event::logs::parse_logs;
+use solana_address::Address;
+use solana_sdk::{instruction::Instruction, signer::Signer};
+
+use crate::{
+    backend::{counter_program_data, Sent, Target},
+    CliError,
+};
+
+pub fn init_counter(
+    target: &mut dyn Target,
+    count: u64,
+    max_count: Option<u64>,
+) -> Result<(), CliError> {
+    let instruction = InitCounterBuilder::new()
+        .payer(target.payer().pubkey())
+        .init_counter_args(InitCounterArgs { count, max_count })
+        .instruction();
+
+    send(target, instruction)
+}
+
+/// Increases the counter by one, or by `amount` with `IncreaseCounterBy`.
+pub fn increase_counter(target: &mut dyn Target, amount: Option<u64>) -> Result<(), CliError> {
+    let instruction = match amount {
+        Some(amount) => IncreaseCounterByBuilder::new()
+            .increase_counter_by_args(IncreaseCounterByArgs { amount })
+            .instruction(),
+        None => IncreaseCounterBuilder::new().instruction(),
+    };
+
+    send(target, instruction)
+}
+
+/// Lowers the counter as the program admin, the upgrade authority of the
+/// program.
+pub fn decrease_counter(target: &mut dyn Target, amount: u64) -> Result<(), CliError> {
+    let instruction = DecreaseCounterBuilder::new()
+        .admin(target.payer().pubkey())
+        .program_data(counter_program_data())
+        .decrease_counter_args(DecreaseCounterArgs { amount })
+        .instruction();
+
+    send(target, instruction)
+}
+
+/// Sets the counter as the program admin, the upgrade authority of the
+/// program.
+pub fn reset_counter(target: &mut dyn Target, count: u64) -> Result<(), CliError> {
+    let instruction = ResetCounterBuilder::new()
+        .admin(target.payer().pubkey())
+        .program_data(counter_program_data())
+        .reset_counter_args(ResetCounterArgs { count })
+        .instruction();
+
+    send(target, instruction)
+}
+
+pub fn init_counter_authority(
+    target: &mut dyn Target,
+    count: u64,
+    max_count: Option<u64>,
+) -> Result<(), CliError> {
+    let payer = target.payer().pubkey();
+
+    let instruction = InitCounterAuhthorityBuilder::new()
+        .payer(payer)
+        .counter_authority(find_counter_authority_pda(&payer).0)
+        .init_counter_authority_args(InitCounterAuthorityArgs { count, max_count })
+        .instruction();
+
+    send(target, instruction)
+}
+
+/// Increases the payer's counter by one, or by `amount` with
+/// `IncreaseCounterAuthorityBy`.
+pub fn increase_counter_authority(
+    target: &mut dyn Target,
+    amount: Option<u64>,
+) -> Result<(), CliError> {
+    let authority = target.payer().pubkey();
+    let counter_authority = find_counter_authority_pda(&authority).0;
+
+    let instruction = match amount {
+        Some(amount) => IncreaseCounterAuthorityByBuilder::new()
+            .authority(authority)
+            .counter_authority(counter_authority)
+            .increase_counter_authority_by_args(IncreaseCounterAuthorityByArgs { amount })
+            .instruction(),
+        None => IncreaseCounterAuthorityBuilder::new()
+            .authority(authority)
+            .counter_authority(counter_authority)
+            .instruction(),
+    };
+
+    send(target, instruction)
+}
+
+pub fn decrease_counter_authority(target: &mut dyn Target, amount: u64) -> Result<(), CliError> {
+    let authority = target.payer().pubkey();
+
+    let instruction = DecreaseCounterAuthorityBuilder::new()
+        .authority(authority)
+        .counter_authority(find_counter_authority_pda(&authority).0)
+        .decrease_counter_authority_args(DecreaseCounterAuthorityArgs { amount })
+        .instruction();
+
+    send(target, instruction)
+}
+
+pub fn reset_counter_authority(target: &mut dyn Target, count: u64) -> Result<(), CliError> {
+    let authority = target.payer().pubkey();
+
+    let instruction = ResetCounterAuthorityBuilder::new()
+        .authority(authority)
+        .counter_authority(find_counter_authority_pda(&authority).0)
+        .reset_counter_authority_args(ResetCounterAuthorityArgs { count })
+        .instruction();
+
+    send(target, instruction)
+}
+
+/// Closes the counter as the program admin, the upgrade authority of the
+/// program, refunding its rent to `destination`, the payer by default.
+pub fn close_counter(
+    target: &mut dyn Target,
+    destination: Option<Address>,
+) -> Result<(), CliError> {
+    let admin = target.payer().pubkey();
+
+    let instruction = CloseCounterBuilder::new()
+        .admin(admin)
+        .destination(destination.unwrap_or(admin))
+        .program_data(counter_program_data())
+        .instruction();
+
+    send(target, instruction)
+}
+
+/// Closes the payer's counter, refunding its rent to `destination`, the payer
+/// by default.
+pub fn close_counter_authority(
+    target: &mut dyn Target,
+    destination: Option<Address>,
+) -> Result<(), CliError> {
+    let authority = target.payer().pubkey();
+
+    let instruction = CloseCounterAuthorityBuilder::new()
+        .authority(authority)
+        .counter_authority(find_counter_authority_pda(&authority).0)
+        .destination(destination.unwrap_or(authority))
+        .instruction();
+
+    send(target, instruction)
+}
+
+pub fn create_meme(target: &mut dyn Target) -> Result<(), CliError> {
+    let instruction = CreateMemeBuilder::new()
+        .payer(target.payer().pubkey())
+        .instruction();
+
+    send(target, instruction)
+}
+
+/// Closes the meme, refunding its rent to `destination`, the payer by default.
+pub fn close_meme(target: &mut dyn Target, destination: Option<Address>) -> Result<(), CliError> {
+    let authority = target.payer().pubkey();
+
+    let instruction = CloseMemeBuilder::new()
+        .authority(authority)
+        .destination(destination.unwrap_or(authority))
+        .instruction();
+
+    send(target, instruction)
+}
+
+fn send(target: &mut dyn Target, instruction: Instruction) -> Result<(), CliError> {
+    let Sent { signature, logs } = target.send(&[instruction])?;
+
+    println!("Signature: {signature}");
+
+    for event in counter_events(&logs)? {
+        println!("{event:?}");
+    }
+
+    Ok(())
+}
+
+/// Prints the `Counter`, `CounterAuthority` or `Meme` at `address`, chosen by
+/// its owner and discriminator.
+pub fn show_account(target: &dyn Target, address: Address) -> Result<(), CliError> {
+    let account = target
+        .account(&address)?
+        .ok_or(CliError::AccountNotFound(address))?;
+
+    let invalid = |error: io::Error| CliError::UnknownAccount {
+        address,
+        reason: error.to_string(),
+    };
+
+    let data = account.data.as_slice();
+
+    if account.owner == counter::PROGRAM_ID {
+        if data.starts_with(&counter::Counter::DISCRIMINATOR) {
+            println!(
+                "{:#?}",
+                counter::Counter::from_bytes(data).map_err(invalid)?
+            );
+        } else {
+            println!(
+                "{:#?}",
+                counter::CounterAuthority::from_bytes(data).map_err(invalid)?
+            );
+        }
+    } else if account.owner == close_account::PROGRAM_ID {
+        println!(
+            "{:#?}",
+            close_account::Meme::from_bytes(data).map_err(invalid)?
+        );
+    } else {
+        return Err(CliError::UnknownAccount {
+            address,
+            reason: format!("owned by {}", account.owner),
+        });
+    }
+
+    Ok(())
+}
+
+/// Prints the counter events of a transaction's logs, one log line per line
+/// of `path`, or of standard input when `path` is `-`.
+pub fn decode_events(path: &Path) -> Result<(), CliError> {
+    let logs = if path == Path::new("-") {
+        let mut logs = String::new();
+        io::stdin().read_to_string(&mut logs)?;
+        logs
+    } else {
+        fs::read_to_string(path)?
+    };
+
+    let logs = logs.lines().collect::<Vec<_>>();
+
+    for event in counter_events(&logs)? {
+        println!("{event:?}");
+    }
+
+    Ok(())
+}
+
+/// The events the counter program logged, skipping any other program's.
+fn counter_events<S: AsRef<str>>(logs: &[S]) -> Result<Vec<counter::CounterEvent>, CliError> {
+    parse_logs(&counter::PROGRAM_ID, logs)
+        .map_err(CliError::InvalidLogs)?
+        .into_iter()
+        .map(|event| {
+            counter::CounterEvent::decode(&event.data).map_err(|source| CliError::InvalidEvent {
+                line: event.line,
+                source,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use borsh::BorshSerialize;
+    use generated_clients::counter::{CounterEvent, CounterIncreased, PROGRAM_ID};
+    use solana_address::Address;
+
+    use super::counter_events;
+
+    #[test]
+    fn decodes_only_the_counter_programs_events() {
+        let event = CounterIncreased {
+            counter: Address::new_from_array([4; 32]),
+            new_count: 3,
+            slot: 1,
+            timestamp: 2,
+        };
+
+        // CounterIncreased's discriminator and version, as listed in the IDL.
+        let mut data = vec![76, 77, 169, 195, 200, 138, 168, 118, 1];
+        event.serialize(&mut data).unwrap();
+        let data = STANDARD.encode(data);
+
+        let other = Address::new_from_array([9; 32]);
+
+        let logs = [
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            format!("Program data: {data}"),
+            format!("Program {other} invoke [2]"),
+            format!("Program data: {data}"),
+            format!("Program {other} success"),
+            format!("Program {PROGRAM_ID} success"),
+        ];
+
+        assert_eq!(
+            counter_events(&logs).unwrap(),
+            [CounterEvent::CounterIncreased(event)]
+        );
+    }
+}
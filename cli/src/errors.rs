@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use shared::event::logs::LogError;
+use solana_address::Address;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid keypair file {0}")]
+    InvalidKeypair(PathBuf),
+
+    #[error("Invalid sandbox state {path}: {reason}")]
+    InvalidState { path: PathBuf, reason: String },
+
+    #[error("Could not load program {path}: {reason}")]
+    InvalidProgram { path: PathBuf, reason: String },
+
+    #[error("RPC request failed: {0}")]
+    Rpc(String),
+
+    #[error("Transaction failed: {error}")]
+    TransactionFailed { error: String, logs: Vec<String> },
+
+    #[error("Account {0} does not exist")]
+    AccountNotFound(Address),
+
+    #[error("Account {address} is not a counter or meme account: {reason}")]
+    UnknownAccount { address: Address, reason: String },
+
+    #[error("Malformed event logs: {0:?}")]
+    InvalidLogs(LogError),
+
+    #[error("Invalid event on log line {line}: {source}")]
+    InvalidEvent { line: usize, source: std::io::Error },
+}
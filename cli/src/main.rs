@@ -0,0 +1,316 @@
+//! `pinocchio-examples`, a CLI for the counter and close-account programs.
+//!
+//! Transactions go to a validator over JSON-RPC, `solana-test-validator` by
+//! default, or with `--sandbox <FILE>` to an in-process LiteSVM whose accounts
+//! are saved to `FILE` after every transaction, so scenarios can be scripted
+//! one command at a time without a validator.
+
+mod backend;
+mod commands;
+mod errors;
+
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::{Args, Parser, Subcommand};
+use solana_address::Address;
+
+use crate::backend::{read_keypair, Rpc, Sandbox, Target};
+
+pub use errors::CliError;
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Interact with the counter and close-account programs"
+)]
+struct Cli {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct TargetArgs {
+    /// JSON-RPC URL of the validator.
+    #[arg(
+        long,
+        global = true,
+        default_value = "http://127.0.0.1:8899",
+        conflicts_with = "sandbox"
+    )]
+    url: String,
+
+    /// Use an in-process LiteSVM whose state persists in FILE instead.
+    #[arg(long, global = true, value_name = "FILE")]
+    sandbox: Option<PathBuf>,
+
+    /// Directory of the program binaries loaded into the sandbox.
+    #[arg(
+        long,
+        global = true,
+        value_name = "DIR",
+        default_value = "target/deploy"
+    )]
+    programs: PathBuf,
+
+    /// Payer and authority keypair. Defaults to the Solana CLI keypair, or to
+    /// the one the sandbox generated on its first run.
+    #[arg(long, global = true, value_name = "FILE")]
+    keypair: Option<PathBuf>,
+}
+
+impl TargetArgs {
+    fn open(self) -> Result<Box<dyn Target>, CliError> {
+        let keypair = self.keypair.as_deref().map(read_keypair).transpose()?;
+
+        Ok(match self.sandbox {
+            Some(path) => Box::new(Sandbox::open(&path, &self.programs, keypair)?),
+            None => {
+                let keypair = match keypair {
+                    Some(keypair) => keypair,
+                    None => {
+                        let home = std::env::var_os("HOME").unwrap_or_default();
+                        read_keypair(&PathBuf::from(home).join(".config/solana/id.json"))?
+                    }
+                };
+
+                Box::new(Rpc::new(self.url, keypair))
+            }
+        })
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// The counter shared by everyone.
+    #[command(subcommand)]
+    Counter(CounterCommand),
+
+    /// The counter only the payer, its authority, can change.
+    #[command(subcommand)]
+    CounterAuthority(CounterCommand),
+
+    /// The close-account program's meme.
+    #[command(subcommand)]
+    Meme(MemeCommand),
+
+    #[command(subcommand)]
+    Account(AccountCommand),
+
+    #[command(subcommand)]
+    Events(EventsCommand),
+}
+
+#[derive(Subcommand)]
+enum CounterCommand {
+    /// Creates the counter.
+    Init {
+        /// Initial count.
+        #[arg(long, default_value_t = 0)]
+        count: u64,
+
+        /// Highest count the counter may reach. Uncapped by default.
+        #[arg(long)]
+        max_count: Option<u64>,
+    },
+
+    /// Increases the counter by one.
+    Increase {
+        /// Increase by this amount instead.
+        #[arg(long)]
+        by: Option<u64>,
+    },
+
+    /// Decreases the counter, failing rather than going below zero. The
+    /// shared one only by the program's upgrade authority.
+    Decrease {
+        #[arg(long, default_value_t = 1)]
+        by: u64,
+    },
+
+    /// Sets the counter back to zero. The shared one only by the program's
+    /// upgrade authority.
+    Reset {
+        /// Set the counter to this count instead.
+        #[arg(long, default_value_t = 0)]
+        count: u64,
+    },
+
+    /// Closes the counter. The shared one only by the program's upgrade
+    /// authority.
+    Close {
+        /// Receives the rent refund. Defaults to the payer.
+        #[arg(long)]
+        destination: Option<Address>,
+    },
+}
+
+#[derive(Subcommand)]
+enum MemeCommand {
+    /// Creates the meme, with the payer as its authority.
+    Create,
+
+    /// Closes the meme.
+    Close {
+        /// Receives the rent refund. Defaults to the payer.
+        #[arg(long)]
+        destination: Option<Address>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AccountCommand {
+    /// Decodes a `Counter`, `CounterAuthority` or `Meme` account.
+    Show { address: Address },
+}
+
+#[derive(Subcommand)]
+enum EventsCommand {
+    /// Decodes the counter events of transaction logs, one line per log.
+    Decode {
+        /// File of log lines, `-` for standard input.
+        logs: PathBuf,
+    },
+}
+
+fn run(cli: Cli) -> Result<(), CliError> {
+    // Only opened by the commands that need it.
+    let target = || cli.target.open();
+
+    match cli.command {
+        Command::Counter(CounterCommand::Init { count, max_count }) => {
+            commands::init_counter(target()?.as_mut(), count, max_count)
+        }
+        Command::Counter(CounterCommand::Increase { by }) => {
+            commands::increase_counter(target()?.as_mut(), by)
+        }
+        Command::Counter(CounterCommand::Decrease { by }) => {
+            commands::decrease_counter(target()?.as_mut(), by)
+        }
+        Command::Counter(CounterCommand::Reset { count }) => {
+            commands::reset_counter(target()?.as_mut(), count)
+        }
+        Command::Counter(CounterCommand::Close { destination }) => {
+            commands::close_counter(target()?.as_mut(), destination)
+        }
+        Command::CounterAuthority(CounterCommand::Init { count, max_count }) => {
+            commands::init_counter_authority(target()?.as_mut(), count, max_count)
+        }
+        Command::CounterAuthority(CounterCommand::Increase { by }) => {
+            commands::increase_counter_authority(target()?.as_mut(), by)
+        }
+        Command::CounterAuthority(CounterCommand::Decrease { by }) => {
+            commands::decrease_counter_authority(target()?.as_mut(), by)
+        }
+        Command::CounterAuthority(CounterCommand::Reset { count }) => {
+            commands::reset_counter_authority(target()?.as_mut(), count)
+        }
+        Command::CounterAuthority(CounterCommand::Close { destination }) => {
+            commands::close_counter_authority(target()?.as_mut(), destination)
+        }
+        Command::Meme(MemeCommand::Create) => commands::create_meme(target()?.as_mut()),
+        Command::Meme(MemeCommand::Close { destination }) => {
+            commands::close_meme(target()?.as_mut(), destination)
+        }
+        Command::Account(AccountCommand::Show { address }) => {
+            commands::show_account(target()?.as_ref(), address)
+        }
+        Command::Events(EventsCommand::Decode { logs }) => commands::decode_events(&logs),
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            if let CliError::TransactionFailed { logs, .. } = &error {
+                for line in logs {
+                    eprintln!("{line}");
+                }
+            }
+
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use clap::{CommandFactory, Parser};
+
+    use super::{Cli, Command, CounterCommand};
+
+    #[test]
+    fn cli_is_well_formed() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parses_global_target_after_the_subcommand() {
+        let cli = Cli::parse_from([
+            "pinocchio-examples",
+            "counter",
+            "init",
+            "--count",
+            "7",
+            "--max-count",
+            "10",
+            "--sandbox",
+            "state.json",
+        ]);
+
+        assert!(matches!(
+            cli.command,
+            Command::Counter(CounterCommand::Init {
+                count: 7,
+                max_count: Some(10)
+            })
+        ));
+        assert_eq!(cli.target.sandbox.unwrap().to_str(), Some("state.json"));
+
+        assert!(Cli::try_parse_from([
+            "pinocchio-examples",
+            "counter",
+            "increase",
+            "--sandbox",
+            "state.json",
+            "--url",
+            "http://localhost:8899",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn parses_counter_amounts() {
+        let cli = Cli::parse_from(["pinocchio-examples", "counter", "increase", "--by", "5"]);
+
+        assert!(matches!(
+            cli.command,
+            Command::Counter(CounterCommand::Increase { by: Some(5) })
+        ));
+
+        let cli = Cli::parse_from(["pinocchio-examples", "counter-authority", "decrease"]);
+
+        assert!(matches!(
+            cli.command,
+            Command::CounterAuthority(CounterCommand::Decrease { by: 1 })
+        ));
+
+        let cli = Cli::parse_from(["pinocchio-examples", "counter", "reset"]);
+
+        assert!(matches!(
+            cli.command,
+            Command::Counter(CounterCommand::Reset { count: 0 })
+        ));
+
+        let cli = Cli::parse_from(["pinocchio-examples", "counter-authority", "close"]);
+
+        assert!(matches!(
+            cli.command,
+            Command::CounterAuthority(CounterCommand::Close { destination: None })
+        ));
+    }
+}
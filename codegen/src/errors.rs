@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CodegenError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid IDL: {0}")]
+    InvalidIdl(#[from] serde_json::Error),
+
+    #[error("Invalid program address {0}")]
+    InvalidProgramAddress(String),
+
+    #[error("Unsupported type {0}")]
+    UnsupportedType(String),
+
+    #[error("Unsupported seed of account {account}: {reason}")]
+    UnsupportedSeed { account: String, reason: String },
+
+    #[error("Instruction {0} has a non-u8 discriminant")]
+    UnsupportedDiscriminant(String),
+
+    #[error("Enum {0} has variants with fields")]
+    UnsupportedEnum(String),
+
+    #[error("Event {0} has no matching type")]
+    MissingEventType(String),
+
+    #[error("Generated code does not parse: {0}")]
+    InvalidOutput(#[from] syn::Error),
+}
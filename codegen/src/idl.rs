@@ -0,0 +1,121 @@
+//! The subset of the Shank IDL format the generator understands.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct Idl {
+    pub name: String,
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub accounts: Vec<IdlTypeDef>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+    #[serde(default)]
+    pub errors: Vec<IdlError>,
+    /// Added by the programs' IDL sync tests, Shank does not emit it.
+    #[serde(default)]
+    pub events: Vec<IdlEvent>,
+    pub metadata: IdlMetadata,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    pub accounts: Vec<IdlInstructionAccount>,
+    pub args: Vec<IdlField>,
+    pub discriminant: IdlDiscriminant,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlInstructionAccount {
+    pub name: String,
+    pub is_mut: bool,
+    pub is_signer: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlDiscriminant {
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub value: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefTy,
+    #[serde(default)]
+    pub seeds: Vec<IdlSeed>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum IdlTypeDefTy {
+    Struct { fields: Vec<IdlField> },
+    Enum { variants: Vec<IdlEnumVariant> },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlEnumVariant {
+    pub name: String,
+    #[serde(default)]
+    pub fields: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+    #[serde(default)]
+    pub attrs: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum IdlType {
+    Primitive(String),
+    Array { array: (Box<IdlType>, usize) },
+    Defined { defined: String },
+    Option { option: Box<IdlType> },
+    Vec { vec: Box<IdlType> },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum IdlSeed {
+    Constant {
+        #[serde(rename = "type")]
+        ty: IdlType,
+        value: Value,
+    },
+    ProgramId,
+    Variable {
+        name: String,
+        description: String,
+        #[serde(rename = "type")]
+        ty: IdlType,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlError {
+    pub code: u32,
+    pub name: String,
+    pub msg: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlEvent {
+    pub name: String,
+    pub discriminator: [u8; 8],
+    pub version: u8,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlMetadata {
+    pub address: String,
+}
@@ -0,0 +1,119 @@
+//! Generates a Rust client module from a Shank IDL.
+//!
+//! The module holds the program id, its errors, the IDL types, the account
+//! types with their PDA helpers, one builder per instruction and, when the
+//! IDL lists them, an enum decoding the program's events.
+
+mod errors;
+pub mod idl;
+mod render;
+
+pub use errors::CodegenError;
+
+use idl::Idl;
+
+/// Generates the client of the IDL in `idl_json`. `source` is the IDL path
+/// quoted in the module docs.
+pub fn generate(idl_json: &str, source: &str) -> Result<String, CodegenError> {
+    let idl: Idl = serde_json::from_str(idl_json)?;
+    let file: syn::File = syn::parse2(render::render(&idl, source)?)?;
+
+    // prettyplease packs items together, unparse them one at a time to keep
+    // a blank line between all but consecutive `use`s.
+    let unparse = |attrs, items| {
+        prettyplease::unparse(&syn::File {
+            shebang: None,
+            attrs,
+            items,
+        })
+    };
+
+    let mut client = unparse(file.attrs, Vec::new());
+    let mut previous_use = false;
+
+    for item in file.items {
+        let is_use = matches!(item, syn::Item::Use(_));
+        if !(previous_use && is_use) {
+            client.push('\n');
+        }
+        previous_use = is_use;
+
+        client.push_str(&unparse(Vec::new(), vec![item]));
+    }
+
+    Ok(client)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path};
+
+    use super::{generate, CodegenError};
+
+    const IDLS: [&str; 4] = ["close_account", "counter", "create_mint", "transfer_mint"];
+
+    /// Regenerates the checked-in clients, rewriting them when
+    /// `UPDATE_CLIENTS` is set.
+    #[test]
+    fn golden_clients_are_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+        for name in IDLS {
+            let source = format!("idl/{name}.json");
+            let idl = fs::read_to_string(root.join(&source)).unwrap();
+            let client = generate(&idl, &source).unwrap();
+
+            let golden = root.join(format!("rust-client/generated/src/{name}.rs"));
+
+            if env::var_os("UPDATE_CLIENTS").is_some() {
+                fs::write(&golden, client).unwrap();
+            } else {
+                assert_eq!(
+                    fs::read_to_string(&golden).unwrap_or_default(),
+                    client,
+                    "{name} client is stale, run `pnpm gen:rust-client`"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_what_it_cannot_generate() {
+        let idl = |address: &str, discriminant: &str, arg: &str| {
+            format!(
+                r#"{{
+                    "name": "example",
+                    "instructions": [{{
+                        "name": "Run",
+                        "accounts": [],
+                        "args": [{{ "name": "arg", "type": {arg} }}],
+                        "discriminant": {discriminant}
+                    }}],
+                    "metadata": {{ "address": "{address}" }}
+                }}"#
+            )
+        };
+        let address = "11111111111111111111111111111111";
+        let u8_discriminant = r#"{ "type": "u8", "value": 0 }"#;
+
+        assert!(generate(&idl(address, u8_discriminant, r#""u64""#), "example.json").is_ok());
+        assert!(matches!(
+            generate(
+                &idl("not-an-address", u8_discriminant, r#""u64""#),
+                "example.json"
+            ),
+            Err(CodegenError::InvalidProgramAddress(_))
+        ));
+        assert!(matches!(
+            generate(
+                &idl(address, r#"{ "type": "u16", "value": 0 }"#, r#""u64""#),
+                "example.json"
+            ),
+            Err(CodegenError::UnsupportedDiscriminant(_))
+        ));
+        assert!(matches!(
+            generate(&idl(address, u8_discriminant, r#""u256""#), "example.json"),
+            Err(CodegenError::UnsupportedType(_))
+        ));
+    }
+}
@@ -0,0 +1,40 @@
+use std::{fs, process::ExitCode};
+
+use idl_codegen::{generate, CodegenError};
+
+const USAGE: &str = "usage: idl-codegen <idl.json> [out.rs]
+
+Generates the Rust client of a Shank IDL. Writes to standard output when no
+<out.rs> is given.";
+
+fn run(source: &str, out: Option<&str>) -> Result<(), CodegenError> {
+    let client = generate(&fs::read_to_string(source)?, source)?;
+
+    match out {
+        Some(out) => fs::write(out, client)?,
+        None => print!("{client}"),
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let (source, out) = match &args[..] {
+        [source] => (source, None),
+        [source, out] => (source, Some(out.as_str())),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(source, out) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{source}: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
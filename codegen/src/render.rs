@@ -0,0 +1,662 @@
+use std::str::FromStr;
+
+use heck::{ToPascalCase, ToSnakeCase};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use serde_json::Value;
+use shared::discriminator::discriminator;
+use solana_address::Address;
+
+use crate::{
+    idl::{
+        Idl, IdlError, IdlEvent, IdlField, IdlInstruction, IdlSeed, IdlType, IdlTypeDef,
+        IdlTypeDefTy,
+    },
+    CodegenError,
+};
+
+const SYSTEM_PROGRAM: &str = "systemProgram";
+
+/// Renders the client module of `idl`, read from `source`.
+pub fn render(idl: &Idl, source: &str) -> Result<TokenStream, CodegenError> {
+    let address = &idl.metadata.address;
+    Address::from_str(address).map_err(|_| CodegenError::InvalidProgramAddress(address.clone()))?;
+
+    let header = docs(&[
+        &format!(
+            "Client for the `{}` program, generated by `idl-codegen` from",
+            idl.name
+        ),
+        &format!("`{source}`. Do not edit, run `pnpm gen:rust-client` instead."),
+    ]);
+
+    let system_program = idl
+        .instructions
+        .iter()
+        .flat_map(|ix| &ix.accounts)
+        .any(|account| account.name == SYSTEM_PROGRAM)
+        .then(|| {
+            quote! {
+                const SYSTEM_PROGRAM_ID: Address =
+                    Address::from_str_const("11111111111111111111111111111111");
+            }
+        });
+
+    let errors = render_errors(idl);
+    let types = idl
+        .types
+        .iter()
+        .map(|ty| render_type_def(ty, None))
+        .collect::<Result<Vec<_>, _>>()?;
+    let accounts = idl
+        .accounts
+        .iter()
+        .map(|account| render_account(idl, account))
+        .collect::<Result<Vec<_>, _>>()?;
+    let instructions = idl
+        .instructions
+        .iter()
+        .map(|ix| render_instruction(idl, ix))
+        .collect::<Result<Vec<_>, _>>()?;
+    let events = render_events(idl)?;
+
+    Ok(quote! {
+        #(#![doc = #header])*
+
+        use borsh::{BorshDeserialize, BorshSerialize};
+        use solana_address::Address;
+        use solana_instruction::{AccountMeta, Instruction};
+
+        pub const PROGRAM_ID: Address = Address::from_str_const(#address);
+
+        #system_program
+
+        #errors
+
+        #(#types)*
+
+        #(#accounts)*
+
+        #(#instructions)*
+
+        #events
+    })
+}
+
+/// `#[doc]` strings for `lines`, with the leading space of a `///` comment.
+fn docs(lines: &[&str]) -> Vec<String> {
+    lines
+        .iter()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!(" {line}")
+            }
+        })
+        .collect()
+}
+
+fn rust_type(ty: &IdlType, attrs: &[String]) -> Result<TokenStream, CodegenError> {
+    Ok(match ty {
+        IdlType::Primitive(name) => match name.as_str() {
+            "bool" | "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64"
+            | "i128" | "f32" | "f64" => {
+                let ident = format_ident!("{name}");
+                quote!(#ident)
+            }
+            "string" => quote!(String),
+            "publicKey" => quote!(Address),
+            "bytes" => quote!(Vec<u8>),
+            _ => return Err(CodegenError::UnsupportedType(name.clone())),
+        },
+        // The programs declare addresses as `#[idl_type("[u8;32]")] Address`,
+        // which Shank only records as an `idl-type` attribute.
+        IdlType::Array { array: (inner, 32) }
+            if attrs.iter().any(|attr| attr == "idl-type")
+                && matches!(&**inner, IdlType::Primitive(name) if name == "u8") =>
+        {
+            quote!(Address)
+        }
+        IdlType::Array {
+            array: (inner, len),
+        } => {
+            let inner = rust_type(inner, &[])?;
+            let len = Literal::usize_unsuffixed(*len);
+            quote!([#inner; #len])
+        }
+        IdlType::Defined { defined } => {
+            let ident = format_ident!("{}", defined);
+            quote!(#ident)
+        }
+        IdlType::Option { option } => {
+            let inner = rust_type(option, &[])?;
+            quote!(Option<#inner>)
+        }
+        IdlType::Vec { vec } => {
+            let inner = rust_type(vec, &[])?;
+            quote!(Vec<#inner>)
+        }
+    })
+}
+
+/// Borsh size of `ty`, or `None` when it varies.
+fn fixed_size(idl: &Idl, ty: &IdlType) -> Option<usize> {
+    match ty {
+        IdlType::Primitive(name) => match name.as_str() {
+            "bool" | "u8" | "i8" => Some(1),
+            "u16" | "i16" => Some(2),
+            "u32" | "i32" | "f32" => Some(4),
+            "u64" | "i64" | "f64" => Some(8),
+            "u128" | "i128" => Some(16),
+            "publicKey" => Some(32),
+            _ => None,
+        },
+        IdlType::Array {
+            array: (inner, len),
+        } => Some(fixed_size(idl, inner)? * len),
+        IdlType::Defined { defined } => {
+            let def = idl.types.iter().find(|ty| &ty.name == defined)?;
+
+            match &def.ty {
+                IdlTypeDefTy::Struct { fields } => fields_size(idl, fields),
+                IdlTypeDefTy::Enum { .. } => Some(1),
+            }
+        }
+        IdlType::Option { .. } | IdlType::Vec { .. } => None,
+    }
+}
+
+fn fields_size(idl: &Idl, fields: &[IdlField]) -> Option<usize> {
+    fields.iter().map(|field| fixed_size(idl, &field.ty)).sum()
+}
+
+fn render_type_def(def: &IdlTypeDef, doc: Option<&str>) -> Result<TokenStream, CodegenError> {
+    let ident = format_ident!("{}", def.name);
+    let doc = doc.map(|doc| docs(&[doc])).unwrap_or_default();
+
+    match &def.ty {
+        IdlTypeDefTy::Struct { fields } => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    let name = format_ident!("{}", field.name.to_snake_case());
+                    let ty = rust_type(&field.ty, &field.attrs)?;
+                    Ok(quote!(pub #name: #ty))
+                })
+                .collect::<Result<Vec<_>, CodegenError>>()?;
+
+            Ok(quote! {
+                #(#[doc = #doc])*
+                #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+                pub struct #ident {
+                    #(#fields,)*
+                }
+            })
+        }
+        IdlTypeDefTy::Enum { variants } => {
+            if variants.iter().any(|variant| variant.fields.is_some()) {
+                return Err(CodegenError::UnsupportedEnum(def.name.clone()));
+            }
+
+            let variants = variants
+                .iter()
+                .map(|variant| format_ident!("{}", variant.name));
+
+            Ok(quote! {
+                #(#[doc = #doc])*
+                #[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+                pub enum #ident {
+                    #(#variants,)*
+                }
+            })
+        }
+    }
+}
+
+fn pda_fn(account: &str) -> proc_macro2::Ident {
+    format_ident!("find_{}_pda", account.to_snake_case())
+}
+
+fn render_account(idl: &Idl, account: &IdlTypeDef) -> Result<TokenStream, CodegenError> {
+    let ident = format_ident!("{}", account.name);
+    let def = render_type_def(account, None)?;
+
+    let IdlTypeDefTy::Struct { fields } = &account.ty else {
+        return Ok(def);
+    };
+
+    let discriminated = fields.first().is_some_and(|field| {
+        field.name == "discriminator" && matches!(&field.ty, IdlType::Array { array: (_, 8) })
+    });
+
+    let discriminator_const = discriminated.then(|| {
+        let bytes = discriminator(format!("account:{}", account.name).as_bytes());
+        let bytes = bytes.iter().map(|byte| Literal::u8_unsuffixed(*byte));
+
+        quote! {
+            pub const DISCRIMINATOR: [u8; 8] = [#(#bytes),*];
+        }
+    });
+
+    let len_const = fields_size(idl, fields).map(|len| {
+        let len = Literal::usize_unsuffixed(len);
+        quote!(pub const LEN: usize = #len;)
+    });
+
+    let discriminator_check = discriminated.then(|| {
+        quote! {
+            if !data.starts_with(&Self::DISCRIMINATOR) {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "account discriminator mismatch",
+                ));
+            }
+        }
+    });
+
+    let from_bytes_doc = if discriminated {
+        docs(&["Decodes the account data, checking its discriminator first."])
+    } else {
+        docs(&["Decodes the account data."])
+    };
+
+    let pda = render_pda(account)?;
+
+    Ok(quote! {
+        #def
+
+        impl #ident {
+            #discriminator_const
+
+            #len_const
+
+            #(#[doc = #from_bytes_doc])*
+            pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+                #discriminator_check
+
+                Self::try_from_slice(data)
+            }
+        }
+
+        #pda
+    })
+}
+
+fn render_pda(account: &IdlTypeDef) -> Result<Option<TokenStream>, CodegenError> {
+    if account.seeds.is_empty() {
+        return Ok(None);
+    }
+
+    let unsupported = |reason: &str| CodegenError::UnsupportedSeed {
+        account: account.name.clone(),
+        reason: reason.to_string(),
+    };
+
+    let mut params = Vec::new();
+    let mut seeds = Vec::new();
+    let mut shown = Vec::new();
+    let mut variables = Vec::new();
+
+    for seed in &account.seeds {
+        match seed {
+            IdlSeed::Constant { value, .. } => {
+                let Value::String(value) = value else {
+                    return Err(unsupported("only string constants are supported"));
+                };
+
+                let literal = Literal::byte_string(value.as_bytes());
+                seeds.push(quote!(#literal));
+                shown.push(format!("\"{value}\""));
+            }
+            IdlSeed::ProgramId => {
+                seeds.push(quote!(PROGRAM_ID.as_ref()));
+                shown.push("program_id".to_string());
+            }
+            IdlSeed::Variable {
+                name,
+                description,
+                ty,
+            } => {
+                let param = format_ident!("{}", name.to_snake_case());
+
+                let (param_ty, seed) = match ty {
+                    IdlType::Primitive(ty) if ty == "publicKey" => {
+                        (quote!(&Address), quote!(#param.as_ref()))
+                    }
+                    IdlType::Primitive(ty) if ty == "string" => {
+                        (quote!(&str), quote!(#param.as_bytes()))
+                    }
+                    IdlType::Primitive(ty) if ty == "u8" => (quote!(u8), quote!(&[#param])),
+                    _ => return Err(unsupported("unsupported variable seed type")),
+                };
+
+                params.push(quote!(#param: #param_ty));
+                seeds.push(seed);
+                shown.push(param.to_string());
+                variables.push(format!("* `{param}` - {description}",));
+            }
+        }
+    }
+
+    let summary = format!(
+        "Derives the [`{}`] PDA from `[{}]`.",
+        account.name,
+        shown.join(", ")
+    );
+    let mut lines = vec![summary.as_str()];
+    if !variables.is_empty() {
+        lines.push("");
+        lines.extend(variables.iter().map(String::as_str));
+    }
+    let doc = docs(&lines);
+
+    let ident = pda_fn(&account.name);
+
+    Ok(Some(quote! {
+        #(#[doc = #doc])*
+        pub fn #ident(#(#params),*) -> (Address, u8) {
+            Address::find_program_address(&[#(#seeds),*], &PROGRAM_ID)
+        }
+    }))
+}
+
+/// The PDA an instruction account defaults to: the account type of the same
+/// name, when its seeds need no arguments.
+fn default_pda(idl: &Idl, account: &str) -> Option<proc_macro2::Ident> {
+    idl.accounts
+        .iter()
+        .find(|def| def.name.to_snake_case() == account.to_snake_case())
+        .filter(|def| {
+            !def.seeds.is_empty()
+                && def
+                    .seeds
+                    .iter()
+                    .all(|seed| !matches!(seed, IdlSeed::Variable { .. }))
+        })
+        .map(|def| pda_fn(&def.name))
+}
+
+fn render_instruction(idl: &Idl, ix: &IdlInstruction) -> Result<TokenStream, CodegenError> {
+    if ix.discriminant.ty != "u8" {
+        return Err(CodegenError::UnsupportedDiscriminant(ix.name.clone()));
+    }
+    let discriminant = u8::try_from(ix.discriminant.value)
+        .map_err(|_| CodegenError::UnsupportedDiscriminant(ix.name.clone()))?;
+    let discriminant = Literal::u8_unsuffixed(discriminant);
+
+    let builder = format_ident!("{}Builder", ix.name.to_pascal_case());
+
+    let mut account_docs = Vec::new();
+    let mut fields = Vec::new();
+    let mut setters = Vec::new();
+    let mut metas = Vec::new();
+    let mut required = Vec::new();
+
+    for (index, account) in ix.accounts.iter().enumerate() {
+        let name = format_ident!("{}", account.name.to_snake_case());
+
+        let flags = match (account.is_mut, account.is_signer) {
+            (true, true) => "[writable, signer]",
+            (true, false) => "[writable]",
+            (false, true) => "[signer]",
+            (false, false) => "[]",
+        };
+
+        let (value, default) = if account.name == SYSTEM_PROGRAM {
+            (
+                quote!(self.#name.unwrap_or(SYSTEM_PROGRAM_ID)),
+                ", defaults to the system program".to_string(),
+            )
+        } else if let Some(pda) = default_pda(idl, &account.name) {
+            (
+                quote!(self.#name.unwrap_or_else(|| #pda().0)),
+                format!(", defaults to [`{pda}`]"),
+            )
+        } else {
+            let message = format!("{name} is not set");
+            required.push(format!("`{name}`"));
+            (quote!(self.#name.expect(#message)), String::new())
+        };
+
+        account_docs.push(format!("  {index}. `{flags}` {name}{default}"));
+
+        let is_signer = account.is_signer;
+        metas.push(if account.is_mut {
+            quote!(AccountMeta::new(#value, #is_signer))
+        } else {
+            quote!(AccountMeta::new_readonly(#value, #is_signer))
+        });
+
+        fields.push(quote!(#name: Option<Address>));
+        setters.push(quote! {
+            pub fn #name(&mut self, #name: Address) -> &mut Self {
+                self.#name = Some(#name);
+                self
+            }
+        });
+    }
+
+    let mut serialize = Vec::new();
+
+    for arg in &ix.args {
+        let name = format_ident!("{}", arg.name.to_snake_case());
+        let ty = rust_type(&arg.ty, &arg.attrs)?;
+        let message = format!("{name} is not set");
+
+        required.push(format!("`{name}`"));
+        fields.push(quote!(#name: Option<#ty>));
+        setters.push(quote! {
+            pub fn #name(&mut self, #name: #ty) -> &mut Self {
+                self.#name = Some(#name);
+                self
+            }
+        });
+        serialize.push(quote! {
+            self.#name.as_ref().expect(#message).serialize(&mut data).unwrap();
+        });
+    }
+
+    let data = if serialize.is_empty() {
+        quote!(let data = vec![Self::DISCRIMINANT];)
+    } else {
+        quote! {
+            let mut data = vec![Self::DISCRIMINANT];
+            #(#serialize)*
+        }
+    };
+
+    let summary = format!("Builds `{}`.", ix.name);
+    let mut lines = vec![summary.as_str(), "", "### Accounts:", ""];
+    lines.extend(account_docs.iter().map(String::as_str));
+    let doc = docs(&lines);
+
+    let panics = match required.split_last() {
+        Some((last, [])) => format!("When {last} is not set."),
+        Some((last, rest)) => format!("When {} or {last} is not set.", rest.join(", ")),
+        None => String::new(),
+    };
+    let instruction_doc = if required.is_empty() {
+        Vec::new()
+    } else {
+        docs(&["# Panics", "", &panics])
+    };
+
+    Ok(quote! {
+        #(#[doc = #doc])*
+        #[derive(Clone, Debug, Default)]
+        pub struct #builder {
+            #(#fields,)*
+            remaining_accounts: Vec<AccountMeta>,
+        }
+
+        impl #builder {
+            pub const DISCRIMINANT: u8 = #discriminant;
+
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #(#setters)*
+
+            pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+                self.remaining_accounts.extend_from_slice(accounts);
+                self
+            }
+
+            #(#[doc = #instruction_doc])*
+            pub fn instruction(&self) -> Instruction {
+                let accounts = [#(#metas),*];
+
+                #data
+
+                Instruction {
+                    program_id: PROGRAM_ID,
+                    accounts: accounts
+                        .into_iter()
+                        .chain(self.remaining_accounts.iter().cloned())
+                        .collect(),
+                    data,
+                }
+            }
+        }
+    })
+}
+
+fn render_errors(idl: &Idl) -> Option<TokenStream> {
+    if idl.errors.is_empty() {
+        return None;
+    }
+
+    let ident = format_ident!("{}Error", idl.name.to_pascal_case());
+
+    let variant = |error: &IdlError| format_ident!("{}", error.name);
+
+    let variants = idl.errors.iter().map(|error| {
+        let name = variant(error);
+        let code = Literal::u32_unsuffixed(error.code);
+        quote!(#name = #code)
+    });
+
+    let from_code = idl.errors.iter().map(|error| {
+        let name = variant(error);
+        let code = Literal::u32_unsuffixed(error.code);
+        quote!(#code => Some(Self::#name))
+    });
+
+    let messages = idl.errors.iter().map(|error| {
+        let name = variant(error);
+        let message = error.msg.as_deref().unwrap_or(&error.name);
+        quote!(Self::#name => #message)
+    });
+
+    let doc = docs(&["Custom program errors, surfaced as `ProgramError::Custom(code)`."]);
+
+    Some(quote! {
+        #(#[doc = #doc])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[repr(u32)]
+        pub enum #ident {
+            #(#variants,)*
+        }
+
+        impl #ident {
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    #(#from_code,)*
+                    _ => None,
+                }
+            }
+
+            pub fn message(self) -> &'static str {
+                match self {
+                    #(#messages,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for #ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.message())
+            }
+        }
+
+        impl std::error::Error for #ident {}
+    })
+}
+
+fn render_events(idl: &Idl) -> Result<Option<TokenStream>, CodegenError> {
+    if idl.events.is_empty() {
+        return Ok(None);
+    }
+
+    let ident = format_ident!("{}Event", idl.name.to_pascal_case());
+
+    let check = |event: &IdlEvent| {
+        idl.types
+            .iter()
+            .any(|ty| ty.name == event.name)
+            .then_some(())
+            .ok_or_else(|| CodegenError::MissingEventType(event.name.clone()))
+    };
+
+    let variants = idl
+        .events
+        .iter()
+        .map(|event| {
+            check(event)?;
+            let name = format_ident!("{}", event.name);
+            Ok(quote!(#name(#name)))
+        })
+        .collect::<Result<Vec<_>, CodegenError>>()?;
+
+    let arms = idl.events.iter().map(|event| {
+        let name = format_ident!("{}", event.name);
+        let header = event
+            .discriminator
+            .iter()
+            .chain([&event.version])
+            .map(|byte| Literal::u8_unsuffixed(*byte));
+
+        quote! {
+            [#(#header),*] => Ok(Self::#name(#name::try_from_slice(payload)?))
+        }
+    });
+
+    let doc = docs(&[
+        "Every event the program emits. Encoded as",
+        "`discriminator (8) ++ version (1) ++ borsh(event)`.",
+    ]);
+    let decode_doc = docs(&[
+        "Decodes an event, as logged in a `Program data:` line or carried by a",
+        "self-CPI once its instruction tag is stripped.",
+    ]);
+
+    Ok(Some(quote! {
+        #(#[doc = #doc])*
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum #ident {
+            #(#variants,)*
+        }
+
+        impl #ident {
+            #(#[doc = #decode_doc])*
+            pub fn decode(data: &[u8]) -> std::io::Result<Self> {
+                let Some((header, payload)) = data.split_at_checked(9) else {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        "event is truncated",
+                    ));
+                };
+
+                match header {
+                    #(#arms,)*
+                    _ => Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "unknown event discriminator or version",
+                    )),
+                }
+            }
+        }
+    }))
+}
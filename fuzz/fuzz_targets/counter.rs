@@ -0,0 +1,27 @@
+#![no_main]
+
+use std::cell::RefCell;
+
+use counter::{fuzz, ID};
+use libfuzzer_sys::fuzz_target;
+use proptest::strategy::BoxedStrategy;
+use test_utils::{
+    fuzz::{cases, from_fuzz_input, run, Case},
+    Fixture,
+};
+
+thread_local! {
+    // Every case presets all of its accounts, so inputs share one fixture.
+    static FIXTURE: RefCell<Fixture> = RefCell::new({
+        let mut fixture = Fixture::new(ID, "counter", counter::process);
+        fuzz::set_up(&mut fixture);
+        fixture
+    });
+    static CASES: BoxedStrategy<Case> = cases(fuzz::target());
+}
+
+fuzz_target!(|input: &[u8]| {
+    let case = CASES.with(|cases| from_fuzz_input(cases, input));
+
+    FIXTURE.with_borrow_mut(|fixture| fuzz::assert_invariants(&run(fixture, &case)));
+});
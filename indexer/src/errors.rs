@@ -0,0 +1,20 @@
+use shared::event::logs::LogError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    #[error("Database error: {0}")]
+    Database(#[from] rusqlite::Error),
+
+    #[error("Could not read transactions: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid transaction record on line {line}: {source}")]
+    InvalidRecord {
+        line: usize,
+        source: serde_json::Error,
+    },
+
+    #[error("Transaction {signature} has malformed event logs: {error:?}")]
+    InvalidLogs { signature: String, error: LogError },
+}
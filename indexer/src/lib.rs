@@ -0,0 +1,15 @@
+//! Local indexer for the events of `basic/counter`.
+//!
+//! Transactions come from a JSON-lines file of `{ "signature", "logs" }`
+//! records or straight from a LiteSVM replay, no RPC involved. Their
+//! `CounterEvent`s are stored in SQLite, in a `counter_events` history table
+//! and a `counters` table holding the latest state of each counter. Every
+//! transaction is recorded by signature, so ingesting it again is a no-op.
+
+mod errors;
+mod source;
+mod store;
+
+pub use errors::IndexerError;
+pub use source::{read_transactions, LoggedTransaction};
+pub use store::{CounterEventKind, CounterRecord, CounterState, Indexer};
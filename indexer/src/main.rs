@@ -0,0 +1,58 @@
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    process::ExitCode,
+};
+
+use counter_indexer::{read_transactions, Indexer, IndexerError};
+use pinocchio::Address;
+
+const USAGE: &str = "usage: counter-indexer <database> [transactions.jsonl]
+
+Stores the counter events of each JSON-lines transaction record
+({\"signature\": ..., \"err\": ..., \"logs\": [...]}) in the SQLite <database>.
+Reads standard input when no file is given.";
+
+fn run(database: &str, input: Option<&str>) -> Result<(), IndexerError> {
+    let mut indexer = Indexer::open(database, Address::new_from_array(counter::ID))?;
+
+    let reader: Box<dyn io::BufRead> = match input {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let (mut ingested, mut skipped) = (0, 0);
+
+    for transaction in read_transactions(reader) {
+        if indexer.ingest(&transaction?)? {
+            ingested += 1;
+        } else {
+            skipped += 1;
+        }
+    }
+
+    println!("Ingested {ingested} transactions, skipped {skipped} failed or already ingested");
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let (database, input) = match &args[..] {
+        [database] => (database, None),
+        [database, input] => (database, Some(input.as_str())),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(database, input) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
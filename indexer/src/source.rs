@@ -0,0 +1,92 @@
+use std::io::BufRead;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::IndexerError;
+
+/// One transaction as read from a JSON-lines file.
+///
+/// `err` follows the RPC's `meta.err`: anything but `null` marks a failed
+/// transaction, whose events were rolled back and are not indexed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LoggedTransaction {
+    pub signature: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+}
+
+impl LoggedTransaction {
+    pub fn succeeded(&self) -> bool {
+        self.err.as_ref().is_none_or(Value::is_null)
+    }
+}
+
+#[cfg(feature = "litesvm")]
+impl From<&litesvm::types::TransactionMetadata> for LoggedTransaction {
+    fn from(meta: &litesvm::types::TransactionMetadata) -> Self {
+        Self {
+            signature: meta.signature.to_string(),
+            err: None,
+            logs: meta.logs.clone(),
+        }
+    }
+}
+
+/// Reads one [`LoggedTransaction`] per non-empty line of `reader`.
+pub fn read_transactions(
+    reader: impl BufRead,
+) -> impl Iterator<Item = Result<LoggedTransaction, IndexerError>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|(index, line)| {
+            serde_json::from_str(&line?).map_err(|source| IndexerError::InvalidRecord {
+                line: index + 1,
+                source,
+            })
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::read_transactions;
+    use crate::IndexerError;
+
+    #[test]
+    fn reads_one_transaction_per_line() {
+        let input = concat!(
+            r#"{"signature":"a","logs":["Program log: hi"]}"#,
+            "\n\n",
+            r#"{"signature":"b","err":{"InstructionError":[0,{"Custom":6000}]},"logs":[]}"#,
+            "\n",
+            r#"{"signature":"c","err":null,"logs":[]}"#,
+            "\n",
+        );
+
+        let transactions = read_transactions(input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(transactions.len(), 3);
+        assert_eq!(transactions[0].signature, "a");
+        assert_eq!(transactions[0].logs, ["Program log: hi"]);
+        assert!(transactions[0].succeeded());
+        assert!(!transactions[1].succeeded());
+        assert!(transactions[2].succeeded());
+    }
+
+    #[test]
+    fn reports_line_of_invalid_record() {
+        let input = "{\"signature\":\"a\",\"logs\":[]}\n{\"signature\":\"b\"}\n";
+
+        let result = read_transactions(input.as_bytes()).collect::<Result<Vec<_>, _>>();
+
+        assert!(matches!(
+            result,
+            Err(IndexerError::InvalidRecord { line: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,775 @@
+use std::path::Path;
+
+use counter::events::CounterEvent;
+use pinocchio::Address;
+use rusqlite::{
+    params,
+    types::{FromSql, FromSqlResult, ToSqlOutput, ValueRef},
+    Connection, OptionalExtension, ToSql,
+};
+
+use crate::{IndexerError, LoggedTransaction};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS transactions (
+        signature   TEXT PRIMARY KEY,
+        event_count INTEGER NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS counter_events (
+        signature   TEXT NOT NULL REFERENCES transactions (signature),
+        event_index INTEGER NOT NULL,
+        kind        TEXT NOT NULL,
+        counter     TEXT NOT NULL,
+        authority   TEXT,
+        payer       TEXT,
+        count       BLOB NOT NULL,
+        slot        BLOB NOT NULL,
+        timestamp   INTEGER NOT NULL,
+        PRIMARY KEY (signature, event_index)
+    );
+
+    CREATE INDEX IF NOT EXISTS counter_events_by_counter
+        ON counter_events (counter, slot);
+
+    CREATE TABLE IF NOT EXISTS counters (
+        counter   TEXT PRIMARY KEY,
+        authority TEXT,
+        count     BLOB NOT NULL,
+        slot      BLOB NOT NULL,
+        timestamp INTEGER NOT NULL,
+        signature TEXT NOT NULL,
+        closed    INTEGER NOT NULL
+    );
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CounterEventKind {
+    Initialized,
+    Increased,
+    AuthorityInitialized,
+    AuthorityIncreased,
+    Decreased,
+    Reset,
+    AuthorityDecreased,
+    AuthorityReset,
+    Closed,
+    AuthorityClosed,
+}
+
+impl CounterEventKind {
+    fn closes(self) -> bool {
+        matches!(self, Self::Closed | Self::AuthorityClosed)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Initialized => "initialized",
+            Self::Increased => "increased",
+            Self::AuthorityInitialized => "authority_initialized",
+            Self::AuthorityIncreased => "authority_increased",
+            Self::Decreased => "decreased",
+            Self::Reset => "reset",
+            Self::AuthorityDecreased => "authority_decreased",
+            Self::AuthorityReset => "authority_reset",
+            Self::Closed => "closed",
+            Self::AuthorityClosed => "authority_closed",
+        }
+    }
+
+    fn parse(kind: &str) -> Option<Self> {
+        [
+            Self::Initialized,
+            Self::Increased,
+            Self::AuthorityInitialized,
+            Self::AuthorityIncreased,
+            Self::Decreased,
+            Self::Reset,
+            Self::AuthorityDecreased,
+            Self::AuthorityReset,
+            Self::Closed,
+            Self::AuthorityClosed,
+        ]
+        .into_iter()
+        .find(|candidate| candidate.as_str() == kind)
+    }
+}
+
+/// One row of a counter's history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CounterRecord {
+    pub signature: String,
+    pub kind: CounterEventKind,
+    pub authority: Option<String>,
+    pub payer: Option<String>,
+    pub count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+/// The most recent state of a counter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CounterState {
+    pub authority: Option<String>,
+    pub count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub signature: String,
+    /// Whether the latest event closed the counter, until it is initialized
+    /// again.
+    pub closed: bool,
+}
+
+/// A `u64` column, stored as an 8-byte big-endian blob since SQLite integers
+/// are signed. Blobs compare bytewise, so SQL orders and compares them as the
+/// numbers they hold.
+struct Unsigned(u64);
+
+impl ToSql for Unsigned {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(self.0.to_be_bytes().to_vec().into())
+    }
+}
+
+impl FromSql for Unsigned {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        <[u8; 8]>::column_result(value).map(|bytes| Self(u64::from_be_bytes(bytes)))
+    }
+}
+
+/// Flattens the event shapes into a history row, closes counting zero.
+fn record(signature: &str, event: &CounterEvent) -> (String, CounterRecord) {
+    let (counter, kind, authority, payer, count, slot, timestamp) = match event {
+        CounterEvent::CounterInitialized(event) => (
+            event.counter,
+            CounterEventKind::Initialized,
+            None,
+            Some(event.payer),
+            event.count,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterIncreased(event) => (
+            event.counter,
+            CounterEventKind::Increased,
+            None,
+            None,
+            event.new_count,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterAuthorityInitialized(event) => (
+            event.counter,
+            CounterEventKind::AuthorityInitialized,
+            Some(event.authority),
+            None,
+            event.count,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterAuthorityIncreased(event) => (
+            event.counter,
+            CounterEventKind::AuthorityIncreased,
+            Some(event.authority),
+            None,
+            event.new_count,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterDecreased(event) => (
+            event.counter,
+            CounterEventKind::Decreased,
+            None,
+            None,
+            event.new_count,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterReset(event) => (
+            event.counter,
+            CounterEventKind::Reset,
+            None,
+            None,
+            event.new_count,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterAuthorityDecreased(event) => (
+            event.counter,
+            CounterEventKind::AuthorityDecreased,
+            Some(event.authority),
+            None,
+            event.new_count,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterAuthorityReset(event) => (
+            event.counter,
+            CounterEventKind::AuthorityReset,
+            Some(event.authority),
+            None,
+            event.new_count,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterClosed(event) => (
+            event.counter,
+            CounterEventKind::Closed,
+            None,
+            None,
+            0,
+            event.slot,
+            event.timestamp,
+        ),
+        CounterEvent::CounterAuthorityClosed(event) => (
+            event.counter,
+            CounterEventKind::AuthorityClosed,
+            Some(event.authority),
+            None,
+            0,
+            event.slot,
+            event.timestamp,
+        ),
+    };
+
+    (
+        counter.to_string(),
+        CounterRecord {
+            signature: signature.to_string(),
+            kind,
+            authority: authority.as_ref().map(Address::to_string),
+            payer: payer.as_ref().map(Address::to_string),
+            count,
+            slot,
+            timestamp,
+        },
+    )
+}
+
+/// SQLite store of the events `program_id` emitted.
+pub struct Indexer {
+    connection: Connection,
+    program_id: Address,
+}
+
+impl Indexer {
+    pub fn open(path: impl AsRef<Path>, program_id: Address) -> Result<Self, IndexerError> {
+        Self::with_connection(Connection::open(path)?, program_id)
+    }
+
+    pub fn open_in_memory(program_id: Address) -> Result<Self, IndexerError> {
+        Self::with_connection(Connection::open_in_memory()?, program_id)
+    }
+
+    fn with_connection(connection: Connection, program_id: Address) -> Result<Self, IndexerError> {
+        connection.execute_batch(SCHEMA)?;
+
+        Ok(Self {
+            connection,
+            program_id,
+        })
+    }
+
+    /// Stores the events of a transaction, returning `false` when its
+    /// signature was already ingested. Failed transactions are skipped, as
+    /// their events never took effect.
+    pub fn ingest(&mut self, transaction: &LoggedTransaction) -> Result<bool, IndexerError> {
+        if !transaction.succeeded() {
+            return Ok(false);
+        }
+
+        let events = shared::event::logs::decode_logs::<CounterEvent, _>(
+            &self.program_id,
+            &transaction.logs,
+        )
+        .map_err(|error| IndexerError::InvalidLogs {
+            signature: transaction.signature.clone(),
+            error,
+        })?;
+
+        let tx = self.connection.transaction()?;
+
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO transactions (signature, event_count) VALUES (?1, ?2)",
+            params![transaction.signature, events.len()],
+        )?;
+
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        for (index, event) in events.iter().enumerate() {
+            let (counter, record) = record(&transaction.signature, event);
+
+            tx.execute(
+                "INSERT INTO counter_events
+                    (signature, event_index, kind, counter, authority, payer, count, slot, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    record.signature,
+                    index,
+                    record.kind.as_str(),
+                    counter,
+                    record.authority,
+                    record.payer,
+                    Unsigned(record.count),
+                    Unsigned(record.slot),
+                    record.timestamp,
+                ],
+            )?;
+
+            // Transactions may be ingested out of order; only a newer slot
+            // moves the latest state.
+            tx.execute(
+                "INSERT INTO counters (counter, authority, count, slot, timestamp, signature, closed)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT (counter) DO UPDATE SET
+                    authority = coalesce(excluded.authority, counters.authority),
+                    count = excluded.count,
+                    slot = excluded.slot,
+                    timestamp = excluded.timestamp,
+                    signature = excluded.signature,
+                    closed = excluded.closed
+                 WHERE excluded.slot >= counters.slot",
+                params![
+                    counter,
+                    record.authority,
+                    Unsigned(record.count),
+                    Unsigned(record.slot),
+                    record.timestamp,
+                    record.signature,
+                    record.kind.closes(),
+                ],
+            )?;
+        }
+
+        tx.commit()?;
+
+        Ok(true)
+    }
+
+    /// [`Indexer::ingest`] for a transaction replayed in LiteSVM.
+    #[cfg(feature = "litesvm")]
+    pub fn ingest_metadata(
+        &mut self,
+        meta: &litesvm::types::TransactionMetadata,
+    ) -> Result<bool, IndexerError> {
+        self.ingest(&LoggedTransaction::from(meta))
+    }
+
+    /// Every event of `counter`, oldest first.
+    pub fn history(&self, counter: &Address) -> Result<Vec<CounterRecord>, IndexerError> {
+        let mut statement = self.connection.prepare(
+            "SELECT signature, kind, authority, payer, count, slot, timestamp
+             FROM counter_events
+             WHERE counter = ?1
+             ORDER BY slot, rowid",
+        )?;
+
+        let records = statement
+            .query_map([counter.to_string()], |row| {
+                let kind: String = row.get(1)?;
+
+                Ok(CounterRecord {
+                    signature: row.get(0)?,
+                    kind: CounterEventKind::parse(&kind).ok_or_else(|| {
+                        rusqlite::Error::InvalidColumnType(
+                            1,
+                            "kind".to_string(),
+                            rusqlite::types::Type::Text,
+                        )
+                    })?,
+                    authority: row.get(2)?,
+                    payer: row.get(3)?,
+                    count: row.get::<_, Unsigned>(4)?.0,
+                    slot: row.get::<_, Unsigned>(5)?.0,
+                    timestamp: row.get(6)?,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+
+        Ok(records)
+    }
+
+    /// The latest state of `counter`, if any of its events was ingested.
+    pub fn latest(&self, counter: &Address) -> Result<Option<CounterState>, IndexerError> {
+        let state = self
+            .connection
+            .query_row(
+                "SELECT authority, count, slot, timestamp, signature, closed
+                 FROM counters
+                 WHERE counter = ?1",
+                [counter.to_string()],
+                |row| {
+                    Ok(CounterState {
+                        authority: row.get(0)?,
+                        count: row.get::<_, Unsigned>(1)?.0,
+                        slot: row.get::<_, Unsigned>(2)?.0,
+                        timestamp: row.get(3)?,
+                        signature: row.get(4)?,
+                        closed: row.get(5)?,
+                    })
+                },
+            )
+            .optional()?;
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::BufReader;
+
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use counter::events::{
+        CounterAuthorityIncreased, CounterClosed, CounterDecreased, CounterIncreased,
+        CounterInitialized, CounterReset,
+    };
+    use pinocchio::Address;
+    use shared::event::Event;
+    use solana_sdk::{instruction::Instruction, message::AccountMeta, signer::Signer};
+    use test_utils::Fixture;
+
+    use super::{CounterEventKind, Indexer, Unsigned};
+    use crate::{read_transactions, LoggedTransaction};
+
+    const PROGRAM_ID: Address = Address::new_from_array(counter::ID);
+    const COUNTER: Address = Address::new_from_array([7; 32]);
+    const PAYER: Address = Address::new_from_array([8; 32]);
+
+    /// The `Program data:` line `shared::event::emit` logs for `event`.
+    fn event_log<E: Event>(event: &E) -> String {
+        let mut data = E::DISCRIMINATOR.to_vec();
+        data.push(E::VERSION);
+        event.serialize(&mut data).unwrap();
+
+        format!("Program data: {}", STANDARD.encode(data))
+    }
+
+    fn transaction(signature: &str, events: Vec<String>) -> LoggedTransaction {
+        let mut logs = vec![format!("Program {PROGRAM_ID} invoke [1]")];
+        logs.extend(events);
+        logs.push(format!("Program {PROGRAM_ID} success"));
+
+        LoggedTransaction {
+            signature: signature.to_string(),
+            err: None,
+            logs,
+        }
+    }
+
+    fn initialized(count: u64, slot: u64) -> String {
+        event_log(&CounterInitialized {
+            counter: COUNTER,
+            payer: PAYER,
+            count,
+            slot,
+            timestamp: slot.wrapping_mul(10) as i64,
+        })
+    }
+
+    fn increased(new_count: u64, slot: u64) -> String {
+        event_log(&CounterIncreased {
+            counter: COUNTER,
+            new_count,
+            slot,
+            timestamp: slot.wrapping_mul(10) as i64,
+        })
+    }
+
+    #[test]
+    fn tracks_decreases_and_resets() {
+        let mut indexer = Indexer::open_in_memory(PROGRAM_ID).unwrap();
+
+        let decrease = event_log(&CounterDecreased {
+            counter: COUNTER,
+            new_count: 15,
+            slot: 2,
+            timestamp: 20,
+        });
+        let reset = event_log(&CounterReset {
+            counter: COUNTER,
+            new_count: 0,
+            slot: 3,
+            timestamp: 30,
+        });
+
+        indexer
+            .ingest(&transaction("init", vec![initialized(19, 1)]))
+            .unwrap();
+        indexer
+            .ingest(&transaction("decrease", vec![decrease]))
+            .unwrap();
+        indexer.ingest(&transaction("reset", vec![reset])).unwrap();
+
+        assert_eq!(
+            indexer
+                .history(&COUNTER)
+                .unwrap()
+                .iter()
+                .map(|record| (record.kind, record.count))
+                .collect::<Vec<_>>(),
+            [
+                (CounterEventKind::Initialized, 19),
+                (CounterEventKind::Decreased, 15),
+                (CounterEventKind::Reset, 0),
+            ]
+        );
+        assert_eq!(indexer.latest(&COUNTER).unwrap().unwrap().count, 0);
+    }
+
+    #[test]
+    fn tracks_closes_and_reinitialization() {
+        let mut indexer = Indexer::open_in_memory(PROGRAM_ID).unwrap();
+
+        let close = event_log(&CounterClosed {
+            counter: COUNTER,
+            admin: Address::new_from_array([5; 32]),
+            destination: Address::new_from_array([6; 32]),
+            lamports: 1_000,
+            slot: 2,
+            timestamp: 20,
+        });
+
+        indexer
+            .ingest(&transaction("init", vec![initialized(19, 1)]))
+            .unwrap();
+        indexer.ingest(&transaction("close", vec![close])).unwrap();
+
+        let closed = indexer.latest(&COUNTER).unwrap().unwrap();
+
+        assert!(closed.closed);
+        assert_eq!(closed.count, 0);
+
+        indexer
+            .ingest(&transaction("reinit", vec![initialized(3, 3)]))
+            .unwrap();
+
+        assert_eq!(
+            indexer
+                .history(&COUNTER)
+                .unwrap()
+                .iter()
+                .map(|record| (record.kind, record.count))
+                .collect::<Vec<_>>(),
+            [
+                (CounterEventKind::Initialized, 19),
+                (CounterEventKind::Closed, 0),
+                (CounterEventKind::Initialized, 3),
+            ]
+        );
+        let reopened = indexer.latest(&COUNTER).unwrap().unwrap();
+
+        assert!(!reopened.closed);
+        assert_eq!(reopened.count, 3);
+    }
+
+    #[test]
+    fn ingests_each_signature_once() {
+        let mut indexer = Indexer::open_in_memory(PROGRAM_ID).unwrap();
+
+        let init = transaction("init", vec![initialized(19, 1)]);
+        let increase = transaction("increase", vec![increased(20, 2), increased(21, 2)]);
+
+        assert!(indexer.ingest(&init).unwrap());
+        assert!(indexer.ingest(&increase).unwrap());
+        assert!(!indexer.ingest(&init).unwrap());
+        assert!(!indexer.ingest(&increase).unwrap());
+
+        let history = indexer.history(&COUNTER).unwrap();
+
+        assert_eq!(
+            history
+                .iter()
+                .map(|record| (record.kind, record.count))
+                .collect::<Vec<_>>(),
+            [
+                (CounterEventKind::Initialized, 19),
+                (CounterEventKind::Increased, 20),
+                (CounterEventKind::Increased, 21),
+            ]
+        );
+        assert_eq!(history[0].payer, Some(PAYER.to_string()));
+
+        let latest = indexer.latest(&COUNTER).unwrap().unwrap();
+
+        assert_eq!(latest.count, 21);
+        assert_eq!(latest.slot, 2);
+        assert_eq!(latest.timestamp, 20);
+        assert_eq!(latest.signature, "increase");
+        assert!(!latest.closed);
+    }
+
+    #[test]
+    fn older_transactions_do_not_override_latest_state() {
+        let mut indexer = Indexer::open_in_memory(PROGRAM_ID).unwrap();
+
+        indexer
+            .ingest(&transaction("newer", vec![increased(25, 9)]))
+            .unwrap();
+        indexer
+            .ingest(&transaction("older", vec![increased(24, 8)]))
+            .unwrap();
+
+        assert_eq!(indexer.history(&COUNTER).unwrap().len(), 2);
+        assert_eq!(indexer.latest(&COUNTER).unwrap().unwrap().count, 25);
+    }
+
+    #[test]
+    fn orders_values_past_i64_max() {
+        let mut indexer = Indexer::open_in_memory(PROGRAM_ID).unwrap();
+
+        let past_i64 = i64::MAX as u64 + 1;
+
+        indexer
+            .ingest(&transaction("init", vec![initialized(u64::MAX, past_i64)]))
+            .unwrap();
+        indexer
+            .ingest(&transaction("older", vec![increased(1, 1)]))
+            .unwrap();
+
+        let latest = indexer.latest(&COUNTER).unwrap().unwrap();
+
+        assert_eq!(latest.count, u64::MAX);
+        assert_eq!(latest.slot, past_i64);
+        assert_eq!(
+            indexer
+                .history(&COUNTER)
+                .unwrap()
+                .iter()
+                .map(|record| (record.count, record.slot))
+                .collect::<Vec<_>>(),
+            [(1, 1), (u64::MAX, past_i64)]
+        );
+
+        // Raw SQL compares them as numbers too.
+        let above: u64 = indexer
+            .connection
+            .query_row(
+                "SELECT count(*) FROM counter_events WHERE count > ?1",
+                [Unsigned(past_i64)],
+                |row| row.get(0),
+            )
+            .unwrap();
+
+        assert_eq!(above, 1);
+    }
+
+    #[test]
+    fn keeps_authority_of_authority_counters() {
+        let mut indexer = Indexer::open_in_memory(PROGRAM_ID).unwrap();
+
+        let increase = event_log(&CounterAuthorityIncreased {
+            counter: COUNTER,
+            authority: PAYER,
+            new_count: 1,
+            slot: 3,
+            timestamp: 30,
+        });
+
+        indexer
+            .ingest(&transaction("increase", vec![increase]))
+            .unwrap();
+
+        let latest = indexer.latest(&COUNTER).unwrap().unwrap();
+
+        assert_eq!(latest.authority, Some(PAYER.to_string()));
+    }
+
+    #[test]
+    fn skips_failed_transactions_and_other_programs() {
+        let mut indexer = Indexer::open_in_memory(PROGRAM_ID).unwrap();
+
+        let mut failed = transaction("failed", vec![initialized(19, 1)]);
+        failed.err = Some(serde_json::json!({ "InstructionError": [0, { "Custom": 6001 }] }));
+
+        let other_program = Address::new_from_array([9; 32]);
+        let foreign = LoggedTransaction {
+            signature: "foreign".to_string(),
+            err: None,
+            logs: vec![
+                format!("Program {other_program} invoke [1]"),
+                initialized(19, 1),
+                format!("Program {other_program} success"),
+            ],
+        };
+
+        assert!(!indexer.ingest(&failed).unwrap());
+        assert!(indexer.ingest(&foreign).unwrap());
+        assert!(indexer.history(&COUNTER).unwrap().is_empty());
+        assert_eq!(indexer.latest(&COUNTER).unwrap(), None);
+    }
+
+    #[test]
+    fn indexes_litesvm_transactions() {
+        let mut fixture = Fixture::new(PROGRAM_ID, "counter", counter::process);
+
+        let (counter, _) = Address::find_program_address(&[b"counter"], &PROGRAM_ID);
+
+        let init = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fixture.payer.pubkey(), true),
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+            data: borsh::to_vec(&counter::CounterInstruction::InitCounter(
+                counter::InitCounterArgs {
+                    count: 19,
+                    max_count: None,
+                },
+            ))
+            .unwrap(),
+        };
+
+        let increase = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![AccountMeta::new(counter, false)],
+            data: borsh::to_vec(&counter::CounterInstruction::IncreaseCounter).unwrap(),
+        };
+
+        let mut transactions = Vec::new();
+
+        for ix in [init, increase.clone(), increase] {
+            let outcome = fixture.send(ix);
+            outcome.assert_success();
+            transactions.push(outcome.meta);
+        }
+
+        let mut indexer = Indexer::open_in_memory(PROGRAM_ID).unwrap();
+
+        for meta in &transactions {
+            assert!(indexer.ingest_metadata(meta).unwrap());
+        }
+
+        assert_eq!(
+            indexer
+                .history(&counter)
+                .unwrap()
+                .iter()
+                .map(|record| record.count)
+                .collect::<Vec<_>>(),
+            [19, 20, 21]
+        );
+
+        // The same transactions, written out and read back as JSON lines,
+        // are recognised as already ingested.
+        let jsonl = transactions
+            .iter()
+            .map(|meta| serde_json::to_string(&LoggedTransaction::from(meta)).unwrap() + "\n")
+            .collect::<String>();
+
+        for transaction in read_transactions(BufReader::new(jsonl.as_bytes())) {
+            assert!(!indexer.ingest(&transaction.unwrap()).unwrap());
+        }
+
+        let latest = indexer.latest(&counter).unwrap().unwrap();
+
+        assert_eq!(latest.count, 21);
+        assert_eq!(
+            latest.signature,
+            transactions.last().unwrap().signature.to_string()
+        );
+    }
+}
@@ -31,25 +31,48 @@ import {
 	getU8Encoder,
 	type MaybeAccount,
 	type MaybeEncodedAccount,
-	type ReadonlyUint8Array
+	type ReadonlyUint8Array,
+	transformEncoder
 } from "@solana/kit"
 
-export type Meme = { address: ReadonlyUint8Array; bump: number }
+export const MEME_DISCRIMINATOR = new Uint8Array([232, 224, 0, 147, 187, 194, 135, 26])
 
-export type MemeArgs = Meme
+export function getMemeDiscriminatorBytes() {
+	return fixEncoderSize(getBytesEncoder(), 8).encode(MEME_DISCRIMINATOR)
+}
+
+export type Meme = {
+	discriminator: ReadonlyUint8Array
+	address: ReadonlyUint8Array
+	authority: ReadonlyUint8Array
+	bump: number
+}
+
+export type MemeArgs = {
+	address: ReadonlyUint8Array
+	authority: ReadonlyUint8Array
+	bump: number
+}
 
 /** Gets the encoder for {@link MemeArgs} account data. */
 export function getMemeEncoder(): FixedSizeEncoder<MemeArgs> {
-	return getStructEncoder([
-		["address", fixEncoderSize(getBytesEncoder(), 32)],
-		["bump", getU8Encoder()]
-	])
+	return transformEncoder(
+		getStructEncoder([
+			["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
+			["address", fixEncoderSize(getBytesEncoder(), 32)],
+			["authority", fixEncoderSize(getBytesEncoder(), 32)],
+			["bump", getU8Encoder()]
+		]),
+		value => ({ ...value, discriminator: MEME_DISCRIMINATOR })
+	)
 }
 
 /** Gets the decoder for {@link Meme} account data. */
 export function getMemeDecoder(): FixedSizeDecoder<Meme> {
 	return getStructDecoder([
+		["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
 		["address", fixDecoderSize(getBytesDecoder(), 32)],
+		["authority", fixDecoderSize(getBytesDecoder(), 32)],
 		["bump", getU8Decoder()]
 	])
 }
@@ -110,5 +133,5 @@ export async function fetchAllMaybeMeme(
 }
 
 export function getMemeSize(): number {
-	return 33
+	return 73
 }
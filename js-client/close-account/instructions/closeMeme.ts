@@ -39,18 +39,22 @@ export function getCloseMemeDiscriminatorBytes() {
 
 export type CloseMemeInstruction<
 	TProgram extends string = typeof CLOSE_ACCOUNT_PROGRAM_ADDRESS,
-	TAccountPayer extends string | AccountMeta<string> = string,
+	TAccountAuthority extends string | AccountMeta<string> = string,
 	TAccountMeme extends string | AccountMeta<string> = string,
+	TAccountDestination extends string | AccountMeta<string> = string,
 	TAccountSystemProgram extends string | AccountMeta<string> = "11111111111111111111111111111111",
 	TRemainingAccounts extends readonly AccountMeta<string>[] = []
 > = Instruction<TProgram> &
 	InstructionWithData<ReadonlyUint8Array> &
 	InstructionWithAccounts<
 		[
-			TAccountPayer extends string
-				? ReadonlySignerAccount<TAccountPayer> & AccountSignerMeta<TAccountPayer>
-				: TAccountPayer,
+			TAccountAuthority extends string
+				? ReadonlySignerAccount<TAccountAuthority> & AccountSignerMeta<TAccountAuthority>
+				: TAccountAuthority,
 			TAccountMeme extends string ? WritableAccount<TAccountMeme> : TAccountMeme,
+			TAccountDestination extends string
+				? WritableAccount<TAccountDestination>
+				: TAccountDestination,
 			TAccountSystemProgram extends string
 				? ReadonlyAccount<TAccountSystemProgram>
 				: TAccountSystemProgram,
@@ -81,31 +85,46 @@ export function getCloseMemeInstructionDataCodec(): FixedSizeCodec<
 }
 
 export type CloseMemeInput<
-	TAccountPayer extends string = string,
+	TAccountAuthority extends string = string,
 	TAccountMeme extends string = string,
+	TAccountDestination extends string = string,
 	TAccountSystemProgram extends string = string
 > = {
-	payer: TransactionSigner<TAccountPayer>
+	authority: TransactionSigner<TAccountAuthority>
 	meme: Address<TAccountMeme>
+	destination: Address<TAccountDestination>
 	systemProgram?: Address<TAccountSystemProgram>
 }
 
 export function getCloseMemeInstruction<
-	TAccountPayer extends string,
+	TAccountAuthority extends string,
 	TAccountMeme extends string,
+	TAccountDestination extends string,
 	TAccountSystemProgram extends string,
 	TProgramAddress extends Address = typeof CLOSE_ACCOUNT_PROGRAM_ADDRESS
 >(
-	input: CloseMemeInput<TAccountPayer, TAccountMeme, TAccountSystemProgram>,
+	input: CloseMemeInput<
+		TAccountAuthority,
+		TAccountMeme,
+		TAccountDestination,
+		TAccountSystemProgram
+	>,
 	config?: { programAddress?: TProgramAddress }
-): CloseMemeInstruction<TProgramAddress, TAccountPayer, TAccountMeme, TAccountSystemProgram> {
+): CloseMemeInstruction<
+	TProgramAddress,
+	TAccountAuthority,
+	TAccountMeme,
+	TAccountDestination,
+	TAccountSystemProgram
+> {
 	// Program address.
 	const programAddress = config?.programAddress ?? CLOSE_ACCOUNT_PROGRAM_ADDRESS
 
 	// Original accounts.
 	const originalAccounts = {
-		payer: { value: input.payer ?? null, isWritable: false },
+		authority: { value: input.authority ?? null, isWritable: false },
 		meme: { value: input.meme ?? null, isWritable: true },
+		destination: { value: input.destination ?? null, isWritable: true },
 		systemProgram: { value: input.systemProgram ?? null, isWritable: false }
 	}
 	const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedAccount>
@@ -119,13 +138,20 @@ export function getCloseMemeInstruction<
 	const getAccountMeta = getAccountMetaFactory(programAddress, "programId")
 	return Object.freeze({
 		accounts: [
-			getAccountMeta(accounts.payer),
+			getAccountMeta(accounts.authority),
 			getAccountMeta(accounts.meme),
+			getAccountMeta(accounts.destination),
 			getAccountMeta(accounts.systemProgram)
 		],
 		data: getCloseMemeInstructionDataEncoder().encode({}),
 		programAddress
-	} as CloseMemeInstruction<TProgramAddress, TAccountPayer, TAccountMeme, TAccountSystemProgram>)
+	} as CloseMemeInstruction<
+		TProgramAddress,
+		TAccountAuthority,
+		TAccountMeme,
+		TAccountDestination,
+		TAccountSystemProgram
+	>)
 }
 
 export type ParsedCloseMemeInstruction<
@@ -134,9 +160,10 @@ export type ParsedCloseMemeInstruction<
 > = {
 	programAddress: Address<TProgram>
 	accounts: {
-		payer: TAccountMetas[0]
+		authority: TAccountMetas[0]
 		meme: TAccountMetas[1]
-		systemProgram: TAccountMetas[2]
+		destination: TAccountMetas[2]
+		systemProgram: TAccountMetas[3]
 	}
 	data: CloseMemeInstructionData
 }
@@ -149,7 +176,7 @@ export function parseCloseMemeInstruction<
 		InstructionWithAccounts<TAccountMetas> &
 		InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseMemeInstruction<TProgram, TAccountMetas> {
-	if (instruction.accounts.length < 3) {
+	if (instruction.accounts.length < 4) {
 		// TODO: Coded error.
 		throw new Error("Not enough accounts")
 	}
@@ -162,8 +189,9 @@ export function parseCloseMemeInstruction<
 	return {
 		programAddress: instruction.programAddress,
 		accounts: {
-			payer: getNextAccount(),
+			authority: getNextAccount(),
 			meme: getNextAccount(),
+			destination: getNextAccount(),
 			systemProgram: getNextAccount()
 		},
 		data: getCloseMemeInstructionDataDecoder().decode(instruction.data)
@@ -6,7 +6,14 @@
  * @see https://github.com/codama-idl/codama
  */
 
-import { type Address, containsBytes, getU8Encoder, type ReadonlyUint8Array } from "@solana/kit"
+import {
+	type Address,
+	containsBytes,
+	fixEncoderSize,
+	getBytesEncoder,
+	getU8Encoder,
+	type ReadonlyUint8Array
+} from "@solana/kit"
 import type { ParsedCloseMemeInstruction, ParsedCreateMemeInstruction } from "../instructions"
 
 export const CLOSE_ACCOUNT_PROGRAM_ADDRESS =
@@ -16,6 +23,24 @@ export enum CloseAccountAccount {
 	Meme
 }
 
+export function identifyCloseAccountAccount(
+	account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
+): CloseAccountAccount {
+	const data = "data" in account ? account.data : account
+	if (
+		containsBytes(
+			data,
+			fixEncoderSize(getBytesEncoder(), 8).encode(
+				new Uint8Array([232, 224, 0, 147, 187, 194, 135, 26])
+			),
+			0
+		)
+	) {
+		return CloseAccountAccount.Meme
+	}
+	throw new Error("The provided account could not be identified as a closeAccount account.")
+}
+
 export enum CloseAccountInstruction {
 	CreateMeme,
 	CloseMeme
@@ -21,6 +21,10 @@ import {
 	type FixedSizeEncoder,
 	fetchEncodedAccount,
 	fetchEncodedAccounts,
+	fixDecoderSize,
+	fixEncoderSize,
+	getBytesDecoder,
+	getBytesEncoder,
 	getStructDecoder,
 	getStructEncoder,
 	getU8Decoder,
@@ -28,26 +32,50 @@ import {
 	getU64Decoder,
 	getU64Encoder,
 	type MaybeAccount,
-	type MaybeEncodedAccount
+	type MaybeEncodedAccount,
+	type ReadonlyUint8Array,
+	transformEncoder
 } from "@solana/kit"
 
-export type Counter = { bump: number; count: bigint }
+export const COUNTER_DISCRIMINATOR = new Uint8Array([255, 176, 4, 245, 188, 253, 124, 25])
 
-export type CounterArgs = { bump: number; count: number | bigint }
+export function getCounterDiscriminatorBytes() {
+	return fixEncoderSize(getBytesEncoder(), 8).encode(COUNTER_DISCRIMINATOR)
+}
+
+export type Counter = {
+	discriminator: ReadonlyUint8Array
+	bump: number
+	count: bigint
+	maxCount: bigint
+}
+
+export type CounterArgs = {
+	bump: number
+	count: number | bigint
+	maxCount: number | bigint
+}
 
 /** Gets the encoder for {@link CounterArgs} account data. */
 export function getCounterEncoder(): FixedSizeEncoder<CounterArgs> {
-	return getStructEncoder([
-		["bump", getU8Encoder()],
-		["count", getU64Encoder()]
-	])
+	return transformEncoder(
+		getStructEncoder([
+			["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
+			["bump", getU8Encoder()],
+			["count", getU64Encoder()],
+			["maxCount", getU64Encoder()]
+		]),
+		value => ({ ...value, discriminator: COUNTER_DISCRIMINATOR })
+	)
 }
 
 /** Gets the decoder for {@link Counter} account data. */
 export function getCounterDecoder(): FixedSizeDecoder<Counter> {
 	return getStructDecoder([
+		["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
 		["bump", getU8Decoder()],
-		["count", getU64Decoder()]
+		["count", getU64Decoder()],
+		["maxCount", getU64Decoder()]
 	])
 }
 
@@ -107,5 +135,5 @@ export async function fetchAllMaybeCounter(
 }
 
 export function getCounterSize(): number {
-	return 9
+	return 25
 }
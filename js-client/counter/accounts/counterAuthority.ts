@@ -33,36 +33,53 @@ import {
 	getU64Encoder,
 	type MaybeAccount,
 	type MaybeEncodedAccount,
-	type ReadonlyUint8Array
+	type ReadonlyUint8Array,
+	transformEncoder
 } from "@solana/kit"
 
+export const COUNTER_AUTHORITY_DISCRIMINATOR = new Uint8Array([174, 239, 40, 200, 210, 232, 249, 180])
+
+export function getCounterAuthorityDiscriminatorBytes() {
+	return fixEncoderSize(getBytesEncoder(), 8).encode(COUNTER_AUTHORITY_DISCRIMINATOR)
+}
+
 export type CounterAuthority = {
+	discriminator: ReadonlyUint8Array
 	authority: ReadonlyUint8Array
 	bump: number
 	count: bigint
+	maxCount: bigint
 }
 
 export type CounterAuthorityArgs = {
 	authority: ReadonlyUint8Array
 	bump: number
 	count: number | bigint
+	maxCount: number | bigint
 }
 
 /** Gets the encoder for {@link CounterAuthorityArgs} account data. */
 export function getCounterAuthorityEncoder(): FixedSizeEncoder<CounterAuthorityArgs> {
-	return getStructEncoder([
-		["authority", fixEncoderSize(getBytesEncoder(), 32)],
-		["bump", getU8Encoder()],
-		["count", getU64Encoder()]
-	])
+	return transformEncoder(
+		getStructEncoder([
+			["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
+			["authority", fixEncoderSize(getBytesEncoder(), 32)],
+			["bump", getU8Encoder()],
+			["count", getU64Encoder()],
+			["maxCount", getU64Encoder()]
+		]),
+		value => ({ ...value, discriminator: COUNTER_AUTHORITY_DISCRIMINATOR })
+	)
 }
 
 /** Gets the decoder for {@link CounterAuthority} account data. */
 export function getCounterAuthorityDecoder(): FixedSizeDecoder<CounterAuthority> {
 	return getStructDecoder([
+		["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
 		["authority", fixDecoderSize(getBytesDecoder(), 32)],
 		["bump", getU8Decoder()],
-		["count", getU64Decoder()]
+		["count", getU64Decoder()],
+		["maxCount", getU64Decoder()]
 	])
 }
 
@@ -125,5 +142,5 @@ export async function fetchAllMaybeCounterAuthority(
 }
 
 export function getCounterAuthoritySize(): number {
-	return 41
+	return 57
 }
@@ -6,7 +6,15 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./closeCounter"
+export * from "./closeCounterAuthority"
+export * from "./decreaseCounter"
+export * from "./decreaseCounterAuthority"
 export * from "./increaseCounter"
 export * from "./increaseCounterAuthority"
+export * from "./increaseCounterAuthorityBy"
+export * from "./increaseCounterBy"
 export * from "./initCounter"
 export * from "./initCounterAuhthority"
+export * from "./resetCounter"
+export * from "./resetCounterAuthority"
@@ -10,10 +10,12 @@ import {
 	type AccountMeta,
 	type AccountSignerMeta,
 	type Address,
+	type Codec,
 	combineCodec,
-	type FixedSizeCodec,
-	type FixedSizeDecoder,
-	type FixedSizeEncoder,
+	type Decoder,
+	type Encoder,
+	getOptionDecoder,
+	getOptionEncoder,
 	getStructDecoder,
 	getStructEncoder,
 	getU8Decoder,
@@ -23,6 +25,8 @@ import {
 	type Instruction,
 	type InstructionWithAccounts,
 	type InstructionWithData,
+	type Option,
+	type OptionOrNullable,
 	type ReadonlyAccount,
 	type ReadonlySignerAccount,
 	type ReadonlyUint8Array,
@@ -63,28 +67,34 @@ export type InitCounterInstruction<
 export type InitCounterInstructionData = {
 	discriminator: number
 	count: bigint
+	maxCount: Option<bigint>
 }
 
-export type InitCounterInstructionDataArgs = { count: number | bigint }
+export type InitCounterInstructionDataArgs = {
+	count: number | bigint
+	maxCount: OptionOrNullable<number | bigint>
+}
 
-export function getInitCounterInstructionDataEncoder(): FixedSizeEncoder<InitCounterInstructionDataArgs> {
+export function getInitCounterInstructionDataEncoder(): Encoder<InitCounterInstructionDataArgs> {
 	return transformEncoder(
 		getStructEncoder([
 			["discriminator", getU8Encoder()],
-			["count", getU64Encoder()]
+			["count", getU64Encoder()],
+			["maxCount", getOptionEncoder(getU64Encoder())]
 		]),
 		value => ({ ...value, discriminator: INIT_COUNTER_DISCRIMINATOR })
 	)
 }
 
-export function getInitCounterInstructionDataDecoder(): FixedSizeDecoder<InitCounterInstructionData> {
+export function getInitCounterInstructionDataDecoder(): Decoder<InitCounterInstructionData> {
 	return getStructDecoder([
 		["discriminator", getU8Decoder()],
-		["count", getU64Decoder()]
+		["count", getU64Decoder()],
+		["maxCount", getOptionDecoder(getU64Decoder())]
 	])
 }
 
-export function getInitCounterInstructionDataCodec(): FixedSizeCodec<
+export function getInitCounterInstructionDataCodec(): Codec<
 	InitCounterInstructionDataArgs,
 	InitCounterInstructionData
 > {
@@ -103,6 +113,7 @@ export type InitCounterInput<
 	counter: Address<TAccountCounter>
 	systemProgram?: Address<TAccountSystemProgram>
 	count: InitCounterInstructionDataArgs["count"]
+	maxCount: InitCounterInstructionDataArgs["maxCount"]
 }
 
 export function getInitCounterInstruction<
@@ -10,10 +10,12 @@ import {
 	type AccountMeta,
 	type AccountSignerMeta,
 	type Address,
+	type Codec,
 	combineCodec,
-	type FixedSizeCodec,
-	type FixedSizeDecoder,
-	type FixedSizeEncoder,
+	type Decoder,
+	type Encoder,
+	getOptionDecoder,
+	getOptionEncoder,
 	getStructDecoder,
 	getStructEncoder,
 	getU8Decoder,
@@ -23,6 +25,8 @@ import {
 	type Instruction,
 	type InstructionWithAccounts,
 	type InstructionWithData,
+	type Option,
+	type OptionOrNullable,
 	type ReadonlyAccount,
 	type ReadonlySignerAccount,
 	type ReadonlyUint8Array,
@@ -65,17 +69,20 @@ export type InitCounterAuhthorityInstruction<
 export type InitCounterAuhthorityInstructionData = {
 	discriminator: number
 	count: bigint
+	maxCount: Option<bigint>
 }
 
 export type InitCounterAuhthorityInstructionDataArgs = {
 	count: number | bigint
+	maxCount: OptionOrNullable<number | bigint>
 }
 
-export function getInitCounterAuhthorityInstructionDataEncoder(): FixedSizeEncoder<InitCounterAuhthorityInstructionDataArgs> {
+export function getInitCounterAuhthorityInstructionDataEncoder(): Encoder<InitCounterAuhthorityInstructionDataArgs> {
 	return transformEncoder(
 		getStructEncoder([
 			["discriminator", getU8Encoder()],
-			["count", getU64Encoder()]
+			["count", getU64Encoder()],
+			["maxCount", getOptionEncoder(getU64Encoder())]
 		]),
 		value => ({
 			...value,
@@ -84,14 +91,15 @@ export function getInitCounterAuhthorityInstructionDataEncoder(): FixedSizeEncod
 	)
 }
 
-export function getInitCounterAuhthorityInstructionDataDecoder(): FixedSizeDecoder<InitCounterAuhthorityInstructionData> {
+export function getInitCounterAuhthorityInstructionDataDecoder(): Decoder<InitCounterAuhthorityInstructionData> {
 	return getStructDecoder([
 		["discriminator", getU8Decoder()],
-		["count", getU64Decoder()]
+		["count", getU64Decoder()],
+		["maxCount", getOptionDecoder(getU64Decoder())]
 	])
 }
 
-export function getInitCounterAuhthorityInstructionDataCodec(): FixedSizeCodec<
+export function getInitCounterAuhthorityInstructionDataCodec(): Codec<
 	InitCounterAuhthorityInstructionDataArgs,
 	InitCounterAuhthorityInstructionData
 > {
@@ -110,6 +118,7 @@ export type InitCounterAuhthorityInput<
 	counterAuthority: Address<TAccountCounterAuthority>
 	systemProgram?: Address<TAccountSystemProgram>
 	count: InitCounterAuhthorityInstructionDataArgs["count"]
+	maxCount: InitCounterAuhthorityInstructionDataArgs["maxCount"]
 }
 
 export function getInitCounterAuhthorityInstruction<
@@ -6,12 +6,27 @@
  * @see https://github.com/codama-idl/codama
  */
 
-import { type Address, containsBytes, getU8Encoder, type ReadonlyUint8Array } from "@solana/kit"
+import {
+	type Address,
+	containsBytes,
+	fixEncoderSize,
+	getBytesEncoder,
+	getU8Encoder,
+	type ReadonlyUint8Array
+} from "@solana/kit"
 import type {
+	ParsedCloseCounterAuthorityInstruction,
+	ParsedCloseCounterInstruction,
+	ParsedDecreaseCounterAuthorityInstruction,
+	ParsedDecreaseCounterInstruction,
+	ParsedIncreaseCounterAuthorityByInstruction,
 	ParsedIncreaseCounterAuthorityInstruction,
+	ParsedIncreaseCounterByInstruction,
 	ParsedIncreaseCounterInstruction,
 	ParsedInitCounterAuhthorityInstruction,
-	ParsedInitCounterInstruction
+	ParsedInitCounterInstruction,
+	ParsedResetCounterAuthorityInstruction,
+	ParsedResetCounterInstruction
 } from "../instructions"
 
 export const COUNTER_PROGRAM_ADDRESS =
@@ -22,11 +37,48 @@ export enum CounterAccount {
 	CounterAuthority
 }
 
+export function identifyCounterAccount(
+	account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
+): CounterAccount {
+	const data = "data" in account ? account.data : account
+	if (
+		containsBytes(
+			data,
+			fixEncoderSize(getBytesEncoder(), 8).encode(
+				new Uint8Array([255, 176, 4, 245, 188, 253, 124, 25])
+			),
+			0
+		)
+	) {
+		return CounterAccount.Counter
+	}
+	if (
+		containsBytes(
+			data,
+			fixEncoderSize(getBytesEncoder(), 8).encode(
+				new Uint8Array([174, 239, 40, 200, 210, 232, 249, 180])
+			),
+			0
+		)
+	) {
+		return CounterAccount.CounterAuthority
+	}
+	throw new Error("The provided account could not be identified as a counter account.")
+}
+
 export enum CounterInstruction {
 	InitCounter,
 	IncreaseCounter,
 	InitCounterAuhthority,
-	IncreaseCounterAuthority
+	IncreaseCounterAuthority,
+	IncreaseCounterBy,
+	DecreaseCounter,
+	ResetCounter,
+	IncreaseCounterAuthorityBy,
+	DecreaseCounterAuthority,
+	ResetCounterAuthority,
+	CloseCounter,
+	CloseCounterAuthority
 }
 
 export function identifyCounterInstruction(
@@ -45,6 +97,30 @@ export function identifyCounterInstruction(
 	if (containsBytes(data, getU8Encoder().encode(3), 0)) {
 		return CounterInstruction.IncreaseCounterAuthority
 	}
+	if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+		return CounterInstruction.IncreaseCounterBy
+	}
+	if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+		return CounterInstruction.DecreaseCounter
+	}
+	if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+		return CounterInstruction.ResetCounter
+	}
+	if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+		return CounterInstruction.IncreaseCounterAuthorityBy
+	}
+	if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+		return CounterInstruction.DecreaseCounterAuthority
+	}
+	if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+		return CounterInstruction.ResetCounterAuthority
+	}
+	if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+		return CounterInstruction.CloseCounter
+	}
+	if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+		return CounterInstruction.CloseCounterAuthority
+	}
 	throw new Error("The provided instruction could not be identified as a counter instruction.")
 }
 
@@ -63,3 +139,27 @@ export type ParsedCounterInstruction<
 	| ({
 			instructionType: CounterInstruction.IncreaseCounterAuthority
 	  } & ParsedIncreaseCounterAuthorityInstruction<TProgram>)
+	| ({
+			instructionType: CounterInstruction.IncreaseCounterBy
+	  } & ParsedIncreaseCounterByInstruction<TProgram>)
+	| ({
+			instructionType: CounterInstruction.DecreaseCounter
+	  } & ParsedDecreaseCounterInstruction<TProgram>)
+	| ({
+			instructionType: CounterInstruction.ResetCounter
+	  } & ParsedResetCounterInstruction<TProgram>)
+	| ({
+			instructionType: CounterInstruction.IncreaseCounterAuthorityBy
+	  } & ParsedIncreaseCounterAuthorityByInstruction<TProgram>)
+	| ({
+			instructionType: CounterInstruction.DecreaseCounterAuthority
+	  } & ParsedDecreaseCounterAuthorityInstruction<TProgram>)
+	| ({
+			instructionType: CounterInstruction.ResetCounterAuthority
+	  } & ParsedResetCounterAuthorityInstruction<TProgram>)
+	| ({
+			instructionType: CounterInstruction.CloseCounter
+	  } & ParsedCloseCounterInstruction<TProgram>)
+	| ({
+			instructionType: CounterInstruction.CloseCounterAuthority
+	  } & ParsedCloseCounterAuthorityInstruction<TProgram>)
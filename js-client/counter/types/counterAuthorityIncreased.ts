@@ -11,22 +11,53 @@ import {
 	type FixedSizeCodec,
 	type FixedSizeDecoder,
 	type FixedSizeEncoder,
+	fixDecoderSize,
+	fixEncoderSize,
+	getBytesDecoder,
+	getBytesEncoder,
+	getI64Decoder,
+	getI64Encoder,
 	getStructDecoder,
 	getStructEncoder,
 	getU64Decoder,
-	getU64Encoder
+	getU64Encoder,
+	type ReadonlyUint8Array
 } from "@solana/kit"
 
-export type CounterAuthorityIncreased = { newCount: bigint }
+export type CounterAuthorityIncreased = {
+	counter: ReadonlyUint8Array
+	authority: ReadonlyUint8Array
+	newCount: bigint
+	slot: bigint
+	timestamp: bigint
+}
 
-export type CounterAuthorityIncreasedArgs = { newCount: number | bigint }
+export type CounterAuthorityIncreasedArgs = {
+	counter: ReadonlyUint8Array
+	authority: ReadonlyUint8Array
+	newCount: number | bigint
+	slot: number | bigint
+	timestamp: number | bigint
+}
 
 export function getCounterAuthorityIncreasedEncoder(): FixedSizeEncoder<CounterAuthorityIncreasedArgs> {
-	return getStructEncoder([["newCount", getU64Encoder()]])
+	return getStructEncoder([
+		["counter", fixEncoderSize(getBytesEncoder(), 32)],
+		["authority", fixEncoderSize(getBytesEncoder(), 32)],
+		["newCount", getU64Encoder()],
+		["slot", getU64Encoder()],
+		["timestamp", getI64Encoder()]
+	])
 }
 
 export function getCounterAuthorityIncreasedDecoder(): FixedSizeDecoder<CounterAuthorityIncreased> {
-	return getStructDecoder([["newCount", getU64Decoder()]])
+	return getStructDecoder([
+		["counter", fixDecoderSize(getBytesDecoder(), 32)],
+		["authority", fixDecoderSize(getBytesDecoder(), 32)],
+		["newCount", getU64Decoder()],
+		["slot", getU64Decoder()],
+		["timestamp", getI64Decoder()]
+	])
 }
 
 export function getCounterAuthorityIncreasedCodec(): FixedSizeCodec<
@@ -11,30 +11,58 @@ import {
 	type FixedSizeCodec,
 	type FixedSizeDecoder,
 	type FixedSizeEncoder,
+	fixDecoderSize,
+	fixEncoderSize,
+	getBytesDecoder,
+	getBytesEncoder,
+	getI64Decoder,
+	getI64Encoder,
 	getStructDecoder,
 	getStructEncoder,
 	getU64Decoder,
-	getU64Encoder
+	getU64Encoder,
+	type ReadonlyUint8Array
 } from "@solana/kit"
 
-export type CounterAuthorityInitialized = { count: bigint }
+export type CounterAuthorityInitialized = {
+	counter: ReadonlyUint8Array
+	authority: ReadonlyUint8Array
+	count: bigint
+	slot: bigint
+	timestamp: bigint
+}
 
-export type CounterAuthorityInitializedArgs = { count: number | bigint }
+export type CounterAuthorityInitializedArgs = {
+	counter: ReadonlyUint8Array
+	authority: ReadonlyUint8Array
+	count: number | bigint
+	slot: number | bigint
+	timestamp: number | bigint
+}
 
 export function getCounterAuthorityInitializedEncoder(): FixedSizeEncoder<CounterAuthorityInitializedArgs> {
-	return getStructEncoder([["count", getU64Encoder()]])
+	return getStructEncoder([
+		["counter", fixEncoderSize(getBytesEncoder(), 32)],
+		["authority", fixEncoderSize(getBytesEncoder(), 32)],
+		["count", getU64Encoder()],
+		["slot", getU64Encoder()],
+		["timestamp", getI64Encoder()]
+	])
 }
 
 export function getCounterAuthorityInitializedDecoder(): FixedSizeDecoder<CounterAuthorityInitialized> {
-	return getStructDecoder([["count", getU64Decoder()]])
+	return getStructDecoder([
+		["counter", fixDecoderSize(getBytesDecoder(), 32)],
+		["authority", fixDecoderSize(getBytesDecoder(), 32)],
+		["count", getU64Decoder()],
+		["slot", getU64Decoder()],
+		["timestamp", getI64Decoder()]
+	])
 }
 
 export function getCounterAuthorityInitializedCodec(): FixedSizeCodec<
 	CounterAuthorityInitializedArgs,
 	CounterAuthorityInitialized
 > {
-	return combineCodec(
-		getCounterAuthorityInitializedEncoder(),
-		getCounterAuthorityInitializedDecoder()
-	)
+	return combineCodec(getCounterAuthorityInitializedEncoder(), getCounterAuthorityInitializedDecoder())
 }
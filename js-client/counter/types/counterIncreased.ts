@@ -11,22 +11,49 @@ import {
 	type FixedSizeCodec,
 	type FixedSizeDecoder,
 	type FixedSizeEncoder,
+	fixDecoderSize,
+	fixEncoderSize,
+	getBytesDecoder,
+	getBytesEncoder,
+	getI64Decoder,
+	getI64Encoder,
 	getStructDecoder,
 	getStructEncoder,
 	getU64Decoder,
-	getU64Encoder
+	getU64Encoder,
+	type ReadonlyUint8Array
 } from "@solana/kit"
 
-export type CounterIncreased = { newCount: bigint }
+export type CounterIncreased = {
+	counter: ReadonlyUint8Array
+	newCount: bigint
+	slot: bigint
+	timestamp: bigint
+}
 
-export type CounterIncreasedArgs = { newCount: number | bigint }
+export type CounterIncreasedArgs = {
+	counter: ReadonlyUint8Array
+	newCount: number | bigint
+	slot: number | bigint
+	timestamp: number | bigint
+}
 
 export function getCounterIncreasedEncoder(): FixedSizeEncoder<CounterIncreasedArgs> {
-	return getStructEncoder([["newCount", getU64Encoder()]])
+	return getStructEncoder([
+		["counter", fixEncoderSize(getBytesEncoder(), 32)],
+		["newCount", getU64Encoder()],
+		["slot", getU64Encoder()],
+		["timestamp", getI64Encoder()]
+	])
 }
 
 export function getCounterIncreasedDecoder(): FixedSizeDecoder<CounterIncreased> {
-	return getStructDecoder([["newCount", getU64Decoder()]])
+	return getStructDecoder([
+		["counter", fixDecoderSize(getBytesDecoder(), 32)],
+		["newCount", getU64Decoder()],
+		["slot", getU64Decoder()],
+		["timestamp", getI64Decoder()]
+	])
 }
 
 export function getCounterIncreasedCodec(): FixedSizeCodec<CounterIncreasedArgs, CounterIncreased> {
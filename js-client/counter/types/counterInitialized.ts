@@ -11,22 +11,53 @@ import {
 	type FixedSizeCodec,
 	type FixedSizeDecoder,
 	type FixedSizeEncoder,
+	fixDecoderSize,
+	fixEncoderSize,
+	getBytesDecoder,
+	getBytesEncoder,
+	getI64Decoder,
+	getI64Encoder,
 	getStructDecoder,
 	getStructEncoder,
 	getU64Decoder,
-	getU64Encoder
+	getU64Encoder,
+	type ReadonlyUint8Array
 } from "@solana/kit"
 
-export type CounterInitialized = { count: bigint }
+export type CounterInitialized = {
+	counter: ReadonlyUint8Array
+	payer: ReadonlyUint8Array
+	count: bigint
+	slot: bigint
+	timestamp: bigint
+}
 
-export type CounterInitializedArgs = { count: number | bigint }
+export type CounterInitializedArgs = {
+	counter: ReadonlyUint8Array
+	payer: ReadonlyUint8Array
+	count: number | bigint
+	slot: number | bigint
+	timestamp: number | bigint
+}
 
 export function getCounterInitializedEncoder(): FixedSizeEncoder<CounterInitializedArgs> {
-	return getStructEncoder([["count", getU64Encoder()]])
+	return getStructEncoder([
+		["counter", fixEncoderSize(getBytesEncoder(), 32)],
+		["payer", fixEncoderSize(getBytesEncoder(), 32)],
+		["count", getU64Encoder()],
+		["slot", getU64Encoder()],
+		["timestamp", getI64Encoder()]
+	])
 }
 
 export function getCounterInitializedDecoder(): FixedSizeDecoder<CounterInitialized> {
-	return getStructDecoder([["count", getU64Decoder()]])
+	return getStructDecoder([
+		["counter", fixDecoderSize(getBytesDecoder(), 32)],
+		["payer", fixDecoderSize(getBytesDecoder(), 32)],
+		["count", getU64Decoder()],
+		["slot", getU64Decoder()],
+		["timestamp", getI64Decoder()]
+	])
 }
 
 export function getCounterInitializedCodec(): FixedSizeCodec<
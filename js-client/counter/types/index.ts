@@ -6,8 +6,13 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./counterAuthorityClosed"
+export * from "./counterAuthorityDecreased"
 export * from "./counterAuthorityIncreased"
 export * from "./counterAuthorityInitialized"
-export * from "./counterEvent"
+export * from "./counterAuthorityReset"
+export * from "./counterClosed"
+export * from "./counterDecreased"
 export * from "./counterIncreased"
 export * from "./counterInitialized"
+export * from "./counterReset"
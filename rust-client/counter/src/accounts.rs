@@ -0,0 +1,100 @@
+use borsh::BorshDeserialize;
+use shared::discriminator::Discriminated;
+
+use crate::errors::ClientError;
+
+pub use counter::accounts::{Counter, CounterAuthority};
+
+/// Decodes the data of a [`Counter`] account, checking its size and
+/// discriminator first.
+pub fn decode_counter(data: &[u8]) -> Result<Counter, ClientError> {
+    decode(data, Counter::SPACE)
+}
+
+/// Decodes the data of a [`CounterAuthority`] account, checking its size and
+/// discriminator first.
+pub fn decode_counter_authority(data: &[u8]) -> Result<CounterAuthority, ClientError> {
+    decode(data, CounterAuthority::SPACE)
+}
+
+fn decode<T: Discriminated + BorshDeserialize>(
+    data: &[u8],
+    space: usize,
+) -> Result<T, ClientError> {
+    if data.len() != space {
+        return Err(ClientError::InvalidAccountLength {
+            expected: space,
+            found: data.len(),
+        });
+    }
+
+    if !T::is_discriminated(data) {
+        return Err(ClientError::AccountDiscriminatorMismatch);
+    }
+
+    T::try_from_slice(data).map_err(|_| ClientError::InvalidAccountData)
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::Address;
+    use shared::discriminator::Discriminated;
+
+    use super::{decode_counter, decode_counter_authority, Counter, CounterAuthority};
+    use crate::errors::ClientError;
+
+    fn counter_data(count: u64) -> Vec<u8> {
+        let mut data = Counter::DISCRIMINATOR.to_vec();
+        data.push(254);
+        data.extend_from_slice(&count.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn decodes_counter() {
+        let counter = decode_counter(&counter_data(19)).unwrap();
+
+        assert_eq!(counter.bump, 254);
+        assert_eq!(counter.count(), 19);
+    }
+
+    #[test]
+    fn decodes_counter_authority() {
+        let authority = Address::new_from_array([3; 32]);
+
+        let mut data = CounterAuthority::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(authority.as_ref());
+        data.push(255);
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.extend_from_slice(&30u64.to_le_bytes());
+
+        let counter = decode_counter_authority(&data).unwrap();
+
+        assert_eq!(counter.authority, authority);
+        assert_eq!(counter.count(), 7);
+        assert_eq!(counter.max_count(), 30);
+    }
+
+    #[test]
+    fn rejects_wrong_discriminator() {
+        let mut data = counter_data(19);
+        data[0] ^= 1;
+
+        assert!(matches!(
+            decode_counter(&data),
+            Err(ClientError::AccountDiscriminatorMismatch)
+        ));
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        assert!(matches!(
+            decode_counter_authority(&counter_data(19)),
+            Err(ClientError::InvalidAccountLength {
+                expected: CounterAuthority::SPACE,
+                found: Counter::SPACE,
+            })
+        ));
+    }
+}
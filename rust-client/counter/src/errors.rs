@@ -0,0 +1,22 @@
+use shared::event::{logs::LogError, EventDecodeError};
+use thiserror::Error;
+
+pub use counter::errors::CounterError;
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ClientError {
+    #[error("Account data is {found} bytes, expected {expected}")]
+    InvalidAccountLength { expected: usize, found: usize },
+
+    #[error("Account discriminator does not match the expected type")]
+    AccountDiscriminatorMismatch,
+
+    #[error("Account data could not be deserialized")]
+    InvalidAccountData,
+
+    #[error("Event could not be decoded: {0:?}")]
+    InvalidEvent(EventDecodeError),
+
+    #[error("Transaction logs could not be parsed: {0:?}")]
+    InvalidLogs(LogError),
+}
@@ -0,0 +1,148 @@
+use shared::event::{cpi, logs, EventSet};
+
+use crate::{errors::ClientError, COUNTER_PROGRAM_ADDRESS};
+
+pub use counter::events::{
+    CounterAuthorityClosed, CounterAuthorityDecreased, CounterAuthorityIncreased,
+    CounterAuthorityInitialized, CounterAuthorityReset, CounterClosed, CounterDecreased,
+    CounterEvent, CounterIncreased, CounterInitialized, CounterReset,
+};
+
+/// Decodes one encoded event, `discriminator ++ version ++ borsh(event)`.
+pub fn decode_event(data: &[u8]) -> Result<CounterEvent, ClientError> {
+    CounterEvent::decode(data).map_err(ClientError::InvalidEvent)
+}
+
+/// Every event the counter program logged in a transaction, in order. Events
+/// logged by other programs, including forgeries by CPI callees, are skipped.
+pub fn decode_logs<S: AsRef<str>>(logs: &[S]) -> Result<Vec<CounterEvent>, ClientError> {
+    logs::decode_logs(&COUNTER_PROGRAM_ADDRESS, logs).map_err(ClientError::InvalidLogs)
+}
+
+/// The event carried by the data of a self-CPI of a `cpi-events` build, or
+/// `None` for any other instruction. Events too large for one CPI are sent in
+/// chunks, which only [`decode_event_instructions`] reassembles.
+///
+/// Only trust inner instructions invoking the counter program with the event
+/// authority (see `pdas::find_event_authority_pda`) as their first account.
+pub fn decode_event_instruction(
+    instruction_data: &[u8],
+) -> Option<Result<CounterEvent, ClientError>> {
+    cpi::event_data(instruction_data).map(decode_event)
+}
+
+/// Every event carried by the data of the self-CPIs of a `cpi-events` build,
+/// in order, chunked ones reassembled. Other instructions are skipped.
+///
+/// Pass only the trusted inner instructions, as for
+/// [`decode_event_instruction`].
+pub fn decode_event_instructions<'a>(
+    instructions: impl IntoIterator<Item = &'a [u8]>,
+) -> Result<Vec<CounterEvent>, ClientError> {
+    let mut events = cpi::EventInstructions::new();
+
+    let decoded = instructions
+        .into_iter()
+        .filter_map(|data| events.push(data))
+        .map(|data| decode_event(&data.map_err(ClientError::InvalidEvent)?))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    events.finish().map_err(ClientError::InvalidEvent)?;
+
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod test {
+    use borsh::BorshSerialize;
+    use pinocchio::Address;
+    use shared::event::{
+        chunk::checksum,
+        cpi::{EVENT_CHUNK_IX_TAG, EVENT_IX_TAG},
+        Event, EventDecodeError,
+    };
+
+    use super::{
+        decode_event_instruction, decode_event_instructions, CounterEvent, CounterIncreased,
+    };
+    use crate::errors::ClientError;
+
+    fn counter_increased(new_count: u64) -> Vec<u8> {
+        let mut data = CounterIncreased::DISCRIMINATOR.to_vec();
+        data.push(CounterIncreased::VERSION);
+        CounterIncreased {
+            counter: Address::new_from_array([4; 32]),
+            new_count,
+            slot: 1,
+            timestamp: 2,
+        }
+        .serialize(&mut data)
+        .unwrap();
+        data
+    }
+
+    /// `data` sent as self-CPI chunks of `len` bytes each, as the program
+    /// does for events too large for one CPI.
+    fn chunk_instructions(data: &[u8], len: usize) -> Vec<Vec<u8>> {
+        let count = data.len().div_ceil(len) as u16;
+
+        data.chunks(len)
+            .enumerate()
+            .map(|(index, payload)| {
+                let mut instruction = EVENT_CHUNK_IX_TAG.to_vec();
+                instruction.extend_from_slice(&(index as u16).to_le_bytes());
+                instruction.extend_from_slice(&count.to_le_bytes());
+                instruction.extend_from_slice(&checksum(data).to_le_bytes());
+                instruction.extend_from_slice(payload);
+                instruction
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decodes_self_cpi_events() {
+        let event = CounterIncreased {
+            counter: Address::new_from_array([4; 32]),
+            new_count: 20,
+            slot: 1,
+            timestamp: 2,
+        };
+
+        let mut data = EVENT_IX_TAG.to_vec();
+        data.extend_from_slice(&CounterIncreased::DISCRIMINATOR);
+        data.push(CounterIncreased::VERSION);
+        event.serialize(&mut data).unwrap();
+
+        let Some(Ok(CounterEvent::CounterIncreased(decoded))) = decode_event_instruction(&data)
+        else {
+            panic!("expected a CounterIncreased event");
+        };
+
+        assert_eq!(decoded.new_count, 20);
+        assert!(decode_event_instruction(&[0; 9]).is_none());
+    }
+
+    #[test]
+    fn decodes_chunked_self_cpi_events() {
+        let whole = [&EVENT_IX_TAG[..], &counter_increased(20)].concat();
+        let chunks = chunk_instructions(&counter_increased(21), 16);
+
+        let instructions = [vec![whole, vec![0; 9]], chunks.clone()].concat();
+
+        let events = decode_event_instructions(instructions.iter().map(Vec::as_slice)).unwrap();
+        let counts = events
+            .iter()
+            .map(|event| match event {
+                CounterEvent::CounterIncreased(event) => event.new_count,
+                _ => panic!("expected CounterIncreased events"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(counts, [20, 21]);
+
+        assert!(matches!(
+            decode_event_instructions(chunks[..1].iter().map(Vec::as_slice)),
+            Err(ClientError::InvalidEvent(EventDecodeError::Truncated))
+        ));
+    }
+}
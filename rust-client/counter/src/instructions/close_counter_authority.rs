@@ -0,0 +1,73 @@
+use counter::CounterInstruction;
+use pinocchio::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+use crate::{pdas::find_counter_authority_pda, COUNTER_PROGRAM_ADDRESS};
+
+/// Builds `CloseCounterAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` counter_authority, defaults to
+///      [`find_counter_authority_pda`] of `authority`
+///   2. `[writable]` destination, defaults to `authority`
+#[derive(Clone, Debug, Default)]
+pub struct CloseCounterAuthorityBuilder {
+    authority: Option<Address>,
+    counter_authority: Option<Address>,
+    destination: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl CloseCounterAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+
+    pub fn destination(&mut self, destination: Address) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// When `authority` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let authority = self.authority.expect("authority is not set");
+
+        let accounts = [
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(
+                self.counter_authority
+                    .unwrap_or_else(|| find_counter_authority_pda(&authority).0),
+                false,
+            ),
+            AccountMeta::new(self.destination.unwrap_or(authority), false),
+        ];
+
+        Instruction {
+            program_id: COUNTER_PROGRAM_ADDRESS,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data: borsh::to_vec(&CounterInstruction::CloseCounterAuthority).unwrap(),
+        }
+    }
+}
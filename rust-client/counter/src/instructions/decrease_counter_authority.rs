@@ -0,0 +1,75 @@
+use counter::{CounterInstruction, DecreaseCounterAuthorityArgs};
+use pinocchio::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+use crate::{pdas::find_counter_authority_pda, COUNTER_PROGRAM_ADDRESS};
+
+/// Builds `DecreaseCounterAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` counter_authority, defaults to
+///      [`find_counter_authority_pda`] of `authority`
+#[derive(Clone, Debug, Default)]
+pub struct DecreaseCounterAuthorityBuilder {
+    authority: Option<Address>,
+    counter_authority: Option<Address>,
+    amount: Option<u64>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl DecreaseCounterAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// When `authority` or `amount` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let authority = self.authority.expect("authority is not set");
+
+        let accounts = [
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(
+                self.counter_authority
+                    .unwrap_or_else(|| find_counter_authority_pda(&authority).0),
+                false,
+            ),
+        ];
+
+        let data = CounterInstruction::DecreaseCounterAuthority(DecreaseCounterAuthorityArgs {
+            amount: self.amount.expect("amount is not set"),
+        });
+
+        Instruction {
+            program_id: COUNTER_PROGRAM_ADDRESS,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data: borsh::to_vec(&data).unwrap(),
+        }
+    }
+}
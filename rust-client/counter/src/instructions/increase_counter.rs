@@ -0,0 +1,48 @@
+use counter::CounterInstruction;
+use pinocchio::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+use crate::{pdas::find_counter_pda, COUNTER_PROGRAM_ADDRESS};
+
+/// Builds `IncreaseCounter`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` counter, defaults to [`find_counter_pda`]
+#[derive(Clone, Debug, Default)]
+pub struct IncreaseCounterBuilder {
+    counter: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl IncreaseCounterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [AccountMeta::new(
+            self.counter.unwrap_or_else(|| find_counter_pda().0),
+            false,
+        )];
+
+        Instruction {
+            program_id: COUNTER_PROGRAM_ADDRESS,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data: borsh::to_vec(&CounterInstruction::IncreaseCounter).unwrap(),
+        }
+    }
+}
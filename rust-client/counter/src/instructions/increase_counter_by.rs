@@ -0,0 +1,61 @@
+use counter::{CounterInstruction, IncreaseCounterByArgs};
+use pinocchio::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+use crate::{pdas::find_counter_pda, COUNTER_PROGRAM_ADDRESS};
+
+/// Builds `IncreaseCounterBy`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` counter, defaults to [`find_counter_pda`]
+#[derive(Clone, Debug, Default)]
+pub struct IncreaseCounterByBuilder {
+    counter: Option<Address>,
+    amount: Option<u64>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl IncreaseCounterByBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// When `amount` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [AccountMeta::new(
+            self.counter.unwrap_or_else(|| find_counter_pda().0),
+            false,
+        )];
+
+        let data = CounterInstruction::IncreaseCounterBy(IncreaseCounterByArgs {
+            amount: self.amount.expect("amount is not set"),
+        });
+
+        Instruction {
+            program_id: COUNTER_PROGRAM_ADDRESS,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data: borsh::to_vec(&data).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use counter::{CounterInstruction, InitCounterArgs};
+use pinocchio::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+use crate::{pdas::find_counter_pda, COUNTER_PROGRAM_ADDRESS};
+
+/// Builds `InitCounter`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` counter, defaults to [`find_counter_pda`]
+///   2. `[]` system_program, defaults to the system program
+#[derive(Clone, Debug, Default)]
+pub struct InitCounterBuilder {
+    payer: Option<Address>,
+    counter: Option<Address>,
+    system_program: Option<Address>,
+    count: Option<u64>,
+    max_count: Option<u64>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl InitCounterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn payer(&mut self, payer: Address) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+
+    pub fn system_program(&mut self, system_program: Address) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+
+    pub fn count(&mut self, count: u64) -> &mut Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Caps the count, which is uncapped when not set.
+    pub fn max_count(&mut self, max_count: u64) -> &mut Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// When `payer` or `count` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new(self.payer.expect("payer is not set"), true),
+            AccountMeta::new(self.counter.unwrap_or_else(|| find_counter_pda().0), false),
+            AccountMeta::new_readonly(self.system_program.unwrap_or(pinocchio_system::ID), false),
+        ];
+
+        let data = CounterInstruction::InitCounter(InitCounterArgs {
+            count: self.count.expect("count is not set"),
+            max_count: self.max_count,
+        });
+
+        Instruction {
+            program_id: COUNTER_PROGRAM_ADDRESS,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data: borsh::to_vec(&data).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use borsh::BorshDeserialize;
+    use counter::CounterInstruction;
+    use pinocchio::Address;
+    use solana_instruction::AccountMeta;
+
+    use super::InitCounterBuilder;
+    use crate::{
+        pdas::{event_accounts, find_counter_pda},
+        COUNTER_PROGRAM_ADDRESS,
+    };
+
+    #[test]
+    fn defaults_counter_and_system_program() {
+        let payer = Address::new_from_array([1; 32]);
+
+        let ix = InitCounterBuilder::new()
+            .payer(payer)
+            .count(19)
+            .instruction();
+
+        assert_eq!(ix.program_id, COUNTER_PROGRAM_ADDRESS);
+        assert_eq!(
+            ix.accounts,
+            [
+                AccountMeta::new(payer, true),
+                AccountMeta::new(find_counter_pda().0, false),
+                AccountMeta::new_readonly(pinocchio_system::ID, false),
+            ]
+        );
+
+        let CounterInstruction::InitCounter(args) =
+            CounterInstruction::try_from_slice(&ix.data).unwrap()
+        else {
+            panic!("expected InitCounter");
+        };
+
+        assert_eq!(args.count, 19);
+        assert_eq!(args.max_count, None);
+    }
+
+    #[test]
+    fn sets_max_count() {
+        let ix = InitCounterBuilder::new()
+            .payer(Address::new_from_array([1; 32]))
+            .count(0)
+            .max_count(30)
+            .instruction();
+
+        let CounterInstruction::InitCounter(args) =
+            CounterInstruction::try_from_slice(&ix.data).unwrap()
+        else {
+            panic!("expected InitCounter");
+        };
+
+        assert_eq!(args.max_count, Some(30));
+    }
+
+    #[test]
+    fn appends_remaining_accounts() {
+        let ix = InitCounterBuilder::new()
+            .payer(Address::new_from_array([1; 32]))
+            .count(0)
+            .add_remaining_accounts(&event_accounts())
+            .instruction();
+
+        assert_eq!(ix.accounts[3..], event_accounts());
+    }
+
+    #[test]
+    #[should_panic(expected = "payer is not set")]
+    fn requires_payer() {
+        InitCounterBuilder::new().count(1).instruction();
+    }
+}
@@ -0,0 +1,92 @@
+use counter::{CounterInstruction, InitCounterAuthorityArgs};
+use pinocchio::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+use crate::{pdas::find_counter_authority_pda, COUNTER_PROGRAM_ADDRESS};
+
+/// Builds `InitCounterAuthority` (`InitCounterAuhthority` on-chain and in the
+/// IDL).
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer, who becomes the counter's authority
+///   1. `[writable]` counter_authority, defaults to
+///      [`find_counter_authority_pda`] of `payer`
+///   2. `[]` system_program, defaults to the system program
+#[derive(Clone, Debug, Default)]
+pub struct InitCounterAuthorityBuilder {
+    payer: Option<Address>,
+    counter_authority: Option<Address>,
+    system_program: Option<Address>,
+    count: Option<u64>,
+    max_count: Option<u64>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl InitCounterAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn payer(&mut self, payer: Address) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+
+    pub fn system_program(&mut self, system_program: Address) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+
+    pub fn count(&mut self, count: u64) -> &mut Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Caps the count, which is uncapped when not set.
+    pub fn max_count(&mut self, max_count: u64) -> &mut Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// When `payer` or `count` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let payer = self.payer.expect("payer is not set");
+
+        let accounts = [
+            AccountMeta::new(payer, true),
+            AccountMeta::new(
+                self.counter_authority
+                    .unwrap_or_else(|| find_counter_authority_pda(&payer).0),
+                false,
+            ),
+            AccountMeta::new_readonly(self.system_program.unwrap_or(pinocchio_system::ID), false),
+        ];
+
+        let data = CounterInstruction::InitCounterAuhthority(InitCounterAuthorityArgs {
+            count: self.count.expect("count is not set"),
+            max_count: self.max_count,
+        });
+
+        Instruction {
+            program_id: COUNTER_PROGRAM_ADDRESS,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data: borsh::to_vec(&data).unwrap(),
+        }
+    }
+}
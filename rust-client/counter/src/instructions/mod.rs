@@ -0,0 +1,32 @@
+//! Builders for the counter instructions.
+//!
+//! Accounts that are PDAs or well-known programs default to their canonical
+//! address, so only signers and arguments have to be set. With a `cpi-events`
+//! build of the program, pass `pdas::event_accounts()` to
+//! `add_remaining_accounts`.
+
+mod close_counter;
+mod close_counter_authority;
+mod decrease_counter;
+mod decrease_counter_authority;
+mod increase_counter;
+mod increase_counter_authority;
+mod increase_counter_authority_by;
+mod increase_counter_by;
+mod init_counter;
+mod init_counter_authority;
+mod reset_counter;
+mod reset_counter_authority;
+
+pub use close_counter::*;
+pub use close_counter_authority::*;
+pub use decrease_counter::*;
+pub use decrease_counter_authority::*;
+pub use increase_counter::*;
+pub use increase_counter_authority::*;
+pub use increase_counter_authority_by::*;
+pub use increase_counter_by::*;
+pub use init_counter::*;
+pub use init_counter_authority::*;
+pub use reset_counter::*;
+pub use reset_counter_authority::*;
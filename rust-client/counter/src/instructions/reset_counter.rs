@@ -0,0 +1,83 @@
+use counter::{CounterInstruction, ResetCounterArgs};
+use pinocchio::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+use crate::{
+    pdas::{find_counter_pda, find_program_data_pda},
+    COUNTER_PROGRAM_ADDRESS,
+};
+
+/// Builds `ResetCounter`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` admin
+///   1. `[writable]` counter, defaults to [`find_counter_pda`]
+///   2. `[]` program_data, defaults to [`find_program_data_pda`]
+#[derive(Clone, Debug, Default)]
+pub struct ResetCounterBuilder {
+    admin: Option<Address>,
+    counter: Option<Address>,
+    program_data: Option<Address>,
+    count: Option<u64>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl ResetCounterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn admin(&mut self, admin: Address) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+
+    pub fn program_data(&mut self, program_data: Address) -> &mut Self {
+        self.program_data = Some(program_data);
+        self
+    }
+
+    pub fn count(&mut self, count: u64) -> &mut Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// When `admin` or `count` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(self.admin.expect("admin is not set"), true),
+            AccountMeta::new(self.counter.unwrap_or_else(|| find_counter_pda().0), false),
+            AccountMeta::new_readonly(
+                self.program_data
+                    .unwrap_or_else(|| find_program_data_pda().0),
+                false,
+            ),
+        ];
+
+        let data = CounterInstruction::ResetCounter(ResetCounterArgs {
+            count: self.count.expect("count is not set"),
+        });
+
+        Instruction {
+            program_id: COUNTER_PROGRAM_ADDRESS,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data: borsh::to_vec(&data).unwrap(),
+        }
+    }
+}
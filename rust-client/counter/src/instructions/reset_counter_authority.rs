@@ -0,0 +1,75 @@
+use counter::{CounterInstruction, ResetCounterAuthorityArgs};
+use pinocchio::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+use crate::{pdas::find_counter_authority_pda, COUNTER_PROGRAM_ADDRESS};
+
+/// Builds `ResetCounterAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` counter_authority, defaults to
+///      [`find_counter_authority_pda`] of `authority`
+#[derive(Clone, Debug, Default)]
+pub struct ResetCounterAuthorityBuilder {
+    authority: Option<Address>,
+    counter_authority: Option<Address>,
+    count: Option<u64>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl ResetCounterAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+
+    pub fn count(&mut self, count: u64) -> &mut Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// When `authority` or `count` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let authority = self.authority.expect("authority is not set");
+
+        let accounts = [
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(
+                self.counter_authority
+                    .unwrap_or_else(|| find_counter_authority_pda(&authority).0),
+                false,
+            ),
+        ];
+
+        let data = CounterInstruction::ResetCounterAuthority(ResetCounterAuthorityArgs {
+            count: self.count.expect("count is not set"),
+        });
+
+        Instruction {
+            program_id: COUNTER_PROGRAM_ADDRESS,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data: borsh::to_vec(&data).unwrap(),
+        }
+    }
+}
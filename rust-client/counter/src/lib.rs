@@ -0,0 +1,177 @@
+//! Rust client for the counter program, the counterpart of the codama-generated
+//! `js-client/counter`: instruction builders, PDA helpers, account decoders
+//! and event decoding.
+
+pub mod accounts;
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod pdas;
+
+use pinocchio::Address;
+
+pub use counter::ID as COUNTER_ID;
+
+pub const COUNTER_PROGRAM_ADDRESS: Address = Address::new_from_array(counter::ID);
+
+#[cfg(test)]
+mod test {
+    use solana_sdk::signer::Signer;
+    use test_utils::Fixture;
+
+    use crate::{
+        accounts::{decode_counter, decode_counter_authority},
+        events::{decode_logs, CounterEvent},
+        instructions::{
+            CloseCounterAuthorityBuilder, CloseCounterBuilder, DecreaseCounterAuthorityBuilder,
+            DecreaseCounterBuilder, IncreaseCounterAuthorityBuilder,
+            IncreaseCounterAuthorityByBuilder, IncreaseCounterBuilder, IncreaseCounterByBuilder,
+            InitCounterAuthorityBuilder, InitCounterBuilder, ResetCounterAuthorityBuilder,
+            ResetCounterBuilder,
+        },
+        pdas::{find_counter_authority_pda, find_counter_pda},
+        COUNTER_PROGRAM_ADDRESS,
+    };
+
+    #[test]
+    fn drives_the_program_end_to_end() {
+        let mut fixture = Fixture::new(COUNTER_PROGRAM_ADDRESS, "counter", counter::process);
+
+        let payer = fixture.payer.pubkey();
+
+        // The payer as the upgrade authority of the program, its admin.
+        fixture.set_upgrade_authority(&COUNTER_PROGRAM_ADDRESS, Some(payer));
+
+        let instructions = [
+            InitCounterBuilder::new()
+                .payer(payer)
+                .count(19)
+                .instruction(),
+            IncreaseCounterBuilder::new().instruction(),
+            IncreaseCounterByBuilder::new().amount(5).instruction(),
+            DecreaseCounterBuilder::new()
+                .admin(payer)
+                .amount(3)
+                .instruction(),
+            ResetCounterBuilder::new()
+                .admin(payer)
+                .count(10)
+                .instruction(),
+            InitCounterAuthorityBuilder::new()
+                .payer(payer)
+                .count(5)
+                .instruction(),
+            IncreaseCounterAuthorityBuilder::new()
+                .authority(payer)
+                .instruction(),
+            IncreaseCounterAuthorityByBuilder::new()
+                .authority(payer)
+                .amount(4)
+                .instruction(),
+            DecreaseCounterAuthorityBuilder::new()
+                .authority(payer)
+                .amount(2)
+                .instruction(),
+            ResetCounterAuthorityBuilder::new()
+                .authority(payer)
+                .count(7)
+                .instruction(),
+        ];
+
+        let outcome = fixture.send_signed(&instructions, &[]);
+        outcome.assert_success();
+
+        let events = decode_logs(outcome.logs()).unwrap();
+
+        assert!(matches!(
+            &events[..],
+            [
+                CounterEvent::CounterInitialized(_),
+                CounterEvent::CounterIncreased(_),
+                CounterEvent::CounterIncreased(_),
+                CounterEvent::CounterDecreased(_),
+                CounterEvent::CounterReset(_),
+                CounterEvent::CounterAuthorityInitialized(_),
+                CounterEvent::CounterAuthorityIncreased(_),
+                CounterEvent::CounterAuthorityIncreased(_),
+                CounterEvent::CounterAuthorityDecreased(_),
+                CounterEvent::CounterAuthorityReset(_),
+            ]
+        ));
+
+        let counter = fixture.account(&find_counter_pda().0).unwrap();
+        let counter_authority = fixture
+            .account(&find_counter_authority_pda(&payer).0)
+            .unwrap();
+
+        assert_eq!(decode_counter(&counter.data).unwrap().count(), 10);
+
+        let counter_authority = decode_counter_authority(&counter_authority.data).unwrap();
+
+        assert_eq!(counter_authority.authority, payer);
+        assert_eq!(counter_authority.count(), 7);
+    }
+
+    #[test]
+    fn closes_and_reinitializes_the_counters() {
+        let mut fixture = Fixture::new(COUNTER_PROGRAM_ADDRESS, "counter", counter::process);
+
+        let payer = fixture.payer.pubkey();
+
+        // The payer as the upgrade authority of the program, its admin.
+        fixture.set_upgrade_authority(&COUNTER_PROGRAM_ADDRESS, Some(payer));
+
+        let instructions = [
+            InitCounterBuilder::new()
+                .payer(payer)
+                .count(19)
+                .instruction(),
+            InitCounterAuthorityBuilder::new()
+                .payer(payer)
+                .count(5)
+                .instruction(),
+            CloseCounterBuilder::new().admin(payer).instruction(),
+            CloseCounterAuthorityBuilder::new()
+                .authority(payer)
+                .instruction(),
+            InitCounterBuilder::new()
+                .payer(payer)
+                .count(1)
+                .instruction(),
+            InitCounterAuthorityBuilder::new()
+                .payer(payer)
+                .count(2)
+                .instruction(),
+        ];
+
+        let outcome = fixture.send_signed(&instructions, &[]);
+        outcome.assert_success();
+
+        let events = decode_logs(outcome.logs()).unwrap();
+
+        assert!(matches!(
+            &events[..],
+            [
+                CounterEvent::CounterInitialized(_),
+                CounterEvent::CounterAuthorityInitialized(_),
+                CounterEvent::CounterClosed(_),
+                CounterEvent::CounterAuthorityClosed(_),
+                CounterEvent::CounterInitialized(_),
+                CounterEvent::CounterAuthorityInitialized(_),
+            ]
+        ));
+
+        let counter = fixture.account(&find_counter_pda().0).unwrap();
+        let counter_authority = fixture
+            .account(&find_counter_authority_pda(&payer).0)
+            .unwrap();
+
+        assert_eq!(decode_counter(&counter.data).unwrap().count(), 1);
+        assert_eq!(
+            decode_counter_authority(&counter_authority.data)
+                .unwrap()
+                .count(),
+            2
+        );
+    }
+}
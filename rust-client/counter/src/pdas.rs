@@ -0,0 +1,37 @@
+use counter::accounts::{Counter, CounterAuthority, ProgramData};
+use pinocchio::Address;
+use shared::event::cpi::EVENT_AUTHORITY_SEED;
+use solana_instruction::AccountMeta;
+
+use crate::COUNTER_PROGRAM_ADDRESS;
+
+/// The program-wide counter, `["counter"]`.
+pub fn find_counter_pda() -> (Address, u8) {
+    Counter::derive()
+}
+
+/// The counter owned by `authority`, `["counter_authority", authority]`.
+pub fn find_counter_authority_pda(authority: &Address) -> (Address, u8) {
+    CounterAuthority::derive(authority)
+}
+
+/// The program's data account under the upgradeable loader, whose upgrade
+/// authority is the program admin, `[program]`.
+pub fn find_program_data_pda() -> (Address, u8) {
+    ProgramData::derive()
+}
+
+/// The signer of the self-CPI events of a `cpi-events` build,
+/// `["__event_authority"]`.
+pub fn find_event_authority_pda() -> (Address, u8) {
+    Address::find_program_address(&[EVENT_AUTHORITY_SEED], &COUNTER_PROGRAM_ADDRESS)
+}
+
+/// The two trailing accounts every instruction of a `cpi-events` build
+/// expects, for the builders' `add_remaining_accounts`.
+pub fn event_accounts() -> [AccountMeta; 2] {
+    [
+        AccountMeta::new_readonly(find_event_authority_pda().0, false),
+        AccountMeta::new_readonly(COUNTER_PROGRAM_ADDRESS, false),
+    ]
+}
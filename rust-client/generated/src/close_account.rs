@@ -0,0 +1,223 @@
+//! Client for the `close_account` program, generated by `idl-codegen` from
+//! `idl/close_account.json`. Do not edit, run `pnpm gen:rust-client` instead.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_address::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+pub const PROGRAM_ID: Address = Address::from_str_const(
+    "2HXWQuEjgRDbNcMx3X32C1aw4fftVMHyUf9KXYyTiPiD",
+);
+
+const SYSTEM_PROGRAM_ID: Address = Address::from_str_const(
+    "11111111111111111111111111111111",
+);
+
+/// Custom program errors, surfaced as `ProgramError::Custom(code)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CloseAccountError {
+    InvalidMemeAddress = 6000,
+    MemeAlreadyInitialized = 6001,
+    InvalidMemeOwner = 6002,
+    InvalidMemeData = 6003,
+    AuthorityMismatch = 6004,
+    InvalidDestination = 6005,
+    AccountDiscriminatorMismatch = 6006,
+}
+
+impl CloseAccountError {
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            6000 => Some(Self::InvalidMemeAddress),
+            6001 => Some(Self::MemeAlreadyInitialized),
+            6002 => Some(Self::InvalidMemeOwner),
+            6003 => Some(Self::InvalidMemeData),
+            6004 => Some(Self::AuthorityMismatch),
+            6005 => Some(Self::InvalidDestination),
+            6006 => Some(Self::AccountDiscriminatorMismatch),
+            _ => None,
+        }
+    }
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::InvalidMemeAddress => "Meme address does not match the expected PDA",
+            Self::MemeAlreadyInitialized => "Meme is already initialized",
+            Self::InvalidMemeOwner => "Meme is not owned by the program",
+            Self::InvalidMemeData => "Meme data could not be deserialized",
+            Self::AuthorityMismatch => "Signer is not the meme authority",
+            Self::InvalidDestination => "Destination cannot be the meme account",
+            Self::AccountDiscriminatorMismatch => {
+                "Account discriminator does not match the expected type"
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for CloseAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for CloseAccountError {}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Meme {
+    pub discriminator: [u8; 8],
+    pub address: Address,
+    pub authority: Address,
+    pub bump: u8,
+}
+
+impl Meme {
+    pub const DISCRIMINATOR: [u8; 8] = [232, 224, 0, 147, 187, 194, 135, 26];
+    pub const LEN: usize = 73;
+    /// Decodes the account data, checking its discriminator first.
+    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        if !data.starts_with(&Self::DISCRIMINATOR) {
+            return Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "account discriminator mismatch",
+                ),
+            );
+        }
+        Self::try_from_slice(data)
+    }
+}
+
+/// Derives the [`Meme`] PDA from `["meme"]`.
+pub fn find_meme_pda() -> (Address, u8) {
+    Address::find_program_address(&[b"meme"], &PROGRAM_ID)
+}
+
+/// Builds `CreateMeme`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` meme, defaults to [`find_meme_pda`]
+///   2. `[]` system_program, defaults to the system program
+#[derive(Clone, Debug, Default)]
+pub struct CreateMemeBuilder {
+    payer: Option<Address>,
+    meme: Option<Address>,
+    system_program: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl CreateMemeBuilder {
+    pub const DISCRIMINANT: u8 = 0;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn payer(&mut self, payer: Address) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn meme(&mut self, meme: Address) -> &mut Self {
+        self.meme = Some(meme);
+        self
+    }
+    pub fn system_program(&mut self, system_program: Address) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `payer` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new(self.payer.expect("payer is not set"), true),
+            AccountMeta::new(self.meme.unwrap_or_else(|| find_meme_pda().0), false),
+            AccountMeta::new_readonly(
+                self.system_program.unwrap_or(SYSTEM_PROGRAM_ID),
+                false,
+            ),
+        ];
+        let data = vec![Self::DISCRIMINANT];
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `CloseMeme`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` meme, defaults to [`find_meme_pda`]
+///   2. `[writable]` destination
+///   3. `[]` system_program, defaults to the system program
+#[derive(Clone, Debug, Default)]
+pub struct CloseMemeBuilder {
+    authority: Option<Address>,
+    meme: Option<Address>,
+    destination: Option<Address>,
+    system_program: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl CloseMemeBuilder {
+    pub const DISCRIMINANT: u8 = 1;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn meme(&mut self, meme: Address) -> &mut Self {
+        self.meme = Some(meme);
+        self
+    }
+    pub fn destination(&mut self, destination: Address) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn system_program(&mut self, system_program: Address) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `authority` or `destination` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(
+                self.authority.expect("authority is not set"),
+                true,
+            ),
+            AccountMeta::new(self.meme.unwrap_or_else(|| find_meme_pda().0), false),
+            AccountMeta::new(self.destination.expect("destination is not set"), false),
+            AccountMeta::new_readonly(
+                self.system_program.unwrap_or(SYSTEM_PROGRAM_ID),
+                false,
+            ),
+        ];
+        let data = vec![Self::DISCRIMINANT];
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
@@ -0,0 +1,1175 @@
+//! Client for the `counter` program, generated by `idl-codegen` from
+//! `idl/counter.json`. Do not edit, run `pnpm gen:rust-client` instead.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_address::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+pub const PROGRAM_ID: Address = Address::from_str_const(
+    "8F1XtWR4wTs37nnutBvd2MWpCTfb7XAciFYkw5XHaENj",
+);
+
+const SYSTEM_PROGRAM_ID: Address = Address::from_str_const(
+    "11111111111111111111111111111111",
+);
+
+/// Custom program errors, surfaced as `ProgramError::Custom(code)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CounterError {
+    InvalidCounterAddress = 6000,
+    CounterAlreadyInitialized = 6001,
+    CounterNotInitialized = 6002,
+    InvalidCounterData = 6003,
+    AuthorityMismatch = 6004,
+    AccountDiscriminatorMismatch = 6005,
+    InvalidEventAuthority = 6006,
+    CountOverflow = 6007,
+    MaxCountExceeded = 6008,
+    CountUnderflow = 6009,
+    AdminMismatch = 6010,
+    InvalidProgramData = 6011,
+    InvalidDestination = 6012,
+}
+
+impl CounterError {
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            6000 => Some(Self::InvalidCounterAddress),
+            6001 => Some(Self::CounterAlreadyInitialized),
+            6002 => Some(Self::CounterNotInitialized),
+            6003 => Some(Self::InvalidCounterData),
+            6004 => Some(Self::AuthorityMismatch),
+            6005 => Some(Self::AccountDiscriminatorMismatch),
+            6006 => Some(Self::InvalidEventAuthority),
+            6007 => Some(Self::CountOverflow),
+            6008 => Some(Self::MaxCountExceeded),
+            6009 => Some(Self::CountUnderflow),
+            6010 => Some(Self::AdminMismatch),
+            6011 => Some(Self::InvalidProgramData),
+            6012 => Some(Self::InvalidDestination),
+            _ => None,
+        }
+    }
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::InvalidCounterAddress => {
+                "Counter address does not match the expected PDA"
+            }
+            Self::CounterAlreadyInitialized => "Counter is already initialized",
+            Self::CounterNotInitialized => "Counter is not initialized",
+            Self::InvalidCounterData => "Counter data could not be deserialized",
+            Self::AuthorityMismatch => "Signer is not the counter authority",
+            Self::AccountDiscriminatorMismatch => {
+                "Account discriminator does not match the expected type"
+            }
+            Self::InvalidEventAuthority => {
+                "Event authority address does not match the expected PDA"
+            }
+            Self::CountOverflow => "Counter would overflow",
+            Self::MaxCountExceeded => "Count would exceed the counter maximum",
+            Self::CountUnderflow => "Counter would go below zero",
+            Self::AdminMismatch => "Signer is not the program admin",
+            Self::InvalidProgramData => "Program data address does not match the program",
+            Self::InvalidDestination => "Destination cannot be the closed counter",
+        }
+    }
+}
+
+impl std::fmt::Display for CounterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for CounterError {}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterClosed {
+    pub counter: Address,
+    pub admin: Address,
+    pub destination: Address,
+    pub lamports: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterAuthorityClosed {
+    pub counter: Address,
+    pub authority: Address,
+    pub destination: Address,
+    pub lamports: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterDecreased {
+    pub counter: Address,
+    pub new_count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterAuthorityDecreased {
+    pub counter: Address,
+    pub authority: Address,
+    pub new_count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterIncreased {
+    pub counter: Address,
+    pub new_count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterAuthorityIncreased {
+    pub counter: Address,
+    pub authority: Address,
+    pub new_count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterInitialized {
+    pub counter: Address,
+    pub payer: Address,
+    pub count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterAuthorityInitialized {
+    pub counter: Address,
+    pub authority: Address,
+    pub count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterReset {
+    pub counter: Address,
+    pub new_count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterAuthorityReset {
+    pub counter: Address,
+    pub authority: Address,
+    pub new_count: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct DecreaseCounterArgs {
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct DecreaseCounterAuthorityArgs {
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct IncreaseCounterByArgs {
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct IncreaseCounterAuthorityByArgs {
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct InitCounterArgs {
+    pub count: u64,
+    pub max_count: Option<u64>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct InitCounterAuthorityArgs {
+    pub count: u64,
+    pub max_count: Option<u64>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ResetCounterArgs {
+    pub count: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ResetCounterAuthorityArgs {
+    pub count: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct Counter {
+    pub discriminator: [u8; 8],
+    pub bump: u8,
+    pub count: u64,
+    pub max_count: u64,
+}
+
+impl Counter {
+    pub const DISCRIMINATOR: [u8; 8] = [255, 176, 4, 245, 188, 253, 124, 25];
+    pub const LEN: usize = 25;
+    /// Decodes the account data, checking its discriminator first.
+    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        if !data.starts_with(&Self::DISCRIMINATOR) {
+            return Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "account discriminator mismatch",
+                ),
+            );
+        }
+        Self::try_from_slice(data)
+    }
+}
+
+/// Derives the [`Counter`] PDA from `["counter"]`.
+pub fn find_counter_pda() -> (Address, u8) {
+    Address::find_program_address(&[b"counter"], &PROGRAM_ID)
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CounterAuthority {
+    pub discriminator: [u8; 8],
+    pub authority: Address,
+    pub bump: u8,
+    pub count: u64,
+    pub max_count: u64,
+}
+
+impl CounterAuthority {
+    pub const DISCRIMINATOR: [u8; 8] = [174, 239, 40, 200, 210, 232, 249, 180];
+    pub const LEN: usize = 57;
+    /// Decodes the account data, checking its discriminator first.
+    pub fn from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        if !data.starts_with(&Self::DISCRIMINATOR) {
+            return Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "account discriminator mismatch",
+                ),
+            );
+        }
+        Self::try_from_slice(data)
+    }
+}
+
+/// Derives the [`CounterAuthority`] PDA from `["counter_authority", authority]`.
+///
+/// * `authority` - The authority of the counter
+pub fn find_counter_authority_pda(authority: &Address) -> (Address, u8) {
+    Address::find_program_address(
+        &[b"counter_authority", authority.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Builds `InitCounter`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` counter, defaults to [`find_counter_pda`]
+///   2. `[]` system_program, defaults to the system program
+#[derive(Clone, Debug, Default)]
+pub struct InitCounterBuilder {
+    payer: Option<Address>,
+    counter: Option<Address>,
+    system_program: Option<Address>,
+    init_counter_args: Option<InitCounterArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl InitCounterBuilder {
+    pub const DISCRIMINANT: u8 = 0;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn payer(&mut self, payer: Address) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+    pub fn system_program(&mut self, system_program: Address) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn init_counter_args(
+        &mut self,
+        init_counter_args: InitCounterArgs,
+    ) -> &mut Self {
+        self.init_counter_args = Some(init_counter_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `payer` or `init_counter_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new(self.payer.expect("payer is not set"), true),
+            AccountMeta::new(
+                self.counter.unwrap_or_else(|| find_counter_pda().0),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                self.system_program.unwrap_or(SYSTEM_PROGRAM_ID),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.init_counter_args
+            .as_ref()
+            .expect("init_counter_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `IncreaseCounter`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` counter, defaults to [`find_counter_pda`]
+#[derive(Clone, Debug, Default)]
+pub struct IncreaseCounterBuilder {
+    counter: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl IncreaseCounterBuilder {
+    pub const DISCRIMINANT: u8 = 1;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new(self.counter.unwrap_or_else(|| find_counter_pda().0), false),
+        ];
+        let data = vec![Self::DISCRIMINANT];
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `InitCounterAuhthority`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` counter_authority
+///   2. `[]` system_program, defaults to the system program
+#[derive(Clone, Debug, Default)]
+pub struct InitCounterAuhthorityBuilder {
+    payer: Option<Address>,
+    counter_authority: Option<Address>,
+    system_program: Option<Address>,
+    init_counter_authority_args: Option<InitCounterAuthorityArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl InitCounterAuhthorityBuilder {
+    pub const DISCRIMINANT: u8 = 2;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn payer(&mut self, payer: Address) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+    pub fn system_program(&mut self, system_program: Address) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn init_counter_authority_args(
+        &mut self,
+        init_counter_authority_args: InitCounterAuthorityArgs,
+    ) -> &mut Self {
+        self.init_counter_authority_args = Some(init_counter_authority_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `payer`, `counter_authority` or `init_counter_authority_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new(self.payer.expect("payer is not set"), true),
+            AccountMeta::new(
+                self.counter_authority.expect("counter_authority is not set"),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                self.system_program.unwrap_or(SYSTEM_PROGRAM_ID),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.init_counter_authority_args
+            .as_ref()
+            .expect("init_counter_authority_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `IncreaseCounterAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` counter_authority
+#[derive(Clone, Debug, Default)]
+pub struct IncreaseCounterAuthorityBuilder {
+    authority: Option<Address>,
+    counter_authority: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl IncreaseCounterAuthorityBuilder {
+    pub const DISCRIMINANT: u8 = 3;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `authority` or `counter_authority` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(
+                self.authority.expect("authority is not set"),
+                true,
+            ),
+            AccountMeta::new(
+                self.counter_authority.expect("counter_authority is not set"),
+                false,
+            ),
+        ];
+        let data = vec![Self::DISCRIMINANT];
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `IncreaseCounterBy`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` counter, defaults to [`find_counter_pda`]
+#[derive(Clone, Debug, Default)]
+pub struct IncreaseCounterByBuilder {
+    counter: Option<Address>,
+    increase_counter_by_args: Option<IncreaseCounterByArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl IncreaseCounterByBuilder {
+    pub const DISCRIMINANT: u8 = 4;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+    pub fn increase_counter_by_args(
+        &mut self,
+        increase_counter_by_args: IncreaseCounterByArgs,
+    ) -> &mut Self {
+        self.increase_counter_by_args = Some(increase_counter_by_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `increase_counter_by_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new(self.counter.unwrap_or_else(|| find_counter_pda().0), false),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.increase_counter_by_args
+            .as_ref()
+            .expect("increase_counter_by_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `DecreaseCounter`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` admin
+///   1. `[writable]` counter, defaults to [`find_counter_pda`]
+///   2. `[]` program_data
+#[derive(Clone, Debug, Default)]
+pub struct DecreaseCounterBuilder {
+    admin: Option<Address>,
+    counter: Option<Address>,
+    program_data: Option<Address>,
+    decrease_counter_args: Option<DecreaseCounterArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl DecreaseCounterBuilder {
+    pub const DISCRIMINANT: u8 = 5;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn admin(&mut self, admin: Address) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+    pub fn program_data(&mut self, program_data: Address) -> &mut Self {
+        self.program_data = Some(program_data);
+        self
+    }
+    pub fn decrease_counter_args(
+        &mut self,
+        decrease_counter_args: DecreaseCounterArgs,
+    ) -> &mut Self {
+        self.decrease_counter_args = Some(decrease_counter_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `admin`, `program_data` or `decrease_counter_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(self.admin.expect("admin is not set"), true),
+            AccountMeta::new(
+                self.counter.unwrap_or_else(|| find_counter_pda().0),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                self.program_data.expect("program_data is not set"),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.decrease_counter_args
+            .as_ref()
+            .expect("decrease_counter_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `ResetCounter`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` admin
+///   1. `[writable]` counter, defaults to [`find_counter_pda`]
+///   2. `[]` program_data
+#[derive(Clone, Debug, Default)]
+pub struct ResetCounterBuilder {
+    admin: Option<Address>,
+    counter: Option<Address>,
+    program_data: Option<Address>,
+    reset_counter_args: Option<ResetCounterArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl ResetCounterBuilder {
+    pub const DISCRIMINANT: u8 = 6;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn admin(&mut self, admin: Address) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+    pub fn program_data(&mut self, program_data: Address) -> &mut Self {
+        self.program_data = Some(program_data);
+        self
+    }
+    pub fn reset_counter_args(
+        &mut self,
+        reset_counter_args: ResetCounterArgs,
+    ) -> &mut Self {
+        self.reset_counter_args = Some(reset_counter_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `admin`, `program_data` or `reset_counter_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(self.admin.expect("admin is not set"), true),
+            AccountMeta::new(
+                self.counter.unwrap_or_else(|| find_counter_pda().0),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                self.program_data.expect("program_data is not set"),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.reset_counter_args
+            .as_ref()
+            .expect("reset_counter_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `IncreaseCounterAuthorityBy`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` counter_authority
+#[derive(Clone, Debug, Default)]
+pub struct IncreaseCounterAuthorityByBuilder {
+    authority: Option<Address>,
+    counter_authority: Option<Address>,
+    increase_counter_authority_by_args: Option<IncreaseCounterAuthorityByArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl IncreaseCounterAuthorityByBuilder {
+    pub const DISCRIMINANT: u8 = 7;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+    pub fn increase_counter_authority_by_args(
+        &mut self,
+        increase_counter_authority_by_args: IncreaseCounterAuthorityByArgs,
+    ) -> &mut Self {
+        self.increase_counter_authority_by_args = Some(
+            increase_counter_authority_by_args,
+        );
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `authority`, `counter_authority` or `increase_counter_authority_by_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(
+                self.authority.expect("authority is not set"),
+                true,
+            ),
+            AccountMeta::new(
+                self.counter_authority.expect("counter_authority is not set"),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.increase_counter_authority_by_args
+            .as_ref()
+            .expect("increase_counter_authority_by_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `DecreaseCounterAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` counter_authority
+#[derive(Clone, Debug, Default)]
+pub struct DecreaseCounterAuthorityBuilder {
+    authority: Option<Address>,
+    counter_authority: Option<Address>,
+    decrease_counter_authority_args: Option<DecreaseCounterAuthorityArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl DecreaseCounterAuthorityBuilder {
+    pub const DISCRIMINANT: u8 = 8;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+    pub fn decrease_counter_authority_args(
+        &mut self,
+        decrease_counter_authority_args: DecreaseCounterAuthorityArgs,
+    ) -> &mut Self {
+        self.decrease_counter_authority_args = Some(decrease_counter_authority_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `authority`, `counter_authority` or `decrease_counter_authority_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(
+                self.authority.expect("authority is not set"),
+                true,
+            ),
+            AccountMeta::new(
+                self.counter_authority.expect("counter_authority is not set"),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.decrease_counter_authority_args
+            .as_ref()
+            .expect("decrease_counter_authority_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `ResetCounterAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` counter_authority
+#[derive(Clone, Debug, Default)]
+pub struct ResetCounterAuthorityBuilder {
+    authority: Option<Address>,
+    counter_authority: Option<Address>,
+    reset_counter_authority_args: Option<ResetCounterAuthorityArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl ResetCounterAuthorityBuilder {
+    pub const DISCRIMINANT: u8 = 9;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+    pub fn reset_counter_authority_args(
+        &mut self,
+        reset_counter_authority_args: ResetCounterAuthorityArgs,
+    ) -> &mut Self {
+        self.reset_counter_authority_args = Some(reset_counter_authority_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `authority`, `counter_authority` or `reset_counter_authority_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(
+                self.authority.expect("authority is not set"),
+                true,
+            ),
+            AccountMeta::new(
+                self.counter_authority.expect("counter_authority is not set"),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.reset_counter_authority_args
+            .as_ref()
+            .expect("reset_counter_authority_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `CloseCounter`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` admin
+///   1. `[writable]` counter, defaults to [`find_counter_pda`]
+///   2. `[writable]` destination
+///   3. `[]` program_data
+#[derive(Clone, Debug, Default)]
+pub struct CloseCounterBuilder {
+    admin: Option<Address>,
+    counter: Option<Address>,
+    destination: Option<Address>,
+    program_data: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl CloseCounterBuilder {
+    pub const DISCRIMINANT: u8 = 10;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn admin(&mut self, admin: Address) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn counter(&mut self, counter: Address) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+    pub fn destination(&mut self, destination: Address) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn program_data(&mut self, program_data: Address) -> &mut Self {
+        self.program_data = Some(program_data);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `admin`, `destination` or `program_data` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(self.admin.expect("admin is not set"), true),
+            AccountMeta::new(
+                self.counter.unwrap_or_else(|| find_counter_pda().0),
+                false,
+            ),
+            AccountMeta::new(self.destination.expect("destination is not set"), false),
+            AccountMeta::new_readonly(
+                self.program_data.expect("program_data is not set"),
+                false,
+            ),
+        ];
+        let data = vec![Self::DISCRIMINANT];
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `CloseCounterAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` counter_authority
+///   2. `[writable]` destination
+#[derive(Clone, Debug, Default)]
+pub struct CloseCounterAuthorityBuilder {
+    authority: Option<Address>,
+    counter_authority: Option<Address>,
+    destination: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl CloseCounterAuthorityBuilder {
+    pub const DISCRIMINANT: u8 = 11;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn authority(&mut self, authority: Address) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn counter_authority(&mut self, counter_authority: Address) -> &mut Self {
+        self.counter_authority = Some(counter_authority);
+        self
+    }
+    pub fn destination(&mut self, destination: Address) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `authority`, `counter_authority` or `destination` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(
+                self.authority.expect("authority is not set"),
+                true,
+            ),
+            AccountMeta::new(
+                self.counter_authority.expect("counter_authority is not set"),
+                false,
+            ),
+            AccountMeta::new(self.destination.expect("destination is not set"), false),
+        ];
+        let data = vec![Self::DISCRIMINANT];
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Every event the program emits. Encoded as
+/// `discriminator (8) ++ version (1) ++ borsh(event)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CounterEvent {
+    CounterInitialized(CounterInitialized),
+    CounterIncreased(CounterIncreased),
+    CounterAuthorityInitialized(CounterAuthorityInitialized),
+    CounterAuthorityIncreased(CounterAuthorityIncreased),
+    CounterDecreased(CounterDecreased),
+    CounterReset(CounterReset),
+    CounterAuthorityDecreased(CounterAuthorityDecreased),
+    CounterAuthorityReset(CounterAuthorityReset),
+    CounterClosed(CounterClosed),
+    CounterAuthorityClosed(CounterAuthorityClosed),
+}
+
+impl CounterEvent {
+    /// Decodes an event, as logged in a `Program data:` line or carried by a
+    /// self-CPI once its instruction tag is stripped.
+    pub fn decode(data: &[u8]) -> std::io::Result<Self> {
+        let Some((header, payload)) = data.split_at_checked(9) else {
+            return Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "event is truncated",
+                ),
+            );
+        };
+        match header {
+            [115, 205, 233, 189, 129, 219, 117, 64, 1] => {
+                Ok(
+                    Self::CounterInitialized(
+                        CounterInitialized::try_from_slice(payload)?,
+                    ),
+                )
+            }
+            [76, 77, 169, 195, 200, 138, 168, 118, 1] => {
+                Ok(Self::CounterIncreased(CounterIncreased::try_from_slice(payload)?))
+            }
+            [51, 154, 125, 172, 107, 66, 177, 191, 1] => {
+                Ok(
+                    Self::CounterAuthorityInitialized(
+                        CounterAuthorityInitialized::try_from_slice(payload)?,
+                    ),
+                )
+            }
+            [55, 44, 8, 230, 44, 145, 146, 72, 1] => {
+                Ok(
+                    Self::CounterAuthorityIncreased(
+                        CounterAuthorityIncreased::try_from_slice(payload)?,
+                    ),
+                )
+            }
+            [70, 250, 207, 2, 91, 65, 30, 253, 1] => {
+                Ok(Self::CounterDecreased(CounterDecreased::try_from_slice(payload)?))
+            }
+            [156, 40, 166, 79, 155, 217, 103, 87, 1] => {
+                Ok(Self::CounterReset(CounterReset::try_from_slice(payload)?))
+            }
+            [223, 236, 2, 6, 56, 232, 99, 53, 1] => {
+                Ok(
+                    Self::CounterAuthorityDecreased(
+                        CounterAuthorityDecreased::try_from_slice(payload)?,
+                    ),
+                )
+            }
+            [186, 152, 79, 200, 99, 177, 248, 87, 1] => {
+                Ok(
+                    Self::CounterAuthorityReset(
+                        CounterAuthorityReset::try_from_slice(payload)?,
+                    ),
+                )
+            }
+            [61, 84, 59, 97, 131, 189, 51, 193, 1] => {
+                Ok(Self::CounterClosed(CounterClosed::try_from_slice(payload)?))
+            }
+            [80, 241, 80, 230, 143, 127, 151, 198, 1] => {
+                Ok(
+                    Self::CounterAuthorityClosed(
+                        CounterAuthorityClosed::try_from_slice(payload)?,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "unknown event discriminator or version",
+                    ),
+                )
+            }
+        }
+    }
+}
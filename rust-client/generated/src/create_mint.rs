@@ -0,0 +1,170 @@
+//! Client for the `create_mint` program, generated by `idl-codegen` from
+//! `idl/create_mint.json`. Do not edit, run `pnpm gen:rust-client` instead.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_address::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+pub const PROGRAM_ID: Address = Address::from_str_const(
+    "7G2hHZRSc1S8yNbDyhn1HxiPz2jVmoEbz1BChG8yAmLZ",
+);
+
+const SYSTEM_PROGRAM_ID: Address = Address::from_str_const(
+    "11111111111111111111111111111111",
+);
+
+/// Custom program errors, surfaced as `ProgramError::Custom(code)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CreateMintError {
+    InvalidMintAuthority = 6000,
+    MintAlreadyInitialized = 6001,
+    ExtensionsRequireToken2022 = 6002,
+}
+
+impl CreateMintError {
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            6000 => Some(Self::InvalidMintAuthority),
+            6001 => Some(Self::MintAlreadyInitialized),
+            6002 => Some(Self::ExtensionsRequireToken2022),
+            _ => None,
+        }
+    }
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::InvalidMintAuthority => {
+                "Mint authority does not match the expected PDA"
+            }
+            Self::MintAlreadyInitialized => "Mint account is already in use",
+            Self::ExtensionsRequireToken2022 => {
+                "Mint extensions require the Token-2022 program"
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for CreateMintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for CreateMintError {}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct CreateMintArgs {
+    pub decimals: u8,
+    pub extensions: MintExtensions,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct MintExtensions {
+    pub metadata: Option<TokenMetadataArgs>,
+    pub transfer_fee: Option<TransferFeeArgs>,
+    pub close_authority: bool,
+    pub non_transferable: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct TokenMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct TransferFeeArgs {
+    pub transfer_fee_basis_points: u16,
+    pub maximum_fee: u64,
+}
+
+/// Builds `CreateMint`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable, signer]` mint
+///   2. `[]` mint_authority
+///   3. `[]` token_program
+///   4. `[]` system_program, defaults to the system program
+#[derive(Clone, Debug, Default)]
+pub struct CreateMintBuilder {
+    payer: Option<Address>,
+    mint: Option<Address>,
+    mint_authority: Option<Address>,
+    token_program: Option<Address>,
+    system_program: Option<Address>,
+    create_mint_args: Option<CreateMintArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl CreateMintBuilder {
+    pub const DISCRIMINANT: u8 = 0;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn payer(&mut self, payer: Address) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn mint(&mut self, mint: Address) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn mint_authority(&mut self, mint_authority: Address) -> &mut Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn token_program(&mut self, token_program: Address) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(&mut self, system_program: Address) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn create_mint_args(&mut self, create_mint_args: CreateMintArgs) -> &mut Self {
+        self.create_mint_args = Some(create_mint_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `payer`, `mint`, `mint_authority`, `token_program` or `create_mint_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new(self.payer.expect("payer is not set"), true),
+            AccountMeta::new(self.mint.expect("mint is not set"), true),
+            AccountMeta::new_readonly(
+                self.mint_authority.expect("mint_authority is not set"),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                self.token_program.expect("token_program is not set"),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                self.system_program.unwrap_or(SYSTEM_PROGRAM_ID),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.create_mint_args
+            .as_ref()
+            .expect("create_mint_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
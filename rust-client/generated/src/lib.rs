@@ -0,0 +1,161 @@
+//! Rust clients generated by `idl-codegen` from the IDLs in `idl/`, one module
+//! per program: errors, IDL types, account decoders, PDA helpers, instruction
+//! builders and event decoding.
+
+#[rustfmt::skip]
+pub mod close_account;
+#[rustfmt::skip]
+pub mod counter;
+#[rustfmt::skip]
+pub mod create_mint;
+#[rustfmt::skip]
+pub mod transfer_mint;
+
+#[cfg(test)]
+mod test {
+    use borsh::BorshSerialize;
+    use shared::{discriminator::Discriminated, event::Event};
+    use solana_address::Address;
+
+    use crate::{close_account, counter, create_mint, transfer_mint};
+
+    #[test]
+    fn program_ids_match_the_programs() {
+        assert_eq!(counter::PROGRAM_ID, Address::new_from_array(::counter::ID));
+        assert_eq!(
+            close_account::PROGRAM_ID,
+            Address::new_from_array(::close_account::ID)
+        );
+        assert_eq!(
+            create_mint::PROGRAM_ID,
+            Address::new_from_array(::create_mint::ID)
+        );
+        assert_eq!(
+            transfer_mint::PROGRAM_ID,
+            Address::new_from_array(::transfer_mint::ID)
+        );
+    }
+
+    #[test]
+    fn counter_accounts_match_the_program() {
+        use ::counter::accounts::{Counter, CounterAuthority};
+
+        assert_eq!(counter::Counter::DISCRIMINATOR, Counter::DISCRIMINATOR);
+        assert_eq!(counter::Counter::LEN, Counter::SPACE);
+        assert_eq!(counter::find_counter_pda(), Counter::derive());
+
+        let authority = Address::new_from_array([7; 32]);
+
+        assert_eq!(
+            counter::CounterAuthority::DISCRIMINATOR,
+            CounterAuthority::DISCRIMINATOR
+        );
+        assert_eq!(counter::CounterAuthority::LEN, CounterAuthority::SPACE);
+        assert_eq!(
+            counter::find_counter_authority_pda(&authority),
+            CounterAuthority::derive(&authority)
+        );
+
+        let mut data = Counter::DISCRIMINATOR.to_vec();
+        data.push(254);
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+
+        let decoded = counter::Counter::from_bytes(&data).unwrap();
+        assert_eq!(
+            (decoded.bump, decoded.count, decoded.max_count),
+            (254, 42, u64::MAX)
+        );
+
+        data[..8].copy_from_slice(&CounterAuthority::DISCRIMINATOR);
+        assert!(counter::Counter::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn counter_instructions_match_the_program() {
+        use ::counter::{CounterInstruction, InitCounterArgs};
+
+        let payer = Address::new_from_array([1; 32]);
+
+        let instruction = counter::InitCounterBuilder::new()
+            .payer(payer)
+            .init_counter_args(counter::InitCounterArgs {
+                count: 5,
+                max_count: Some(9),
+            })
+            .instruction();
+
+        assert_eq!(instruction.program_id, counter::PROGRAM_ID);
+        assert_eq!(
+            instruction.data,
+            borsh::to_vec(&CounterInstruction::InitCounter(InitCounterArgs {
+                count: 5,
+                max_count: Some(9),
+            }))
+            .unwrap()
+        );
+
+        let metas = instruction
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey, meta.is_writable, meta.is_signer))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            metas,
+            [
+                (payer, true, true),
+                (counter::find_counter_pda().0, true, false),
+                (
+                    Address::from_str_const("11111111111111111111111111111111"),
+                    false,
+                    false
+                ),
+            ]
+        );
+
+        let instruction = counter::IncreaseCounterAuthorityBuilder::new()
+            .authority(payer)
+            .counter_authority(counter::find_counter_authority_pda(&payer).0)
+            .instruction();
+
+        assert_eq!(
+            instruction.data,
+            borsh::to_vec(&CounterInstruction::IncreaseCounterAuthority).unwrap()
+        );
+    }
+
+    #[test]
+    fn counter_errors_and_events_match_the_program() {
+        use ::counter::{errors::CounterError, events::CounterIncreased};
+
+        assert_eq!(
+            counter::CounterError::from_code(CounterError::AuthorityMismatch as u32),
+            Some(counter::CounterError::AuthorityMismatch)
+        );
+
+        let event = CounterIncreased {
+            counter: Address::new_from_array([4; 32]),
+            new_count: 20,
+            slot: 1,
+            timestamp: 2,
+        };
+
+        let mut data = CounterIncreased::DISCRIMINATOR.to_vec();
+        data.push(CounterIncreased::VERSION);
+        event.serialize(&mut data).unwrap();
+
+        assert_eq!(
+            counter::CounterEvent::decode(&data).unwrap(),
+            counter::CounterEvent::CounterIncreased(counter::CounterIncreased {
+                counter: event.counter,
+                new_count: 20,
+                slot: 1,
+                timestamp: 2,
+            })
+        );
+
+        data[8] += 1;
+        assert!(counter::CounterEvent::decode(&data).is_err());
+    }
+}
@@ -0,0 +1,406 @@
+//! Client for the `transfer_mint` program, generated by `idl-codegen` from
+//! `idl/transfer_mint.json`. Do not edit, run `pnpm gen:rust-client` instead.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_address::Address;
+use solana_instruction::{AccountMeta, Instruction};
+
+pub const PROGRAM_ID: Address = Address::from_str_const(
+    "FfQmPA3CVeGL8Ai5ARrsVHwiRspiM5vvv4VJWAmDvAUM",
+);
+
+const SYSTEM_PROGRAM_ID: Address = Address::from_str_const(
+    "11111111111111111111111111111111",
+);
+
+/// Custom program errors, surfaced as `ProgramError::Custom(code)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum TransferMintError {
+    InvalidVaultAddress = 6000,
+    VaultAlreadyInitialized = 6001,
+    MintMismatch = 6002,
+    InvalidTokenAccountOwner = 6003,
+    DecimalsMismatch = 6004,
+    InsufficientFunds = 6005,
+}
+
+impl TransferMintError {
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            6000 => Some(Self::InvalidVaultAddress),
+            6001 => Some(Self::VaultAlreadyInitialized),
+            6002 => Some(Self::MintMismatch),
+            6003 => Some(Self::InvalidTokenAccountOwner),
+            6004 => Some(Self::DecimalsMismatch),
+            6005 => Some(Self::InsufficientFunds),
+            _ => None,
+        }
+    }
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::InvalidVaultAddress => "Vault address does not match the expected PDA",
+            Self::VaultAlreadyInitialized => "Vault is already initialized",
+            Self::MintMismatch => "Token account mint does not match the vault mint",
+            Self::InvalidTokenAccountOwner => "Token account is not owned by the signer",
+            Self::DecimalsMismatch => "Decimals do not match the mint",
+            Self::InsufficientFunds => "Source token account balance is too low",
+        }
+    }
+}
+
+impl std::fmt::Display for TransferMintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for TransferMintError {}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct DepositArgs {
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct WithdrawArgs {
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct WithdrawCheckedArgs {
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+/// Builds `InitVault`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` owner
+///   1. `[writable]` vault
+///   2. `[]` mint
+///   3. `[]` token_program
+///   4. `[]` system_program, defaults to the system program
+#[derive(Clone, Debug, Default)]
+pub struct InitVaultBuilder {
+    owner: Option<Address>,
+    vault: Option<Address>,
+    mint: Option<Address>,
+    token_program: Option<Address>,
+    system_program: Option<Address>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl InitVaultBuilder {
+    pub const DISCRIMINANT: u8 = 0;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn owner(&mut self, owner: Address) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn vault(&mut self, vault: Address) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    pub fn mint(&mut self, mint: Address) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn token_program(&mut self, token_program: Address) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(&mut self, system_program: Address) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `owner`, `vault`, `mint` or `token_program` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new(self.owner.expect("owner is not set"), true),
+            AccountMeta::new(self.vault.expect("vault is not set"), false),
+            AccountMeta::new_readonly(self.mint.expect("mint is not set"), false),
+            AccountMeta::new_readonly(
+                self.token_program.expect("token_program is not set"),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                self.system_program.unwrap_or(SYSTEM_PROGRAM_ID),
+                false,
+            ),
+        ];
+        let data = vec![Self::DISCRIMINANT];
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `Deposit`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` owner
+///   1. `[writable]` owner_token
+///   2. `[writable]` vault
+///   3. `[]` mint
+///   4. `[]` token_program
+#[derive(Clone, Debug, Default)]
+pub struct DepositBuilder {
+    owner: Option<Address>,
+    owner_token: Option<Address>,
+    vault: Option<Address>,
+    mint: Option<Address>,
+    token_program: Option<Address>,
+    deposit_args: Option<DepositArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl DepositBuilder {
+    pub const DISCRIMINANT: u8 = 1;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn owner(&mut self, owner: Address) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn owner_token(&mut self, owner_token: Address) -> &mut Self {
+        self.owner_token = Some(owner_token);
+        self
+    }
+    pub fn vault(&mut self, vault: Address) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    pub fn mint(&mut self, mint: Address) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn token_program(&mut self, token_program: Address) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn deposit_args(&mut self, deposit_args: DepositArgs) -> &mut Self {
+        self.deposit_args = Some(deposit_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `owner`, `owner_token`, `vault`, `mint`, `token_program` or `deposit_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(self.owner.expect("owner is not set"), true),
+            AccountMeta::new(self.owner_token.expect("owner_token is not set"), false),
+            AccountMeta::new(self.vault.expect("vault is not set"), false),
+            AccountMeta::new_readonly(self.mint.expect("mint is not set"), false),
+            AccountMeta::new_readonly(
+                self.token_program.expect("token_program is not set"),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.deposit_args
+            .as_ref()
+            .expect("deposit_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `Withdraw`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` owner
+///   1. `[writable]` owner_token
+///   2. `[writable]` vault
+///   3. `[]` mint
+///   4. `[]` token_program
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawBuilder {
+    owner: Option<Address>,
+    owner_token: Option<Address>,
+    vault: Option<Address>,
+    mint: Option<Address>,
+    token_program: Option<Address>,
+    withdraw_args: Option<WithdrawArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl WithdrawBuilder {
+    pub const DISCRIMINANT: u8 = 2;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn owner(&mut self, owner: Address) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn owner_token(&mut self, owner_token: Address) -> &mut Self {
+        self.owner_token = Some(owner_token);
+        self
+    }
+    pub fn vault(&mut self, vault: Address) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    pub fn mint(&mut self, mint: Address) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn token_program(&mut self, token_program: Address) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn withdraw_args(&mut self, withdraw_args: WithdrawArgs) -> &mut Self {
+        self.withdraw_args = Some(withdraw_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `owner`, `owner_token`, `vault`, `mint`, `token_program` or `withdraw_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(self.owner.expect("owner is not set"), true),
+            AccountMeta::new(self.owner_token.expect("owner_token is not set"), false),
+            AccountMeta::new(self.vault.expect("vault is not set"), false),
+            AccountMeta::new_readonly(self.mint.expect("mint is not set"), false),
+            AccountMeta::new_readonly(
+                self.token_program.expect("token_program is not set"),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.withdraw_args
+            .as_ref()
+            .expect("withdraw_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
+
+/// Builds `WithdrawChecked`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` owner
+///   1. `[writable]` owner_token
+///   2. `[writable]` vault
+///   3. `[]` mint
+///   4. `[]` token_program
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawCheckedBuilder {
+    owner: Option<Address>,
+    owner_token: Option<Address>,
+    vault: Option<Address>,
+    mint: Option<Address>,
+    token_program: Option<Address>,
+    withdraw_checked_args: Option<WithdrawCheckedArgs>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+impl WithdrawCheckedBuilder {
+    pub const DISCRIMINANT: u8 = 3;
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn owner(&mut self, owner: Address) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn owner_token(&mut self, owner_token: Address) -> &mut Self {
+        self.owner_token = Some(owner_token);
+        self
+    }
+    pub fn vault(&mut self, vault: Address) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    pub fn mint(&mut self, mint: Address) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn token_program(&mut self, token_program: Address) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn withdraw_checked_args(
+        &mut self,
+        withdraw_checked_args: WithdrawCheckedArgs,
+    ) -> &mut Self {
+        self.withdraw_checked_args = Some(withdraw_checked_args);
+        self
+    }
+    pub fn add_remaining_accounts(&mut self, accounts: &[AccountMeta]) -> &mut Self {
+        self.remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    /// # Panics
+    ///
+    /// When `owner`, `owner_token`, `vault`, `mint`, `token_program` or `withdraw_checked_args` is not set.
+    pub fn instruction(&self) -> Instruction {
+        let accounts = [
+            AccountMeta::new_readonly(self.owner.expect("owner is not set"), true),
+            AccountMeta::new(self.owner_token.expect("owner_token is not set"), false),
+            AccountMeta::new(self.vault.expect("vault is not set"), false),
+            AccountMeta::new_readonly(self.mint.expect("mint is not set"), false),
+            AccountMeta::new_readonly(
+                self.token_program.expect("token_program is not set"),
+                false,
+            ),
+        ];
+        let mut data = vec![Self::DISCRIMINANT];
+        self.withdraw_checked_args
+            .as_ref()
+            .expect("withdraw_checked_args is not set")
+            .serialize(&mut data)
+            .unwrap();
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts
+                .into_iter()
+                .chain(self.remaining_accounts.iter().cloned())
+                .collect(),
+            data,
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, Fields, Lit};
+
+/// Derives `shared::error::ErrorCode` for a fieldless error enum.
+///
+/// Each variant must carry the same `#[error("...")]` message used by
+/// `thiserror`, which shank also reads to emit the IDL `errors` section.
+#[proc_macro_derive(ErrorCode, attributes(error))]
+pub fn derive_error_code(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_error_code(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_error_code(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "ErrorCode can only be derived for enums",
+        ));
+    };
+
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut messages = Vec::with_capacity(data.variants.len());
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "ErrorCode variants cannot hold fields",
+            ));
+        }
+
+        let message = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("error"))
+            .ok_or_else(|| Error::new_spanned(variant, "missing #[error(\"...\")] message"))?
+            .parse_args::<Expr>()?;
+
+        let Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(message),
+            ..
+        }) = message
+        else {
+            return Err(Error::new_spanned(
+                message,
+                "#[error] expects a single string literal",
+            ));
+        };
+
+        variants.push(&variant.ident);
+        messages.push(message);
+    }
+
+    Ok(quote! {
+        impl ::shared::error::ErrorCode for #ident {
+            fn code(self) -> u32 {
+                self as u32
+            }
+
+            fn message(self) -> &'static str {
+                match self {
+                    #(Self::#variants => #messages,)*
+                }
+            }
+        }
+
+        impl ::core::convert::From<#ident> for ::shared::error::ProgramError {
+            fn from(error: #ident) -> Self {
+                ::shared::error::ProgramError::Custom(error as u32)
+            }
+        }
+
+        impl ::core::convert::TryFrom<u32> for #ident {
+            type Error = ::shared::error::ProgramError;
+
+            fn try_from(code: u32) -> ::core::result::Result<Self, Self::Error> {
+                #(
+                    if code == Self::#variants as u32 {
+                        return Ok(Self::#variants);
+                    }
+                )*
+
+                Err(::shared::error::ProgramError::InvalidArgument)
+            }
+        }
+
+        impl ::shared::error::ToStr for #ident {
+            fn to_str(&self) -> &'static str {
+                ::shared::error::ErrorCode::message(*self)
+            }
+        }
+    })
+}
+
+/// Derives `shared::event::Event` for an event struct.
+///
+/// The discriminator is `sha256("event:<Name>")[..8]`, so it only changes when
+/// the type is renamed. The schema version defaults to 1 and is bumped with
+/// `#[event(version = N)]` whenever the fields change.
+///
+/// The size hint is summed from the fields' `SizeHint`s; events with unbounded
+/// fields (vectors, strings) can state a bound with `#[event(max_size = N)]`.
+#[proc_macro_derive(Event, attributes(event))]
+pub fn derive_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_event(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_event(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Event can only be derived for structs",
+        ));
+    };
+
+    let mut version = None;
+    let mut max_size = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("event"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("version") {
+                version = Some(meta.value()?.parse::<syn::LitInt>()?.base10_parse::<u8>()?);
+                Ok(())
+            } else if meta.path.is_ident("max_size") {
+                max_size = Some(
+                    meta.value()?
+                        .parse::<syn::LitInt>()?
+                        .base10_parse::<usize>()?,
+                );
+                Ok(())
+            } else {
+                Err(meta.error("expected `version = <u8>` or `max_size = <usize>`"))
+            }
+        })?;
+    }
+
+    let version = version.unwrap_or(1);
+
+    let max_size = match max_size {
+        Some(max_size) => quote! { ::shared::event::EVENT_HEADER_LEN + #max_size },
+        None => {
+            let types = data.fields.iter().map(|field| &field.ty);
+
+            quote! {
+                ::shared::event::EVENT_HEADER_LEN
+                    #(.saturating_add(<#types as ::shared::event::SizeHint>::MAX_SIZE))*
+            }
+        }
+    };
+    let name = ident.to_string();
+    let preimage = syn::LitByteStr::new(format!("event:{name}").as_bytes(), ident.span());
+
+    Ok(quote! {
+        impl ::shared::event::Event for #ident {
+            const NAME: &'static str = #name;
+
+            const DISCRIMINATOR: [u8; ::shared::discriminator::DISCRIMINATOR_LEN] =
+                ::shared::discriminator::discriminator(#preimage);
+
+            const VERSION: u8 = #version;
+
+            const MAX_SIZE: usize = #max_size;
+        }
+    })
+}
+
+/// Derives `shared::event::EventSet` for an enum listing a program's events,
+/// one single-field tuple variant per `Event` type.
+///
+/// Decoding dispatches on the discriminator rather than on a Borsh enum tag, so
+/// variants can be reordered freely.
+#[proc_macro_derive(EventSet)]
+pub fn derive_event_set(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_event_set(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_event_set(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "EventSet can only be derived for enums",
+        ));
+    };
+
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut types = Vec::with_capacity(data.variants.len());
+
+    for variant in &data.variants {
+        let Fields::Unnamed(fields) = &variant.fields else {
+            return Err(Error::new_spanned(
+                variant,
+                "EventSet variants must wrap a single event type",
+            ));
+        };
+
+        let [field] = &fields.unnamed.iter().collect::<Vec<_>>()[..] else {
+            return Err(Error::new_spanned(
+                variant,
+                "EventSet variants must wrap a single event type",
+            ));
+        };
+
+        variants.push(&variant.ident);
+        types.push(&field.ty);
+    }
+
+    Ok(quote! {
+        impl ::shared::event::EventSet for #ident {
+            const EVENTS: &'static [::shared::event::EventInfo] =
+                &[#(<#types as ::shared::event::Event>::INFO,)*];
+
+            fn decode(data: &[u8]) -> ::core::result::Result<Self, ::shared::event::EventDecodeError> {
+                let discriminator = ::shared::event::peek_discriminator(data)?;
+
+                #(
+                    if discriminator == <#types as ::shared::event::Event>::DISCRIMINATOR {
+                        return ::shared::event::decode::<#types>(data).map(Self::#variants);
+                    }
+                )*
+
+                Err(::shared::event::EventDecodeError::UnknownDiscriminator(discriminator))
+            }
+        }
+    })
+}
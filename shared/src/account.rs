@@ -0,0 +1,170 @@
+use core::mem::{align_of, size_of};
+
+use pinocchio::{error::ProgramError, AccountView};
+
+use crate::{
+    discriminator::{Discriminated, DISCRIMINATOR_LEN},
+    system,
+};
+
+/// A program account that is read and written in place, straight from the
+/// account data, instead of being deserialized and re-serialized.
+///
+/// Multi-byte integers are stored as little-endian byte arrays behind
+/// accessors, so the byte layout is byte-for-byte the Borsh encoding of the
+/// same fields, which is what the generated clients decode.
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]`, start with the `[u8; 8]` discriminator
+/// and only contain byte arrays (or `#[repr(transparent)]` wrappers around
+/// them, like `Address`). That gives the type an alignment of 1, no padding,
+/// and makes every bit pattern a valid value.
+pub unsafe trait ZeroCopy: Discriminated + Sized {
+    const LEN: usize = size_of::<Self>();
+
+    /// Reinterprets `data` as `Self` after checking its length and alignment.
+    fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        check_layout::<Self>(data)?;
+
+        // SAFETY: length and alignment were checked above and the trait
+        // contract guarantees every bit pattern is a valid `Self`.
+        Ok(unsafe { &*(data.as_ptr() as *const Self) })
+    }
+
+    /// Mutable counterpart of [`ZeroCopy::load`].
+    fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        check_layout::<Self>(data)?;
+
+        // SAFETY: same as `load`, and the exclusive borrow of `data` is
+        // carried over to the returned reference.
+        Ok(unsafe { &mut *(data.as_mut_ptr() as *mut Self) })
+    }
+
+    /// Writes the discriminator into freshly allocated account data and
+    /// returns the account for the caller to fill in the remaining fields.
+    fn initialize(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        check_layout::<Self>(data)?;
+
+        data[..DISCRIMINATOR_LEN].copy_from_slice(&Self::DISCRIMINATOR);
+
+        Self::load_mut(data)
+    }
+}
+
+fn check_layout<T>(data: &[u8]) -> Result<(), ProgramError> {
+    if data.len() != size_of::<T>() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if data.as_ptr().align_offset(align_of::<T>()) != 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Closes the program account `account`: moves its lamports to
+/// `destination`, then zeroes, shrinks and hands it back to the system
+/// program, which leaves it as if never created so it can be initialized
+/// again. Fails with `invalid_destination` when `destination` is `account`
+/// itself. Returns the lamports refunded.
+pub fn close(
+    account: &AccountView,
+    destination: &AccountView,
+    invalid_destination: impl Into<ProgramError>,
+) -> Result<u64, ProgramError> {
+    if destination.address().eq(account.address()) {
+        return Err(invalid_destination.into());
+    }
+
+    let refund = account.lamports();
+
+    destination.set_lamports(
+        destination
+            .lamports()
+            .checked_add(refund)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    );
+    account.set_lamports(0);
+
+    account.try_borrow_mut()?.fill(0);
+    account.resize(0)?;
+
+    // SAFETY: `account` is owned by the running program, which may reassign
+    // it, and its data is no longer borrowed.
+    unsafe {
+        account.assign(&system::ID);
+    }
+
+    Ok(refund)
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::error::ProgramError;
+
+    use super::ZeroCopy;
+    use crate::discriminator::{discriminator, Discriminated, DISCRIMINATOR_LEN};
+
+    #[repr(C)]
+    struct Sample {
+        discriminator: [u8; 8],
+        flag: u8,
+        value: [u8; 8],
+    }
+
+    impl Discriminated for Sample {
+        const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] = discriminator(b"account:Sample");
+    }
+
+    unsafe impl ZeroCopy for Sample {}
+
+    #[test]
+    fn load_reads_fields_in_place() {
+        let mut data = [0u8; Sample::LEN];
+        data[..8].copy_from_slice(&Sample::DISCRIMINATOR);
+        data[8] = 7;
+        data[9..].copy_from_slice(&42u64.to_le_bytes());
+
+        let sample = Sample::load(&data).unwrap();
+
+        assert_eq!(sample.discriminator, Sample::DISCRIMINATOR);
+        assert_eq!(sample.flag, 7);
+        assert_eq!(u64::from_le_bytes(sample.value), 42);
+    }
+
+    #[test]
+    fn load_mut_writes_through() {
+        let mut data = [0u8; Sample::LEN];
+
+        Sample::load_mut(&mut data).unwrap().value = 5u64.to_le_bytes();
+
+        assert_eq!(&data[9..], &5u64.to_le_bytes());
+    }
+
+    #[test]
+    fn initialize_writes_discriminator() {
+        let mut data = [0xffu8; Sample::LEN];
+
+        let sample = Sample::initialize(&mut data).unwrap();
+        sample.flag = 1;
+
+        assert!(Sample::is_discriminated(&data));
+        assert_eq!(data[8], 1);
+    }
+
+    #[test]
+    fn load_rejects_wrong_length() {
+        let data = [0u8; Sample::LEN + 1];
+
+        assert_eq!(
+            Sample::load(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            Sample::load(&data[..Sample::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
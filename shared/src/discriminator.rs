@@ -0,0 +1,179 @@
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// A program-owned type whose serialized form starts with a fixed tag.
+///
+/// The tag is written when the account is initialized and checked on every
+/// load, so an account of one type can never be passed off as another.
+pub trait Discriminated {
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN];
+
+    fn is_discriminated(data: &[u8]) -> bool {
+        data.starts_with(&Self::DISCRIMINATOR)
+    }
+}
+
+/// Returns the first 8 bytes of `sha256(preimage)`, e.g. `b"account:Counter"`.
+///
+/// Matches the Anchor convention so off-chain tooling can derive the same tag.
+pub const fn discriminator(preimage: &[u8]) -> [u8; DISCRIMINATOR_LEN] {
+    let hash = sha256(preimage);
+    let mut out = [0u8; DISCRIMINATOR_LEN];
+    let mut i = 0;
+    while i < DISCRIMINATOR_LEN {
+        out[i] = hash[i];
+        i += 1;
+    }
+    out
+}
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const H: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Compile-time SHA-256, only meant for short discriminator preimages.
+const fn sha256(input: &[u8]) -> [u8; 32] {
+    let mut state = H;
+
+    // Message + 0x80 + 64-bit length, rounded up to whole 64-byte blocks.
+    let total_len = (input.len() + 9).div_ceil(64) * 64;
+    let bit_len = (input.len() as u64) * 8;
+
+    let mut block_start = 0;
+    while block_start < total_len {
+        let mut w = [0u32; 64];
+
+        let mut t = 0;
+        while t < 16 {
+            let mut word = 0u32;
+            let mut b = 0;
+            while b < 4 {
+                let idx = block_start + t * 4 + b;
+                let byte = if idx < input.len() {
+                    input[idx]
+                } else if idx == input.len() {
+                    0x80
+                } else if idx >= total_len - 8 {
+                    (bit_len >> ((total_len - 1 - idx) * 8)) as u8
+                } else {
+                    0
+                };
+                word = (word << 8) | byte as u32;
+                b += 1;
+            }
+            w[t] = word;
+            t += 1;
+        }
+
+        while t < 64 {
+            let s0 = w[t - 15].rotate_right(7) ^ w[t - 15].rotate_right(18) ^ (w[t - 15] >> 3);
+            let s1 = w[t - 2].rotate_right(17) ^ w[t - 2].rotate_right(19) ^ (w[t - 2] >> 10);
+            w[t] = w[t - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[t - 7])
+                .wrapping_add(s1);
+            t += 1;
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+
+        t = 0;
+        while t < 64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[t])
+                .wrapping_add(w[t]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+            t += 1;
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+        state[5] = state[5].wrapping_add(f);
+        state[6] = state[6].wrapping_add(g);
+        state[7] = state[7].wrapping_add(h);
+
+        block_start += 64;
+    }
+
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 8 {
+        let bytes = state[i].to_be_bytes();
+        out[i * 4] = bytes[0];
+        out[i * 4 + 1] = bytes[1];
+        out[i * 4 + 2] = bytes[2];
+        out[i * 4 + 3] = bytes[3];
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{discriminator, sha256};
+
+    #[test]
+    fn sha256_matches_known_vectors() {
+        assert_eq!(
+            sha256(b""),
+            [
+                0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f,
+                0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b,
+                0x78, 0x52, 0xb8, 0x55,
+            ]
+        );
+        assert_eq!(
+            sha256(b"abc"),
+            [
+                0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+                0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+                0xf2, 0x00, 0x15, 0xad,
+            ]
+        );
+        // Two blocks: the padding does not fit after a 56-byte message.
+        assert_eq!(
+            sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            [
+                0x24, 0x8d, 0x6a, 0x61, 0xd2, 0x06, 0x38, 0xb8, 0xe5, 0xc0, 0x26, 0x93, 0x0c, 0x3e,
+                0x60, 0x39, 0xa3, 0x3c, 0xe4, 0x59, 0x64, 0xff, 0x21, 0x67, 0xf6, 0xec, 0xed, 0xd4,
+                0x19, 0xdb, 0x06, 0xc1,
+            ]
+        );
+    }
+
+    #[test]
+    fn discriminator_is_hash_prefix() {
+        assert_eq!(
+            discriminator(b"abc"),
+            [0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea]
+        );
+    }
+}
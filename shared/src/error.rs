@@ -0,0 +1,14 @@
+pub use pinocchio::error::{ProgramError, ToStr};
+pub use shared_derive::ErrorCode;
+
+/// A program-specific error surfaced to clients as `ProgramError::Custom`.
+///
+/// Implement it with `#[derive(ErrorCode)]` on a fieldless `#[repr(u32)]` enum,
+/// which also provides `Into<ProgramError>`, `TryFrom<u32>` and `ToStr`. Codes
+/// start at 6000 to stay clear of the custom codes returned by native programs
+/// (e.g. the system program) during CPIs.
+pub trait ErrorCode: Copy + Into<ProgramError> + TryFrom<u32> + 'static {
+    fn code(self) -> u32;
+
+    fn message(self) -> &'static str;
+}
@@ -0,0 +1,453 @@
+//! Chunked emission for events larger than [`MAX_EVENT_SIZE`].
+//!
+//! The encoded event is streamed through a fixed buffer and handed out in
+//! chunks of at most [`CHUNK_PAYLOAD_LEN`] bytes, each prefixed with
+//!
+//! | offset | size | field                                  |
+//! |--------|------|----------------------------------------|
+//! | 0      | 2    | index (u16, little end)                |
+//! | 2      | 2    | count (u16, little end)                |
+//! | 4      | 4    | checksum of the whole event (FNV-1a)   |
+//!
+//! so the program never needs the whole event in memory, and a consumer can
+//! reassemble it with [`ChunkAssembler`] and verify it was not altered or
+//! interleaved with another event's chunks.
+//!
+//! [`MAX_EVENT_SIZE`]: super::MAX_EVENT_SIZE
+
+use alloc::vec::Vec;
+
+use borsh::io;
+use pinocchio::{error::ProgramError, ProgramResult};
+
+use super::{Event, EventDecodeError, EVENT_HEADER_LEN};
+use crate::discriminator::{discriminator, DISCRIMINATOR_LEN};
+
+/// Marks a chunk, as the first slice of its `Program data:` line or as the
+/// instruction tag of its self-CPI.
+pub const CHUNK_TAG: [u8; DISCRIMINATOR_LEN] = discriminator(b"event:chunk");
+
+pub const CHUNK_HEADER_LEN: usize = 2 + 2 + 4;
+
+/// Event bytes carried by every chunk but the last.
+pub const CHUNK_PAYLOAD_LEN: usize = 192;
+
+pub const MAX_CHUNK_LEN: usize = CHUNK_HEADER_LEN + CHUNK_PAYLOAD_LEN;
+
+const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+/// 32-bit FNV-1a, cheap enough to run over kilobytes of event data on-chain.
+#[derive(Clone, Copy)]
+struct Checksum(u32);
+
+impl Checksum {
+    fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.0 = (self.0 ^ *byte as u32).wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+/// Checksum of a whole encoded event, as carried by its chunks.
+pub fn checksum(data: &[u8]) -> u32 {
+    let mut checksum = Checksum::new();
+    checksum.update(data);
+    checksum.0
+}
+
+/// Discards the serialized bytes, keeping only their length and checksum.
+struct MeasureWriter {
+    len: usize,
+    checksum: Checksum,
+}
+
+impl io::Write for MeasureWriter {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.len += data.len();
+        self.checksum.update(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Length and checksum of `event` once encoded, header included.
+pub(super) fn measure<E: Event>(event: &E) -> Result<(usize, u32), ProgramError> {
+    let mut writer = MeasureWriter {
+        len: 0,
+        checksum: Checksum::new(),
+    };
+
+    io::Write::write_all(&mut writer, &header::<E>()).map_err(|_| ProgramError::BorshIoError)?;
+
+    event
+        .serialize(&mut writer)
+        .map_err(|_| ProgramError::BorshIoError)?;
+
+    Ok((writer.len, writer.checksum.0))
+}
+
+fn header<E: Event>() -> [u8; EVENT_HEADER_LEN] {
+    let mut header = [0u8; EVENT_HEADER_LEN];
+    header[..DISCRIMINATOR_LEN].copy_from_slice(&E::DISCRIMINATOR);
+    header[DISCRIMINATOR_LEN] = E::VERSION;
+    header
+}
+
+/// Buffers serialized bytes and hands every full chunk to `sink`.
+struct ChunkWriter<F> {
+    chunk: [u8; MAX_CHUNK_LEN],
+    len: usize,
+    index: u16,
+    count: u16,
+    sink: F,
+}
+
+impl<F: FnMut(&[u8]) -> ProgramResult> ChunkWriter<F> {
+    fn flush_chunk(&mut self) -> ProgramResult {
+        self.chunk[..2].copy_from_slice(&self.index.to_le_bytes());
+        (self.sink)(&self.chunk[..CHUNK_HEADER_LEN + self.len])?;
+
+        self.index += 1;
+        self.len = 0;
+
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&[u8]) -> ProgramResult> io::Write for ChunkWriter<F> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let written = data.len();
+        let mut data = data;
+
+        while !data.is_empty() {
+            if self.len == CHUNK_PAYLOAD_LEN {
+                self.flush_chunk()
+                    .map_err(|_| io::Error::from(io::ErrorKind::Other))?;
+            }
+
+            let take = data.len().min(CHUNK_PAYLOAD_LEN - self.len);
+            let start = CHUNK_HEADER_LEN + self.len;
+
+            self.chunk[start..start + take].copy_from_slice(&data[..take]);
+            self.len += take;
+            data = &data[take..];
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Streams `event`, already measured to `len` bytes with `checksum`, to
+/// `sink` one chunk at a time.
+pub(super) fn write_chunks<E: Event>(
+    event: &E,
+    len: usize,
+    checksum: u32,
+    sink: impl FnMut(&[u8]) -> ProgramResult,
+) -> ProgramResult {
+    let count =
+        u16::try_from(len.div_ceil(CHUNK_PAYLOAD_LEN)).map_err(|_| ProgramError::BorshIoError)?;
+
+    let mut writer = ChunkWriter {
+        chunk: [0u8; MAX_CHUNK_LEN],
+        len: 0,
+        index: 0,
+        count,
+        sink,
+    };
+
+    writer.chunk[2..4].copy_from_slice(&writer.count.to_le_bytes());
+    writer.chunk[4..8].copy_from_slice(&checksum.to_le_bytes());
+
+    io::Write::write_all(&mut writer, &header::<E>()).map_err(|_| ProgramError::BorshIoError)?;
+
+    event
+        .serialize(&mut writer)
+        .map_err(|_| ProgramError::BorshIoError)?;
+
+    writer.flush_chunk()
+}
+
+/// Reassembles the chunks of one event at a time, in emission order.
+#[derive(Default)]
+pub struct ChunkAssembler {
+    pending: Option<Pending>,
+}
+
+struct Pending {
+    count: u16,
+    checksum: u32,
+    data: Vec<u8>,
+    next: u16,
+}
+
+impl ChunkAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the next chunk, returning the encoded event once its last chunk
+    /// arrives and the checksum matches.
+    ///
+    /// Any error drops the partially assembled event. The first chunk of an
+    /// event still starts it when one is pending, which is dropped and
+    /// reported as [`EventDecodeError::EventCutShort`]; a new event complete
+    /// in that one chunk, which emitters never produce, is dropped with it.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<Option<Vec<u8>>, EventDecodeError> {
+        let cut_short = chunk.starts_with(&[0, 0]) && self.pending.take().is_some();
+
+        let result = self.push_inner(chunk);
+
+        if result.is_err() {
+            self.pending = None;
+        }
+
+        match result {
+            Ok(_) if cut_short => Err(EventDecodeError::EventCutShort),
+            result => result,
+        }
+    }
+
+    /// Whether chunks of an unfinished event are buffered.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    fn push_inner(&mut self, chunk: &[u8]) -> Result<Option<Vec<u8>>, EventDecodeError> {
+        let header = chunk
+            .get(..CHUNK_HEADER_LEN)
+            .ok_or(EventDecodeError::Truncated)?;
+
+        let index = u16::from_le_bytes([header[0], header[1]]);
+        let count = u16::from_le_bytes([header[2], header[3]]);
+        let checksum = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        let payload = &chunk[CHUNK_HEADER_LEN..];
+
+        if index == 0 {
+            // `push` took any event still pending.
+            self.pending = Some(Pending {
+                count,
+                checksum,
+                data: Vec::with_capacity(count as usize * CHUNK_PAYLOAD_LEN),
+                next: 0,
+            });
+        }
+
+        let pending = self
+            .pending
+            .as_mut()
+            .ok_or(EventDecodeError::ChunkOutOfOrder)?;
+
+        if index != pending.next || count != pending.count || checksum != pending.checksum {
+            return Err(EventDecodeError::ChunkOutOfOrder);
+        }
+
+        pending.data.extend_from_slice(payload);
+        pending.next += 1;
+
+        if pending.next < pending.count {
+            return Ok(None);
+        }
+
+        let Pending { checksum, data, .. } = self.pending.take().unwrap();
+
+        if self::checksum(&data) != checksum {
+            return Err(EventDecodeError::ChecksumMismatch);
+        }
+
+        Ok(Some(data))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::{ChunkAssembler, CHUNK_HEADER_LEN, CHUNK_PAYLOAD_LEN};
+    use crate::event::{
+        decode, emit_with, Event, EventDecodeError, EVENT_HEADER_LEN, MAX_EVENT_SIZE,
+    };
+
+    #[derive(BorshSerialize, BorshDeserialize, Event, Debug, PartialEq)]
+    struct Blob {
+        id: u32,
+        bytes: Vec<u8>,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Event, Debug, PartialEq)]
+    #[event(max_size = 68)]
+    struct Bounded {
+        bytes: Vec<u8>,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Event)]
+    struct Fixed {
+        a: u64,
+        b: [u8; 32],
+        c: Option<u16>,
+    }
+
+    /// Runs `emit_with`, returning the whole event or the chunks it produced.
+    fn emit_pieces<E: Event>(event: &E) -> (Option<Vec<u8>>, Vec<Vec<u8>>) {
+        let mut whole = None;
+        let mut chunks = Vec::new();
+
+        emit_with(
+            event,
+            |data| {
+                whole = Some(data.to_vec());
+                Ok(())
+            },
+            |chunk| {
+                chunks.push(chunk.to_vec());
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        (whole, chunks)
+    }
+
+    fn blob(len: usize) -> Blob {
+        Blob {
+            id: 7,
+            bytes: (0..len).map(|i| i as u8).collect(),
+        }
+    }
+
+    #[test]
+    fn size_hints() {
+        assert_eq!(Fixed::MAX_SIZE, EVENT_HEADER_LEN + 8 + 32 + 3);
+        assert_eq!(Bounded::MAX_SIZE, EVENT_HEADER_LEN + 68);
+        assert_eq!(Blob::MAX_SIZE, usize::MAX);
+    }
+
+    #[test]
+    fn small_unbounded_event_is_emitted_whole() {
+        let (whole, chunks) = emit_pieces(&blob(16));
+
+        assert!(chunks.is_empty());
+        assert_eq!(decode::<Blob>(&whole.unwrap()), Ok(blob(16)));
+    }
+
+    #[test]
+    fn large_event_round_trips_through_chunks() {
+        for len in [MAX_EVENT_SIZE, 4 * 1024, 6 * 1024 + 5] {
+            let event = blob(len);
+            let (whole, chunks) = emit_pieces(&event);
+
+            let encoded_len = EVENT_HEADER_LEN + 4 + 4 + len;
+
+            assert!(whole.is_none());
+            assert_eq!(chunks.len(), encoded_len.div_ceil(CHUNK_PAYLOAD_LEN));
+            assert!(chunks
+                .iter()
+                .all(|chunk| chunk.len() <= CHUNK_HEADER_LEN + CHUNK_PAYLOAD_LEN));
+
+            let mut assembler = ChunkAssembler::new();
+            let (last, rest) = chunks.split_last().unwrap();
+
+            for chunk in rest {
+                assert_eq!(assembler.push(chunk), Ok(None));
+            }
+
+            let data = assembler.push(last).unwrap().unwrap();
+
+            assert!(!assembler.is_pending());
+            assert_eq!(data.len(), encoded_len);
+            assert_eq!(decode::<Blob>(&data), Ok(event));
+        }
+    }
+
+    #[test]
+    fn assembler_rejects_missing_chunk() {
+        let (_, chunks) = emit_pieces(&blob(1024));
+
+        let mut assembler = ChunkAssembler::new();
+
+        assert_eq!(assembler.push(&chunks[0]), Ok(None));
+        assert_eq!(
+            assembler.push(&chunks[2]),
+            Err(EventDecodeError::ChunkOutOfOrder)
+        );
+        assert!(!assembler.is_pending());
+    }
+
+    #[test]
+    fn assembler_rejects_interleaved_events() {
+        let (_, first) = emit_pieces(&blob(1024));
+        let (_, second) = emit_pieces(&blob(2048));
+
+        let mut assembler = ChunkAssembler::new();
+
+        assert_eq!(assembler.push(&first[0]), Ok(None));
+        assert_eq!(
+            assembler.push(&second[1]),
+            Err(EventDecodeError::ChunkOutOfOrder)
+        );
+    }
+
+    #[test]
+    fn assembler_restarts_on_first_chunk() {
+        let (_, first) = emit_pieces(&blob(1024));
+        let (_, second) = emit_pieces(&blob(2048));
+
+        let mut assembler = ChunkAssembler::new();
+
+        assert_eq!(assembler.push(&first[0]), Ok(None));
+        assert_eq!(
+            assembler.push(&second[0]),
+            Err(EventDecodeError::EventCutShort)
+        );
+        assert!(assembler.is_pending());
+
+        let (last, rest) = second[1..].split_last().unwrap();
+
+        for chunk in rest {
+            assert_eq!(assembler.push(chunk), Ok(None));
+        }
+
+        let data = assembler.push(last).unwrap().unwrap();
+
+        assert_eq!(decode::<Blob>(&data), Ok(blob(2048)));
+    }
+
+    #[test]
+    fn assembler_rejects_corrupted_chunk() {
+        let (_, mut chunks) = emit_pieces(&blob(1024));
+        chunks[1][CHUNK_HEADER_LEN] ^= 0xff;
+
+        let mut assembler = ChunkAssembler::new();
+        let mut result = Ok(None);
+
+        for chunk in &chunks {
+            result = assembler.push(chunk);
+        }
+
+        assert_eq!(result, Err(EventDecodeError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn assembler_rejects_truncated_chunk() {
+        let mut assembler = ChunkAssembler::new();
+
+        assert_eq!(
+            assembler.push(&[0; CHUNK_HEADER_LEN - 1]),
+            Err(EventDecodeError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Self-CPI event transport.
+//!
+//! Instead of logging, the program invokes itself with the serialized event as
+//! instruction data, signed by its event authority PDA. The event then shows up
+//! in the transaction's inner instructions, which RPC nodes do not truncate and
+//! which no other program can forge: only the emitting program can sign for its
+//! event authority.
+//!
+//! The receiving side of the CPI must recognise [`EVENT_IX_TAG`] before routing
+//! its own instructions and accept it only when [`check_event_authority`]
+//! passes.
+
+use alloc::vec::Vec;
+
+use pinocchio::{
+    error::ProgramError,
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, Address, ProgramResult,
+};
+
+use super::{chunk::ChunkAssembler, emit_with, Event, EventDecodeError, MAX_PIECE_LEN};
+use crate::discriminator::{discriminator, DISCRIMINATOR_LEN};
+use crate::syscalls::invoke_signed;
+
+/// Reserved instruction discriminator that prefixes every self-CPI event.
+///
+/// Same bytes as Anchor's `emit_cpi!`, so existing explorers and indexers pick
+/// the events up without changes. Anchor reads `sha256("anchor:event")[..8]` as
+/// a big-endian `u64` and writes it little-endian, hence the reversal.
+pub const EVENT_IX_TAG: [u8; DISCRIMINATOR_LEN] = {
+    let hash = discriminator(b"anchor:event");
+    let mut tag = [0u8; DISCRIMINATOR_LEN];
+    let mut i = 0;
+    while i < DISCRIMINATOR_LEN {
+        tag[i] = hash[DISCRIMINATOR_LEN - 1 - i];
+        i += 1;
+    }
+    tag
+};
+
+/// Instruction discriminator of the self-CPIs carrying the chunks of an event
+/// larger than `MAX_EVENT_SIZE` (see `event::chunk`).
+pub const EVENT_CHUNK_IX_TAG: [u8; DISCRIMINATOR_LEN] = super::chunk::CHUNK_TAG;
+
+/// Seed of the PDA that signs event CPIs, `[EVENT_AUTHORITY_SEED]` under the
+/// emitting program.
+pub const EVENT_AUTHORITY_SEED: &[u8; 17] = b"__event_authority";
+
+/// Whether `instruction_data` is a self-CPI event (or event chunk) rather than
+/// a regular instruction.
+pub fn is_event_instruction(instruction_data: &[u8]) -> bool {
+    instruction_data.starts_with(&EVENT_IX_TAG) || instruction_data.starts_with(&EVENT_CHUNK_IX_TAG)
+}
+
+/// Emits `event` by invoking `program_id` with `EVENT_IX_TAG` followed by the
+/// encoded event, signed by `event_authority`. Past `event::MAX_EVENT_SIZE` the event
+/// is sent as one `EVENT_CHUNK_IX_TAG` invocation per chunk instead.
+///
+/// `event_authority` must be the `[EVENT_AUTHORITY_SEED]` PDA of `program_id`
+/// with the given `bump`, and the program account itself must be part of the
+/// instruction's accounts for the runtime to allow the CPI.
+pub fn emit_cpi<E: Event>(
+    program_id: &Address,
+    event_authority: &AccountView,
+    bump: u8,
+    event: &E,
+) -> ProgramResult {
+    emit_with(
+        event,
+        |data| invoke_event(program_id, event_authority, bump, &EVENT_IX_TAG, data),
+        |chunk| {
+            invoke_event(
+                program_id,
+                event_authority,
+                bump,
+                &EVENT_CHUNK_IX_TAG,
+                chunk,
+            )
+        },
+    )
+}
+
+/// Self-invokes with `tag ++ payload`.
+fn invoke_event(
+    program_id: &Address,
+    event_authority: &AccountView,
+    bump: u8,
+    tag: &[u8; DISCRIMINATOR_LEN],
+    payload: &[u8],
+) -> ProgramResult {
+    let mut data = [0u8; DISCRIMINATOR_LEN + MAX_PIECE_LEN];
+    let data_len = DISCRIMINATOR_LEN + payload.len();
+
+    data[..DISCRIMINATOR_LEN].copy_from_slice(tag);
+    data[DISCRIMINATOR_LEN..data_len].copy_from_slice(payload);
+
+    let instruction_accounts = [InstructionAccount::readonly_signer(
+        event_authority.address(),
+    )];
+
+    let instruction = InstructionView {
+        program_id,
+        accounts: &instruction_accounts,
+        data: &data[..data_len],
+    };
+
+    let bump_bytes = [bump];
+    let seeds: [&[u8]; 2] = [EVENT_AUTHORITY_SEED, &bump_bytes];
+
+    invoke_signed(&instruction, &[event_authority], &[&seeds])
+}
+
+/// Accepts an incoming event instruction only when it was signed by the
+/// program's own event authority, i.e. when it is a genuine self-CPI.
+pub fn check_event_authority(
+    accounts: &[AccountView],
+    event_authority: &Address,
+) -> Result<(), ProgramError> {
+    let [signer, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !signer.is_signer() || signer.address().ne(event_authority) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    Ok(())
+}
+
+/// The encoded event carried by the data of an inner instruction emitted with
+/// [`emit_cpi`], ready for `event::decode` or `EventSet::decode`.
+///
+/// Returns `None` when the data is not an event instruction.
+pub fn event_data(instruction_data: &[u8]) -> Option<&[u8]> {
+    instruction_data.strip_prefix(&EVENT_IX_TAG)
+}
+
+/// The chunk carried by an `EVENT_CHUNK_IX_TAG` inner instruction, ready for
+/// `chunk::ChunkAssembler::push`.
+pub fn chunk_data(instruction_data: &[u8]) -> Option<&[u8]> {
+    instruction_data.strip_prefix(&EVENT_CHUNK_IX_TAG)
+}
+
+/// Reassembles the events of one program's event instructions, fed in order:
+/// whole events as they come, chunked ones once their last chunk does.
+#[derive(Default)]
+pub struct EventInstructions {
+    chunks: ChunkAssembler,
+}
+
+impl EventInstructions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The encoded event `instruction_data` completes, or `None` for a chunk
+    /// that does not finish its event and for any other instruction.
+    pub fn push(&mut self, instruction_data: &[u8]) -> Option<Result<Vec<u8>, EventDecodeError>> {
+        if let Some(data) = event_data(instruction_data) {
+            return Some(Ok(data.to_vec()));
+        }
+
+        self.chunks.push(chunk_data(instruction_data)?).transpose()
+    }
+
+    /// Checks that no chunked event was left unfinished, once every
+    /// instruction was pushed.
+    pub fn finish(&self) -> Result<(), EventDecodeError> {
+        if self.chunks.is_pending() {
+            return Err(EventDecodeError::Truncated);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::{cell::RefCell, vec::Vec};
+
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::{
+        event_data, is_event_instruction, EventInstructions, EVENT_CHUNK_IX_TAG, EVENT_IX_TAG,
+    };
+    use crate::event::{decode, emit_with, Event, EventDecodeError};
+
+    #[derive(BorshSerialize, BorshDeserialize, Event, Debug, PartialEq)]
+    struct Blob {
+        bytes: Vec<u8>,
+    }
+
+    /// The event instructions `emit_cpi` would send for `event`.
+    fn event_instructions<E: Event>(event: &E) -> Vec<Vec<u8>> {
+        let instructions = RefCell::new(Vec::new());
+
+        emit_with(
+            event,
+            |data| {
+                instructions
+                    .borrow_mut()
+                    .push([&EVENT_IX_TAG[..], data].concat());
+                Ok(())
+            },
+            |chunk| {
+                instructions
+                    .borrow_mut()
+                    .push([&EVENT_CHUNK_IX_TAG[..], chunk].concat());
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        instructions.into_inner()
+    }
+
+    #[test]
+    fn event_tag_matches_anchor() {
+        assert_eq!(
+            EVENT_IX_TAG,
+            [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d]
+        );
+    }
+
+    #[test]
+    fn event_data_strips_tag() {
+        let mut data = std::vec::Vec::from(EVENT_IX_TAG);
+        data.extend_from_slice(&7u64.to_le_bytes());
+
+        assert!(is_event_instruction(&data));
+        assert_eq!(event_data(&data), Some(&7u64.to_le_bytes()[..]));
+    }
+
+    #[test]
+    fn event_data_rejects_other_instructions() {
+        let data = [0u8; 16];
+
+        assert!(!is_event_instruction(&data));
+        assert_eq!(event_data(&data), None);
+    }
+
+    #[test]
+    fn reassembles_chunked_event_instructions() {
+        let small = Blob {
+            bytes: std::vec![1; 16],
+        };
+        let large = Blob {
+            bytes: std::vec![2; 1024],
+        };
+
+        let instructions = [event_instructions(&small), event_instructions(&large)].concat();
+        assert!(instructions.len() > 2);
+
+        let mut events = EventInstructions::new();
+        let decoded = instructions
+            .iter()
+            .chain([&std::vec![0; 16]])
+            .filter_map(|data| events.push(data))
+            .map(|data| decode::<Blob>(&data.unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(events.finish(), Ok(()));
+        assert_eq!(decoded, [small, large]);
+    }
+
+    #[test]
+    fn reports_unfinished_chunked_event() {
+        let instructions = event_instructions(&Blob {
+            bytes: std::vec![2; 1024],
+        });
+
+        let mut events = EventInstructions::new();
+
+        for data in &instructions[..instructions.len() - 1] {
+            assert_eq!(events.push(data), None);
+        }
+
+        assert_eq!(events.finish(), Err(EventDecodeError::Truncated));
+    }
+}
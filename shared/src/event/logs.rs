@@ -0,0 +1,495 @@
+//! Off-chain decoding of the events a transaction logged.
+//!
+//! The runtime logs every invocation as a `Program <id> invoke [<depth>]` /
+//! `Program <id> success` pair, with the invoked program's own logs in
+//! between. [`parse_logs`] replays that nesting to find the event lines
+//! (`Program data:`, or the `base64-events` `instruction data:` lines) one
+//! program emitted, and at which depth, reassembling chunked events on the
+//! way. Lines logged by any other program, e.g. a forged or malformed line
+//! from a CPI callee, are skipped without being decoded, so they can neither
+//! pass for the program's events nor fail the whole transaction.
+//!
+//! Events sent through the `cpi-events` transport are not logged; read them
+//! from the inner instructions with `cpi::EventInstructions` instead.
+
+extern crate std;
+
+use std::{str::FromStr, vec::Vec};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use pinocchio::Address;
+
+use super::{
+    chunk::{ChunkAssembler, CHUNK_TAG},
+    EventDecodeError, EventSet, CHUNK_LOG_PREFIX, EVENT_LOG_PREFIX,
+};
+
+const INVOKE_PREFIX: &str = "Program ";
+const DATA_PREFIX: &str = "Program data: ";
+const LOG_PREFIX: &str = "Program log: ";
+const TRUNCATED: &str = "Log truncated";
+
+/// An encoded event found in the logs, with the invocation that emitted it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoggedEvent {
+    /// Invocation depth, 1 for a top-level instruction.
+    pub depth: usize,
+    /// Index of the log line that completed the event.
+    pub line: usize,
+    /// `discriminator ++ version ++ borsh(event)`, ready for `EventSet::decode`.
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LogError {
+    /// An event line outside of any program invocation.
+    OutsideInvocation { line: usize },
+    /// An invocation that does not nest within the current one, or a result
+    /// for a program other than the one currently running.
+    UnbalancedInvocation { line: usize },
+    /// Event data that is not valid base64.
+    InvalidBase64 { line: usize },
+    /// An event line, or the invocation that ended at `line`, that does not
+    /// hold a valid event.
+    Event {
+        line: usize,
+        error: EventDecodeError,
+    },
+    /// The runtime stopped logging, so events past `line` are missing.
+    Truncated { line: usize },
+}
+
+/// One running program invocation and the chunked event it may be emitting.
+struct Frame {
+    program_id: Address,
+    chunks: ChunkAssembler,
+}
+
+/// Every event `program_id` logged in the transaction, in order, with the
+/// invocation that emitted it.
+pub fn parse_logs<S: AsRef<str>>(
+    program_id: &Address,
+    logs: &[S],
+) -> Result<Vec<LoggedEvent>, LogError> {
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut events = Vec::new();
+
+    for (line, log) in logs.iter().enumerate() {
+        let log = log.as_ref();
+        let depth = stack.len();
+
+        if log.starts_with(TRUNCATED) {
+            return Err(LogError::Truncated { line });
+        }
+
+        if let Some(encoded) = log.strip_prefix(DATA_PREFIX) {
+            let frame = stack
+                .last_mut()
+                .ok_or(LogError::OutsideInvocation { line })?;
+
+            if frame.program_id != *program_id {
+                continue;
+            }
+
+            let slices = encoded
+                .split(' ')
+                .map(|slice| STANDARD.decode(slice))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| LogError::InvalidBase64 { line })?;
+
+            let data = match &slices[..] {
+                [tag, chunk] if tag[..] == CHUNK_TAG => push_chunk(frame, chunk, line)?,
+                _ => Some(slices.concat()),
+            };
+
+            events.extend(data.map(|data| LoggedEvent { depth, line, data }));
+        } else if let Some(message) = log.strip_prefix(LOG_PREFIX) {
+            let (chunked, encoded) = if let Some(encoded) = message.strip_prefix(CHUNK_LOG_PREFIX) {
+                (true, encoded)
+            } else if let Some(encoded) = message.strip_prefix(EVENT_LOG_PREFIX) {
+                (false, encoded)
+            } else {
+                continue;
+            };
+
+            let frame = stack
+                .last_mut()
+                .ok_or(LogError::OutsideInvocation { line })?;
+
+            if frame.program_id != *program_id {
+                continue;
+            }
+
+            let bytes = STANDARD
+                .decode(encoded)
+                .map_err(|_| LogError::InvalidBase64 { line })?;
+
+            let data = if chunked {
+                push_chunk(frame, &bytes, line)?
+            } else {
+                Some(bytes)
+            };
+
+            events.extend(data.map(|data| LoggedEvent { depth, line, data }));
+        } else if let Some(rest) = log.strip_prefix(INVOKE_PREFIX) {
+            let mut words = rest.split(' ');
+            let (Some(invoked), Some(status)) = (words.next(), words.next()) else {
+                continue;
+            };
+            // `Program return:`, `Program consumption:` and the like.
+            let Ok(invoked) = Address::from_str(invoked) else {
+                continue;
+            };
+
+            match status {
+                "invoke" => {
+                    let depth = words
+                        .next()
+                        .and_then(|depth| depth.strip_prefix('[')?.strip_suffix(']'))
+                        .and_then(|depth| depth.parse::<usize>().ok());
+
+                    if depth != Some(stack.len() + 1) {
+                        return Err(LogError::UnbalancedInvocation { line });
+                    }
+
+                    stack.push(Frame {
+                        program_id: invoked,
+                        chunks: ChunkAssembler::new(),
+                    });
+                }
+                "success" | "failed:" => {
+                    let frame = stack
+                        .pop()
+                        .filter(|frame| frame.program_id == invoked)
+                        .ok_or(LogError::UnbalancedInvocation { line })?;
+
+                    if frame.chunks.is_pending() {
+                        return Err(LogError::Event {
+                            line,
+                            error: EventDecodeError::Truncated,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+fn push_chunk(frame: &mut Frame, chunk: &[u8], line: usize) -> Result<Option<Vec<u8>>, LogError> {
+    frame
+        .chunks
+        .push(chunk)
+        .map_err(|error| LogError::Event { line, error })
+}
+
+/// Decodes every event `program_id` logged, at any invocation depth, into
+/// `E`. Events logged by other programs are skipped.
+pub fn decode_logs<E: EventSet, S: AsRef<str>>(
+    program_id: &Address,
+    logs: &[S],
+) -> Result<Vec<E>, LogError> {
+    parse_logs(program_id, logs)?
+        .into_iter()
+        .map(|event| {
+            E::decode(&event.data).map_err(|error| LogError::Event {
+                line: event.line,
+                error,
+            })
+        })
+        .collect()
+}
+
+/// [`decode_logs`] over the logs of a LiteSVM transaction.
+#[cfg(feature = "litesvm")]
+pub fn decode_transaction<E: EventSet>(
+    program_id: &Address,
+    meta: &litesvm::types::TransactionMetadata,
+) -> Result<Vec<E>, LogError> {
+    decode_logs(program_id, &meta.logs)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::{format, string::String, vec, vec::Vec};
+
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use pinocchio::Address;
+
+    use super::{decode_logs, parse_logs, LogError, LoggedEvent};
+    use crate::event::{
+        chunk::CHUNK_TAG, emit_with, write_event, Event, EventDecodeError, EventSet,
+        CHUNK_LOG_PREFIX, EVENT_LOG_PREFIX, MAX_EVENT_SIZE,
+    };
+
+    const PROGRAM: Address = Address::new_from_array([1; 32]);
+    const OTHER: Address = Address::new_from_array([2; 32]);
+
+    #[derive(BorshSerialize, BorshDeserialize, Event, Debug, PartialEq)]
+    struct Tick {
+        value: u64,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Event, Debug, PartialEq)]
+    struct Blob {
+        bytes: Vec<u8>,
+    }
+
+    #[derive(EventSet, Debug, PartialEq)]
+    enum TestEvent {
+        Tick(Tick),
+        Blob(Blob),
+    }
+
+    fn invoke(program_id: &Address, depth: usize) -> String {
+        format!("Program {program_id} invoke [{depth}]")
+    }
+
+    fn success(program_id: &Address) -> String {
+        format!("Program {program_id} success")
+    }
+
+    fn encode<E: Event>(event: &E) -> Vec<u8> {
+        let mut buf = [0u8; MAX_EVENT_SIZE];
+        let len = write_event(event, &mut buf).unwrap();
+        buf[..len].to_vec()
+    }
+
+    /// The `Program data:` lines `event::emit` produces for `event`.
+    fn data_lines<E: Event>(event: &E) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut chunks = Vec::new();
+
+        emit_with(
+            event,
+            |data| {
+                lines.push(format!("Program data: {}", STANDARD.encode(data)));
+                Ok(())
+            },
+            |chunk| {
+                chunks.push(format!(
+                    "Program data: {} {}",
+                    STANDARD.encode(CHUNK_TAG),
+                    STANDARD.encode(chunk)
+                ));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        lines.extend(chunks);
+        lines
+    }
+
+    /// The `Program log:` lines `event::emit` produces for `event` under
+    /// `base64-events`.
+    fn base64_lines<E: Event>(event: &E) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut chunks = Vec::new();
+
+        emit_with(
+            event,
+            |data| {
+                lines.push(format!(
+                    "Program log: {EVENT_LOG_PREFIX}{}",
+                    STANDARD.encode(data)
+                ));
+                Ok(())
+            },
+            |chunk| {
+                chunks.push(format!(
+                    "Program log: {CHUNK_LOG_PREFIX}{}",
+                    STANDARD.encode(chunk)
+                ));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        lines.extend(chunks);
+        lines
+    }
+
+    #[test]
+    fn attributes_events_to_their_invocation() {
+        let mut logs = vec![invoke(&PROGRAM, 1)];
+        logs.extend(data_lines(&Tick { value: 1 }));
+        logs.push(invoke(&OTHER, 2));
+        // A callee logging our event format must not pass for us.
+        logs.extend(data_lines(&Tick { value: 2 }));
+        logs.push(format!(
+            "Program {OTHER} consumed 100 of 200000 compute units"
+        ));
+        logs.push(success(&OTHER));
+        logs.extend(data_lines(&Tick { value: 3 }));
+        logs.push(success(&PROGRAM));
+
+        assert_eq!(
+            parse_logs(&PROGRAM, &logs),
+            Ok(vec![
+                LoggedEvent {
+                    depth: 1,
+                    line: 1,
+                    data: encode(&Tick { value: 1 }),
+                },
+                LoggedEvent {
+                    depth: 1,
+                    line: 6,
+                    data: encode(&Tick { value: 3 }),
+                },
+            ])
+        );
+        assert_eq!(
+            parse_logs(&OTHER, &logs),
+            Ok(vec![LoggedEvent {
+                depth: 2,
+                line: 3,
+                data: encode(&Tick { value: 2 }),
+            }])
+        );
+        assert_eq!(
+            decode_logs::<TestEvent, _>(&PROGRAM, &logs),
+            Ok(vec![
+                TestEvent::Tick(Tick { value: 1 }),
+                TestEvent::Tick(Tick { value: 3 }),
+            ])
+        );
+    }
+
+    #[test]
+    fn skips_malformed_lines_of_other_programs() {
+        let mut logs = vec![invoke(&PROGRAM, 1), invoke(&OTHER, 2)];
+        logs.push(String::from("Program data: not*base64"));
+        logs.push(format!("Program log: {EVENT_LOG_PREFIX}not*base64"));
+        // The first chunk of an event that never completes.
+        let chunks = data_lines(&Blob {
+            bytes: vec![0; 1024],
+        });
+        logs.push(chunks[0].clone());
+        logs.push(format!("Program log: {CHUNK_LOG_PREFIX}AAAA"));
+        logs.push(success(&OTHER));
+        logs.extend(data_lines(&Tick { value: 1 }));
+        logs.push(success(&PROGRAM));
+
+        assert_eq!(
+            decode_logs::<TestEvent, _>(&PROGRAM, &logs),
+            Ok(vec![TestEvent::Tick(Tick { value: 1 })])
+        );
+        assert_eq!(
+            parse_logs(&OTHER, &logs),
+            Err(LogError::InvalidBase64 { line: 2 })
+        );
+    }
+
+    #[test]
+    fn reassembles_chunked_events() {
+        let blob = Blob {
+            bytes: (0..1024).map(|i| i as u8).collect(),
+        };
+
+        let mut logs = vec![invoke(&PROGRAM, 1)];
+        logs.extend(data_lines(&blob));
+        logs.push(success(&PROGRAM));
+
+        assert!(logs.len() > 3);
+        assert_eq!(
+            decode_logs::<TestEvent, _>(&PROGRAM, &logs),
+            Ok(vec![TestEvent::Blob(blob)])
+        );
+    }
+
+    #[test]
+    fn decodes_base64_event_lines() {
+        let blob = Blob {
+            bytes: vec![7; 512],
+        };
+
+        let mut logs = vec![invoke(&PROGRAM, 1)];
+        logs.extend(base64_lines(&Tick { value: 9 }));
+        logs.extend(base64_lines(&blob));
+        logs.push(success(&PROGRAM));
+
+        assert_eq!(
+            decode_logs::<TestEvent, _>(&PROGRAM, &logs),
+            Ok(vec![
+                TestEvent::Tick(Tick { value: 9 }),
+                TestEvent::Blob(blob)
+            ])
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_logs() {
+        let mut logs = vec![invoke(&PROGRAM, 1)];
+        logs.extend(data_lines(&Tick { value: 1 }));
+        logs.push(String::from("Log truncated"));
+
+        assert_eq!(
+            parse_logs(&PROGRAM, &logs),
+            Err(LogError::Truncated { line: 2 })
+        );
+    }
+
+    #[test]
+    fn rejects_invocation_ending_mid_event() {
+        let mut logs = vec![invoke(&PROGRAM, 1)];
+        logs.extend(data_lines(&Blob {
+            bytes: vec![0; 1024],
+        }));
+        logs.pop();
+        logs.push(format!(
+            "Program {PROGRAM} failed: custom program error: 0x1"
+        ));
+
+        assert_eq!(
+            parse_logs(&PROGRAM, &logs),
+            Err(LogError::Event {
+                line: logs.len() - 1,
+                error: EventDecodeError::Truncated,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unbalanced_invocations() {
+        let skipped_depth = [invoke(&PROGRAM, 2)];
+        let wrong_program = [invoke(&PROGRAM, 1), success(&OTHER)];
+
+        assert_eq!(
+            parse_logs(&PROGRAM, &skipped_depth),
+            Err(LogError::UnbalancedInvocation { line: 0 })
+        );
+        assert_eq!(
+            parse_logs(&PROGRAM, &wrong_program),
+            Err(LogError::UnbalancedInvocation { line: 1 })
+        );
+    }
+
+    #[test]
+    fn rejects_events_outside_invocations() {
+        let logs = data_lines(&Tick { value: 1 });
+
+        assert_eq!(
+            parse_logs(&PROGRAM, &logs),
+            Err(LogError::OutsideInvocation { line: 0 })
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_base64() {
+        let logs = [
+            invoke(&PROGRAM, 1),
+            String::from("Program data: not*base64"),
+        ];
+
+        assert_eq!(
+            parse_logs(&PROGRAM, &logs),
+            Err(LogError::InvalidBase64 { line: 1 })
+        );
+    }
+}
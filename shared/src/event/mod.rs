@@ -0,0 +1,389 @@
+#[cfg(feature = "base64-events")]
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::io;
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, Address, ProgramResult};
+pub use shared_derive::{Event, EventSet};
+
+use crate::discriminator::DISCRIMINATOR_LEN;
+pub use crate::syscalls::log_data;
+
+pub mod chunk;
+#[cfg(feature = "cpi-events")]
+pub mod cpi;
+#[cfg(feature = "std")]
+pub mod logs;
+
+/// Discriminator plus schema version, in front of every encoded event.
+pub const EVENT_HEADER_LEN: usize = DISCRIMINATOR_LEN + 1;
+
+/// A program event with a stable wire identity.
+///
+/// Encoded events are `discriminator ++ version ++ borsh(event)`, so consumers
+/// can tell events apart without relying on a Borsh enum tag, and notice when
+/// a schema changed under them. Implement it with `#[derive(Event)]`.
+pub trait Event: BorshSerialize {
+    const NAME: &'static str;
+
+    const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN];
+
+    /// Bumped whenever the serialized fields change.
+    const VERSION: u8;
+
+    /// Upper bound of the encoded size, header included, or `usize::MAX` when
+    /// a field is unbounded. Events that always fit in [`MAX_EVENT_SIZE`] skip
+    /// the measuring pass that decides whether to chunk.
+    const MAX_SIZE: usize;
+
+    const INFO: EventInfo = EventInfo {
+        name: Self::NAME,
+        discriminator: Self::DISCRIMINATOR,
+        version: Self::VERSION,
+    };
+}
+
+/// Name, discriminator and version of an event, as listed in the IDL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventInfo {
+    pub name: &'static str,
+    pub discriminator: [u8; DISCRIMINATOR_LEN],
+    pub version: u8,
+}
+
+/// Every event a program can emit, decoded by discriminator.
+///
+/// Implement it with `#[derive(EventSet)]` on an enum with one
+/// `Variant(EventType)` per event.
+pub trait EventSet: Sized {
+    const EVENTS: &'static [EventInfo];
+
+    fn decode(data: &[u8]) -> Result<Self, EventDecodeError>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventDecodeError {
+    /// Shorter than the header.
+    Truncated,
+    /// Not one of the expected events.
+    UnknownDiscriminator([u8; DISCRIMINATOR_LEN]),
+    /// The expected event, but written with another schema version.
+    UnsupportedVersion { expected: u8, found: u8 },
+    /// The payload does not deserialize into the event.
+    InvalidData,
+    /// A chunk that does not continue the event being reassembled.
+    ChunkOutOfOrder,
+    /// The first chunk of an event arrived before the last one of the event
+    /// being reassembled, which was dropped.
+    EventCutShort,
+    /// The reassembled event does not match the checksum of its chunks.
+    ChecksumMismatch,
+}
+
+/// Upper bound of a type's Borsh encoding, summed over the fields of an event
+/// by `#[derive(Event)]`.
+///
+/// Unbounded types report `usize::MAX`; events holding them can state a real
+/// bound with `#[event(max_size = N)]`.
+pub trait SizeHint {
+    const MAX_SIZE: usize;
+}
+
+macro_rules! impl_size_hint {
+    ($($ty:ty => $size:expr),* $(,)?) => {
+        $(impl SizeHint for $ty {
+            const MAX_SIZE: usize = $size;
+        })*
+    };
+}
+
+impl_size_hint!(
+    bool => 1, u8 => 1, i8 => 1, u16 => 2, i16 => 2, u32 => 4, i32 => 4, f32 => 4,
+    u64 => 8, i64 => 8, f64 => 8, u128 => 16, i128 => 16, Address => 32,
+    alloc::string::String => usize::MAX,
+);
+
+impl<T: SizeHint, const N: usize> SizeHint for [T; N] {
+    const MAX_SIZE: usize = T::MAX_SIZE.saturating_mul(N);
+}
+
+impl<T: SizeHint> SizeHint for Option<T> {
+    const MAX_SIZE: usize = T::MAX_SIZE.saturating_add(1);
+}
+
+impl<T> SizeHint for alloc::vec::Vec<T> {
+    const MAX_SIZE: usize = usize::MAX;
+}
+
+/// Prefix of the `base64-events` log line carrying a whole event, after
+/// `Program log: `.
+pub const EVENT_LOG_PREFIX: &str = "instruction data: ";
+
+/// Prefix of the `base64-events` log lines carrying the chunks of a large
+/// event.
+pub const CHUNK_LOG_PREFIX: &str = "instruction data chunk: ";
+
+/// Largest event emitted in one piece; bigger ones are chunked.
+pub const MAX_EVENT_SIZE: usize = 256;
+
+/// Largest piece, whole event or chunk, handed to a transport at once.
+#[cfg(any(feature = "cpi-events", feature = "base64-events"))]
+const MAX_PIECE_LEN: usize = if MAX_EVENT_SIZE > chunk::MAX_CHUNK_LEN {
+    MAX_EVENT_SIZE
+} else {
+    chunk::MAX_CHUNK_LEN
+};
+#[cfg(feature = "base64-events")]
+const MAX_LOG_LEN: usize = CHUNK_LOG_PREFIX.len() + MAX_PIECE_LEN.div_ceil(3) * 4;
+
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn written(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> io::Write for SliceWriter<'a> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let end = self.pos + data.len();
+        if end > self.buf.len() {
+            return Err(io::ErrorKind::WriteZero.into());
+        }
+        self.buf[self.pos..end].copy_from_slice(data);
+        self.pos = end;
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Serializes `event` behind its header into `buf`, returning the length used.
+fn write_event<E: Event>(event: &E, buf: &mut [u8]) -> Result<usize, ProgramError> {
+    if buf.len() < EVENT_HEADER_LEN {
+        return Err(ProgramError::BorshIoError);
+    }
+
+    buf[..DISCRIMINATOR_LEN].copy_from_slice(&E::DISCRIMINATOR);
+    buf[DISCRIMINATOR_LEN] = E::VERSION;
+
+    let mut writer = SliceWriter::new(&mut buf[EVENT_HEADER_LEN..]);
+
+    event
+        .serialize(&mut writer)
+        .map_err(|_| ProgramError::BorshIoError)?;
+
+    Ok(EVENT_HEADER_LEN + writer.written())
+}
+
+/// Hands `event` to `whole` when it fits in [`MAX_EVENT_SIZE`], or streams
+/// it to `chunk` piece by piece otherwise.
+fn emit_with<E: Event>(
+    event: &E,
+    whole: impl FnOnce(&[u8]) -> ProgramResult,
+    chunk: impl FnMut(&[u8]) -> ProgramResult,
+) -> ProgramResult {
+    if E::MAX_SIZE > MAX_EVENT_SIZE {
+        let (len, checksum) = chunk::measure(event)?;
+
+        if len > MAX_EVENT_SIZE {
+            return chunk::write_chunks(event, len, checksum, chunk);
+        }
+    }
+
+    let mut event_buf = [0u8; MAX_EVENT_SIZE];
+    let event_len = write_event(event, &mut event_buf)?;
+
+    whole(&event_buf[..event_len])
+}
+
+/// Logs `event` as `Program data: <base64(discriminator ++ version ++ borsh)>`,
+/// or, past [`MAX_EVENT_SIZE`], as one `Program data: <base64(CHUNK_TAG)>
+/// <base64(chunk)>` line per chunk (see [`chunk`]).
+///
+/// The runtime does the base64 encoding, so this costs neither the compute
+/// units nor the stack buffer of the `base64-events` format.
+#[cfg(not(feature = "base64-events"))]
+pub fn emit<E: Event>(event: &E) -> ProgramResult {
+    emit_with(
+        event,
+        |data| {
+            log_data(&[data]);
+            Ok(())
+        },
+        |chunk| {
+            log_data(&[&chunk::CHUNK_TAG, chunk]);
+            Ok(())
+        },
+    )
+}
+
+/// Logs `prefix` followed by `data` in base64.
+#[cfg(feature = "base64-events")]
+fn log_base64(prefix: &str, data: &[u8]) -> ProgramResult {
+    let mut message = [0u8; MAX_LOG_LEN];
+    message[..prefix.len()].copy_from_slice(prefix.as_bytes());
+
+    let encoded_len = STANDARD
+        .encode_slice(data, &mut message[prefix.len()..])
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    crate::syscalls::log(&message[..prefix.len() + encoded_len]);
+
+    Ok(())
+}
+
+/// Logs `event` as `instruction data: <base64(discriminator ++ version ++ borsh)>`,
+/// or, past [`MAX_EVENT_SIZE`], as a series of `instruction data chunk:`
+/// lines (see [`chunk`]).
+#[cfg(feature = "base64-events")]
+pub fn emit<E: Event>(event: &E) -> ProgramResult {
+    emit_with(
+        event,
+        |data| log_base64(EVENT_LOG_PREFIX, data),
+        |chunk| log_base64(CHUNK_LOG_PREFIX, chunk),
+    )
+}
+
+/// Reads the discriminator of an encoded event without decoding it.
+pub fn peek_discriminator(data: &[u8]) -> Result<[u8; DISCRIMINATOR_LEN], EventDecodeError> {
+    data.get(..DISCRIMINATOR_LEN)
+        .and_then(|discriminator| discriminator.try_into().ok())
+        .ok_or(EventDecodeError::Truncated)
+}
+
+/// Decodes an event written by [`emit`] (or `cpi::emit_cpi`, once the
+/// instruction tag is stripped), checking its discriminator and version.
+pub fn decode<E: Event + BorshDeserialize>(data: &[u8]) -> Result<E, EventDecodeError> {
+    let discriminator = peek_discriminator(data)?;
+
+    if discriminator != E::DISCRIMINATOR {
+        return Err(EventDecodeError::UnknownDiscriminator(discriminator));
+    }
+
+    let version = *data
+        .get(DISCRIMINATOR_LEN)
+        .ok_or(EventDecodeError::Truncated)?;
+
+    if version != E::VERSION {
+        return Err(EventDecodeError::UnsupportedVersion {
+            expected: E::VERSION,
+            found: version,
+        });
+    }
+
+    E::try_from_slice(&data[EVENT_HEADER_LEN..]).map_err(|_| EventDecodeError::InvalidData)
+}
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use super::{decode, write_event, Event, EventDecodeError, EventSet, MAX_EVENT_SIZE};
+    use crate::discriminator::discriminator;
+
+    #[derive(BorshSerialize, BorshDeserialize, Event, Debug, PartialEq)]
+    struct Ping {
+        value: u64,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Event, Debug, PartialEq)]
+    #[event(version = 2)]
+    struct Pong {
+        value: u32,
+    }
+
+    #[derive(EventSet, Debug, PartialEq)]
+    enum PingPong {
+        Ping(Ping),
+        Pong(Pong),
+    }
+
+    fn encode<E: Event>(event: &E) -> ([u8; MAX_EVENT_SIZE], usize) {
+        let mut buf = [0u8; MAX_EVENT_SIZE];
+        let len = write_event(event, &mut buf).unwrap();
+        (buf, len)
+    }
+
+    #[test]
+    fn derive_uses_name_and_version() {
+        assert_eq!(Ping::NAME, "Ping");
+        assert_eq!(Ping::DISCRIMINATOR, discriminator(b"event:Ping"));
+        assert_eq!(Ping::VERSION, 1);
+        assert_eq!(Pong::VERSION, 2);
+    }
+
+    #[test]
+    fn encoding_starts_with_header() {
+        let (buf, len) = encode(&Ping { value: 7 });
+
+        assert_eq!(len, 8 + 1 + 8);
+        assert_eq!(buf[..8], Ping::DISCRIMINATOR);
+        assert_eq!(buf[8], 1);
+        assert_eq!(buf[9..17], 7u64.to_le_bytes());
+    }
+
+    #[test]
+    fn decode_round_trips() {
+        let (buf, len) = encode(&Pong { value: 3 });
+
+        assert_eq!(decode::<Pong>(&buf[..len]), Ok(Pong { value: 3 }));
+        assert_eq!(
+            PingPong::decode(&buf[..len]),
+            Ok(PingPong::Pong(Pong { value: 3 }))
+        );
+    }
+
+    #[test]
+    fn decode_rejects_other_event() {
+        let (buf, len) = encode(&Ping { value: 7 });
+
+        assert_eq!(
+            decode::<Pong>(&buf[..len]),
+            Err(EventDecodeError::UnknownDiscriminator(Ping::DISCRIMINATOR))
+        );
+    }
+
+    #[test]
+    fn decode_rejects_unknown_discriminator() {
+        let data = [0u8; 16];
+
+        assert_eq!(
+            PingPong::decode(&data),
+            Err(EventDecodeError::UnknownDiscriminator([0; 8]))
+        );
+    }
+
+    #[test]
+    fn decode_rejects_version_mismatch() {
+        let (mut buf, len) = encode(&Ping { value: 7 });
+        buf[8] = 2;
+
+        assert_eq!(
+            decode::<Ping>(&buf[..len]),
+            Err(EventDecodeError::UnsupportedVersion {
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn decode_rejects_truncated_data() {
+        let (buf, _) = encode(&Ping { value: 7 });
+
+        assert_eq!(decode::<Ping>(&buf[..4]), Err(EventDecodeError::Truncated));
+        assert_eq!(
+            decode::<Ping>(&buf[..12]),
+            Err(EventDecodeError::InvalidData)
+        );
+    }
+}
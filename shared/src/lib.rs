@@ -1,3 +1,13 @@
 #![no_std]
 
+extern crate alloc;
+
+// Lets the derive macros' `::shared::...` paths resolve inside this crate too.
+extern crate self as shared;
+
+pub mod account;
+pub mod discriminator;
+pub mod error;
 pub mod event;
+pub mod syscalls;
+pub mod system;
@@ -0,0 +1,223 @@
+//! The syscalls the programs make, in one place so their processors also run
+//! natively.
+//!
+//! On-chain each function is the pinocchio syscall. Off-chain pinocchio
+//! compiles CPIs, sysvars and logs to no-ops, so with `std` a host can install
+//! [`SyscallStubs`] that carry them out instead, e.g. `test_utils::native`
+//! running a processor as a LiteSVM builtin. Without stubs the functions keep
+//! pinocchio's off-chain behavior.
+
+#[cfg(all(feature = "std", not(target_os = "solana")))]
+extern crate std;
+
+#[cfg(all(feature = "std", not(target_os = "solana")))]
+use std::sync::{PoisonError, RwLock};
+
+use pinocchio::{
+    address::MAX_SEEDS,
+    cpi::{self, Seed, Signer},
+    error::ProgramError,
+    instruction::InstructionView,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    AccountView, ProgramResult,
+};
+#[cfg(all(feature = "std", not(target_os = "solana")))]
+use pinocchio::{sysvars::clock::CLOCK_ID, sysvars::rent::RENT_ID, Address};
+
+/// Carries out the syscalls of a processor run off-chain.
+#[cfg(all(feature = "std", not(target_os = "solana")))]
+pub trait SyscallStubs: Send + Sync {
+    /// `sol_invoke_signed`, with the seeds of every signer PDA.
+    fn invoke_signed(&self, instruction: &InstructionView, signers: &[&[&[u8]]]) -> ProgramResult;
+
+    /// `sol_get_sysvar`: fills `var` with the leading bytes of the sysvar
+    /// `id`, in its bincode layout.
+    fn get_sysvar(&self, id: &Address, var: &mut [u8]) -> ProgramResult;
+
+    /// `sol_log_`.
+    fn log(&self, message: &[u8]);
+
+    /// `sol_log_data`.
+    fn log_data(&self, data: &[&[u8]]);
+}
+
+#[cfg(all(feature = "std", not(target_os = "solana")))]
+static SYSCALL_STUBS: RwLock<Option<&'static dyn SyscallStubs>> = RwLock::new(None);
+
+/// Routes the syscalls of every processor run off-chain to `stubs`, returning
+/// the stubs installed before.
+#[cfg(all(feature = "std", not(target_os = "solana")))]
+pub fn set_syscall_stubs(stubs: &'static dyn SyscallStubs) -> Option<&'static dyn SyscallStubs> {
+    SYSCALL_STUBS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .replace(stubs)
+}
+
+#[cfg(all(feature = "std", not(target_os = "solana")))]
+fn syscall_stubs() -> Option<&'static dyn SyscallStubs> {
+    *SYSCALL_STUBS.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Most PDAs a CPI made through [`with_signers`] can be signed by.
+pub const MAX_SIGNERS: usize = 4;
+
+/// Calls `f` with a pinocchio [`Signer`] for each PDA in `signers`, given as
+/// the seeds it is derived from. The signers are built on the stack, since
+/// most programs here have no allocator.
+pub fn with_signers(
+    signers: &[&[&[u8]]],
+    f: impl FnOnce(&[Signer]) -> ProgramResult,
+) -> ProgramResult {
+    if signers.len() > MAX_SIGNERS || signers.iter().any(|seeds| seeds.len() > MAX_SEEDS) {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    let signer_seeds = |signer: usize| signers.get(signer).copied().unwrap_or_default();
+
+    let seeds: [[Seed; MAX_SEEDS]; MAX_SIGNERS] = core::array::from_fn(|signer| {
+        core::array::from_fn(|seed| {
+            Seed::from(signer_seeds(signer).get(seed).copied().unwrap_or_default())
+        })
+    });
+
+    let pda_signers: [Signer; MAX_SIGNERS] =
+        core::array::from_fn(|signer| Signer::from(&seeds[signer][..signer_seeds(signer).len()]));
+
+    f(&pda_signers[..signers.len()])
+}
+
+/// `cpi::invoke_signed`, with the seeds of every signer PDA.
+pub fn invoke_signed<const ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    accounts: &[&AccountView; ACCOUNTS],
+    signers: &[&[&[u8]]],
+) -> ProgramResult {
+    // Off-chain this still checks `accounts` against the instruction and
+    // their borrows, and only skips the CPI itself.
+    with_signers(signers, |signers| {
+        cpi::invoke_signed(instruction, accounts, signers)
+    })?;
+
+    #[cfg(all(feature = "std", not(target_os = "solana")))]
+    if let Some(stubs) = syscall_stubs() {
+        return stubs.invoke_signed(instruction, signers);
+    }
+
+    Ok(())
+}
+
+/// `cpi::invoke`.
+pub fn invoke<const ACCOUNTS: usize>(
+    instruction: &InstructionView,
+    accounts: &[&AccountView; ACCOUNTS],
+) -> ProgramResult {
+    invoke_signed(instruction, accounts, &[])
+}
+
+/// Reads a sysvar of `size_of::<T>()` bytes, a prefix of its bincode layout,
+/// from the stubs.
+#[cfg(all(feature = "std", not(target_os = "solana")))]
+fn stub_sysvar<T: Sysvar>(stubs: &dyn SyscallStubs, id: &Address) -> Result<T, ProgramError> {
+    let mut var = core::mem::MaybeUninit::<T>::zeroed();
+
+    // SAFETY: `var` is `size_of::<T>()` initialized bytes, and the sysvars
+    // read here are plain integers, valid for any bytes the stubs write.
+    unsafe {
+        stubs.get_sysvar(
+            id,
+            core::slice::from_raw_parts_mut(var.as_mut_ptr() as *mut u8, size_of::<T>()),
+        )?;
+
+        Ok(var.assume_init())
+    }
+}
+
+/// `Rent::get`.
+pub fn rent() -> Result<Rent, ProgramError> {
+    #[cfg(all(feature = "std", not(target_os = "solana")))]
+    if let Some(stubs) = syscall_stubs() {
+        return stub_sysvar(stubs, &RENT_ID);
+    }
+
+    Rent::get()
+}
+
+/// `Clock::get`.
+pub fn clock() -> Result<Clock, ProgramError> {
+    #[cfg(all(feature = "std", not(target_os = "solana")))]
+    if let Some(stubs) = syscall_stubs() {
+        return stub_sysvar(stubs, &CLOCK_ID);
+    }
+
+    Clock::get()
+}
+
+/// Logs `message` as a `Program log:` line.
+pub fn log(message: &[u8]) {
+    #[cfg(all(feature = "std", not(target_os = "solana")))]
+    if let Some(stubs) = syscall_stubs() {
+        return stubs.log(message);
+    }
+
+    pinocchio_log::logger::log_message(message);
+}
+
+/// Logs `slices` as a `Program data:` line, each slice base64-encoded by the
+/// runtime and separated by a space.
+pub fn log_data(slices: &[&[u8]]) {
+    #[cfg(target_os = "solana")]
+    // SAFETY: the syscall reads `slices.len()` fat pointers, laid out as
+    // `(ptr, len)` pairs, from `slices`.
+    unsafe {
+        pinocchio::syscalls::sol_log_data(slices.as_ptr() as *const u8, slices.len() as u64);
+    }
+
+    #[cfg(all(feature = "std", not(target_os = "solana")))]
+    if let Some(stubs) = syscall_stubs() {
+        return stubs.log_data(slices);
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box(slices);
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::error::ProgramError;
+
+    use super::{with_signers, MAX_SIGNERS};
+
+    #[test]
+    fn builds_a_signer_per_pda() {
+        let bump = [254];
+        let seeds: [&[u8]; 2] = [b"counter", &bump];
+
+        with_signers(&[&seeds, &[]], |signers| {
+            assert_eq!(signers.len(), 2);
+            Ok(())
+        })
+        .unwrap();
+
+        with_signers(&[], |signers| {
+            assert!(signers.is_empty());
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn rejects_more_signers_or_seeds_than_it_holds() {
+        let seeds: [&[u8]; 17] = [b"seed"; 17];
+        let no_seeds: &[&[u8]] = &[];
+
+        assert_eq!(
+            with_signers(&[&seeds], |_| Ok(())),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+        assert_eq!(
+            with_signers(&[no_seeds; MAX_SIGNERS + 1], |_| Ok(())),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+    }
+}
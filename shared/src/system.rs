@@ -0,0 +1,83 @@
+//! System program CPIs with the API of `pinocchio-system`, taking the signer
+//! seeds directly. On-chain they are the `pinocchio-system` builders;
+//! off-chain the same instructions go through [`crate::syscalls`], so they
+//! also run natively.
+
+#[cfg(not(target_os = "solana"))]
+use pinocchio::instruction::{InstructionAccount, InstructionView};
+use pinocchio::{AccountView, Address, ProgramResult};
+
+#[cfg(not(target_os = "solana"))]
+use crate::syscalls::invoke_signed;
+#[cfg(target_os = "solana")]
+use crate::syscalls::with_signers;
+
+/// The system program.
+pub const ID: Address = Address::new_from_array([0; 32]);
+
+/// Create a new account.
+///
+/// ### Accounts:
+///   0. `[WRITE, SIGNER]` Funding account
+///   1. `[WRITE, SIGNER]` New account
+pub struct CreateAccount<'a, 'b> {
+    /// Funding account.
+    pub from: &'a AccountView,
+
+    /// New account.
+    pub to: &'a AccountView,
+
+    /// Number of lamports to transfer to the new account.
+    pub lamports: u64,
+
+    /// Number of bytes of memory to allocate.
+    pub space: u64,
+
+    /// Address of program that will own the new account.
+    pub owner: &'b Address,
+}
+
+impl CreateAccount<'_, '_> {
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[cfg(target_os = "solana")]
+    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
+        with_signers(signers, |signers| {
+            pinocchio_system::instructions::CreateAccount {
+                from: self.from,
+                to: self.to,
+                lamports: self.lamports,
+                space: self.space,
+                owner: self.owner,
+            }
+            .invoke_signed(signers)
+        })
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
+        let instruction_accounts = [
+            InstructionAccount::writable_signer(self.from.address()),
+            InstructionAccount::writable_signer(self.to.address()),
+        ];
+
+        // - [0..4  ]: instruction discriminator, 0
+        // - [4..12 ]: lamports
+        // - [12..20]: account space
+        // - [20..52]: owner address
+        let mut data = [0; 52];
+        data[4..12].copy_from_slice(&self.lamports.to_le_bytes());
+        data[12..20].copy_from_slice(&self.space.to_le_bytes());
+        data[20..52].copy_from_slice(self.owner.as_ref());
+
+        let instruction = InstructionView {
+            program_id: &ID,
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(&instruction, &[self.from, self.to], signers)
+    }
+}
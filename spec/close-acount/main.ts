@@ -35,10 +35,16 @@ const main = async () => {
 
 	assert(getBase58Decoder().decode(meme.data.address) === memeAddr)
 
+	assert(getBase58Decoder().decode(meme.data.authority) === payer.address)
+
 	assert(meme.data.bump, bump)
 	assert(meme.data.address, memeAddr)
 
-	const closeMemeIx = getCloseMemeInstruction({ meme: memeAddr, payer })
+	const closeMemeIx = getCloseMemeInstruction({
+		authority: payer,
+		meme: memeAddr,
+		destination: payer.address
+	})
 
 	const closeMemeSignature = await buildAndSendTransaction(payer, [closeMemeIx])
 
@@ -0,0 +1,143 @@
+//! `error-branches`, which runs the tests of the counter, close-account and
+//! create-mint programs under `cargo llvm-cov` and fails when one of their
+//! error branches is never hit. Prints each branch with its hit count.
+//!
+//! ```bash
+//! cargo install cargo-llvm-cov
+//! cargo run -p test-utils --bin error-branches
+//! ```
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode},
+};
+
+use test_utils::{error_branch_report, Coverage};
+
+/// The test runs whose coverage is merged, as `cargo llvm-cov` arguments.
+const RUNS: [&[&str]; 2] = [
+    &["-p", "counter", "-p", "close-account", "-p", "create-mint"],
+    // The branches of the self-CPI event transport.
+    &["-p", "counter", "--features", "cpi-events"],
+];
+
+struct Program {
+    /// Source directory, relative to the workspace root.
+    src: &'static str,
+    /// Files searched for branches, relative to `src`.
+    files: &'static [&'static str],
+    /// `(file, error, reason)` of the branches no test can reach.
+    untestable: &'static [(&'static str, &'static str, &'static str)],
+}
+
+const BORSH_ONLY: &str = "only built for the `borsh-accounts` baseline, which repeats the \
+                          zero-copy checks and re-encodes a same-sized value";
+
+const PROGRAMS: [Program; 3] = [
+    Program {
+        src: "basic/counter/src",
+        files: &["lib.rs", "accounts", "events/mod.rs", "instructions"],
+        untestable: &[
+            (
+                "instructions/increase_counter.rs",
+                "CounterError::InvalidCounterData",
+                BORSH_ONLY,
+            ),
+            (
+                "instructions/increase_counter_authority.rs",
+                "CounterError::InvalidCounterData",
+                BORSH_ONLY,
+            ),
+            (
+                "instructions/increase_counter_authority.rs",
+                "CounterError::AuthorityMismatch",
+                "only built for the `borsh-accounts` baseline, which repeats the zero-copy \
+                 check",
+            ),
+            (
+                "instructions/init_counter.rs",
+                "CounterError::InvalidCounterData",
+                "`CreateAccount` allocates exactly `Counter::SPACE`",
+            ),
+            (
+                "instructions/init_counter_authority.rs",
+                "CounterError::InvalidCounterData",
+                "`CreateAccount` allocates exactly `CounterAuthority::SPACE`",
+            ),
+        ],
+    },
+    Program {
+        src: "basic/close-account/src",
+        files: &["lib.rs", "instructions"],
+        untestable: &[(
+            "instructions/create_meme.rs",
+            "CloseAccountError::InvalidMemeData",
+            "`CreateAccount` allocates exactly `Meme::SPACE`",
+        )],
+    },
+    Program {
+        src: "token/create-mint/src",
+        files: &["lib.rs", "instructions"],
+        untestable: &[],
+    },
+];
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// Runs the tests `args` selects under coverage, returning the export path.
+fn measure(run: usize, args: &[&str]) -> PathBuf {
+    let dir = workspace_root().join("target/error-branches");
+    fs::create_dir_all(&dir).unwrap();
+
+    let export = dir.join(format!("run-{run}.json"));
+
+    let status = Command::new("cargo")
+        .args(["llvm-cov", "--json", "--output-path"])
+        .arg(&export)
+        .args(args)
+        .current_dir(workspace_root())
+        // Keeps stdout for the report.
+        .stdout(io::stderr())
+        .status()
+        .unwrap_or_else(|error| panic!("could not run `cargo llvm-cov`: {error}"));
+
+    assert!(
+        status.success(),
+        "`cargo llvm-cov {}` failed; install it with `cargo install cargo-llvm-cov`",
+        args.join(" ")
+    );
+
+    export
+}
+
+fn main() -> ExitCode {
+    let mut coverage = Coverage::default();
+
+    for (run, args) in RUNS.iter().enumerate() {
+        coverage.add_export(measure(run, args));
+    }
+
+    let mut missed = 0;
+
+    for program in PROGRAMS {
+        let (report, program_missed) = error_branch_report(
+            &coverage,
+            workspace_root().join(program.src),
+            program.files,
+            program.untestable,
+        );
+
+        println!("{}\n{report}", program.src);
+        missed += program_missed;
+    }
+
+    if missed > 0 {
+        eprintln!("{missed} error branches are never hit by a test");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,354 @@
+//! Checks that the tests of a program reach every error it returns, from the
+//! line coverage `cargo llvm-cov` measures over them.
+//!
+//! The branches are found in the sources: each `return Err(..)` line of a
+//! program file, and each `.map_err(|_| ..)?` and `.ok_or(..)?` line. A branch
+//! counts as hit once its coverage region ran: the `Err(..)` expression of a
+//! `return`, or the error path of the `?`. Code compiled out of every
+//! measured build has no region at all, and is reported as not built.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+/// Prefixes of the expressions returning an error early, and whether the
+/// error path is the `?` after them rather than the `Err(..)` they start.
+const BRANCH_MARKERS: [(&str, bool); 3] = [
+    ("return Err(", false),
+    (".map_err(|_| ", true),
+    (".ok_or(", true),
+];
+
+/// Where `#[cfg(test)] mod test {` starts the tests of a program file.
+const TEST_MODULE: &str = "#[cfg(test)]\nmod test {";
+
+/// An early error return of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorBranch {
+    /// The file, relative to the directory the branches were searched in.
+    pub file: PathBuf,
+    pub line: usize,
+    /// The 1-based byte column of the error path's coverage region.
+    pub column: usize,
+    /// The error path, e.g. `CounterError::CounterNotInitialized`.
+    pub error: String,
+}
+
+/// The error branches of `files`, relative to `src`, in file and line order.
+/// A directory stands for the `.rs` files directly in it.
+pub fn error_branches(src: impl AsRef<Path>, files: &[&str]) -> Vec<ErrorBranch> {
+    let src = src.as_ref();
+
+    let mut paths = Vec::new();
+
+    for file in files {
+        let path = src.join(file);
+
+        if path.is_dir() {
+            let mut entries = fs::read_dir(&path)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+                .collect::<Vec<_>>();
+            entries.sort();
+            paths.extend(entries);
+        } else {
+            paths.push(path);
+        }
+    }
+
+    paths
+        .into_iter()
+        .flat_map(|path| {
+            let source = fs::read_to_string(&path)
+                .unwrap_or_else(|error| panic!("could not read {}: {error}", path.display()));
+            file_branches(path.strip_prefix(src).unwrap(), &source)
+        })
+        .collect()
+}
+
+fn file_branches(file: &Path, source: &str) -> Vec<ErrorBranch> {
+    let program = &source[..source.find(TEST_MODULE).unwrap_or(source.len())];
+
+    program
+        .lines()
+        .enumerate()
+        .flat_map(|(index, line)| {
+            BRANCH_MARKERS
+                .iter()
+                .flat_map(move |&(marker, at_question_mark)| {
+                    line.match_indices(marker).filter_map(move |(start, _)| {
+                        let rest = &line[start + marker.len()..];
+                        let end = rest
+                            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+                            .unwrap_or(rest.len());
+                        let error = &rest[..end];
+
+                        if !error.contains("::") {
+                            return None;
+                        }
+
+                        let column = if at_question_mark {
+                            start + marker.len() + closing_paren(rest)? + 1
+                        } else {
+                            start + "return ".len()
+                        };
+
+                        Some(ErrorBranch {
+                            file: file.to_path_buf(),
+                            line: index + 1,
+                            column: column + 1,
+                            error: error.to_string(),
+                        })
+                    })
+                })
+        })
+        .collect()
+}
+
+/// The index of the `)` closing the call `rest` is the arguments of, when a
+/// `?` follows it.
+fn closing_paren(rest: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (index, c) in rest.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return rest[index + 1..].starts_with('?').then_some(index),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The execution counts of the coverage regions of a `cargo llvm-cov --json`
+/// export, merged over every export added.
+#[derive(Debug, Default)]
+pub struct Coverage {
+    /// By absolute file path, the count of each region by its start.
+    regions: BTreeMap<PathBuf, BTreeMap<(usize, usize), u64>>,
+}
+
+impl Coverage {
+    /// Adds the regions of the export at `path`, summing the counts of the
+    /// regions already added.
+    pub fn add_export(&mut self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let export = fs::read(path)
+            .unwrap_or_else(|error| panic!("could not read {}: {error}", path.display()));
+
+        self.add(&serde_json::from_slice(&export).unwrap());
+    }
+
+    fn add(&mut self, export: &Value) {
+        let files = export["data"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .flat_map(|data| data["files"].as_array().into_iter().flatten());
+
+        for file in files {
+            let regions = self
+                .regions
+                .entry(PathBuf::from(file["filename"].as_str().unwrap()))
+                .or_default();
+
+            // `[line, column, count, has_count, is_region_entry, is_gap_region]`
+            let segments = file["segments"].as_array().into_iter().flatten();
+
+            for segment in segments {
+                let field = |index: usize| &segment[index];
+
+                if field(3) == true && field(4) == true && field(5) == false {
+                    let start = (
+                        field(0).as_u64().unwrap() as usize,
+                        field(1).as_u64().unwrap() as usize,
+                    );
+
+                    *regions.entry(start).or_default() += field(2).as_u64().unwrap();
+                }
+            }
+        }
+    }
+
+    /// How often the region starting at `line` and `column` of `file` ran, or
+    /// `None` when no region starts there.
+    pub fn count(&self, file: &Path, line: usize, column: usize) -> Option<u64> {
+        self.regions.get(file)?.get(&(line, column)).copied()
+    }
+}
+
+/// The error branches of `files`, relative to `src`, with how often
+/// `coverage` saw each of them run, and how many were never hit.
+///
+/// `untestable` lists the `(file, error)` branches no test can reach, e.g. a
+/// check repeating what the runtime already guarantees or code only built
+/// with a feature left out of the coverage runs, with the reason as a third
+/// element. It only excuses the branches of that error left unhit.
+pub fn error_branch_report(
+    coverage: &Coverage,
+    src: impl AsRef<Path>,
+    files: &[&str],
+    untestable: &[(&str, &str, &str)],
+) -> (String, usize) {
+    let src = src.as_ref();
+
+    report(coverage, src, error_branches(src, files), untestable)
+}
+
+fn report(
+    coverage: &Coverage,
+    src: &Path,
+    branches: Vec<ErrorBranch>,
+    untestable: &[(&str, &str, &str)],
+) -> (String, usize) {
+    let mut report = String::new();
+    let mut missed = 0;
+
+    for branch in branches {
+        let file = branch.file.to_string_lossy();
+
+        let status = match (
+            coverage.count(&src.join(&branch.file), branch.line, branch.column),
+            untestable
+                .iter()
+                .find(|(path, error, _)| *path == file && *error == branch.error),
+        ) {
+            (Some(1), _) => "hit once".to_string(),
+            (Some(count @ 2..), _) => format!("hit {count} times"),
+            (_, Some((_, _, reason))) => format!("untestable: {reason}"),
+            (Some(_), None) => {
+                missed += 1;
+                "NEVER HIT".to_string()
+            }
+            (None, None) => {
+                missed += 1;
+                "NOT BUILT".to_string()
+            }
+        };
+
+        writeln!(
+            report,
+            "{:<48} {:<48} {status}",
+            format!("{file}:{}", branch.line),
+            branch.error
+        )
+        .unwrap();
+    }
+
+    (report, missed)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{file_branches, report, Coverage, ErrorBranch};
+
+    const SOURCE: &str = r#"
+pub fn process(accounts: &[AccountView]) -> ProgramResult {
+    let [payer, meme] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !payer.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let data = Meme::load(&data).map_err(|_| MemeError::InvalidMemeData)?;
+
+    let count = data.count.checked_add(1).ok_or(MemeError::Overflow);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    fn without_signers() {
+        return Err(MemeError::NotAProgramBranch);
+    }
+}
+"#;
+
+    fn branch(line: usize, column: usize, error: &str) -> ErrorBranch {
+        ErrorBranch {
+            file: "meme.rs".into(),
+            line,
+            column,
+            error: error.to_string(),
+        }
+    }
+
+    #[test]
+    fn finds_the_error_path_of_each_branch() {
+        assert_eq!(
+            file_branches(Path::new("meme.rs"), SOURCE),
+            [
+                // The `Err(..)` of a `return`.
+                branch(4, 16, "ProgramError::NotEnoughAccountKeys"),
+                branch(8, 16, "ProgramError::MissingRequiredSignature"),
+                // The `?` after the call.
+                branch(11, 73, "MemeError::InvalidMemeData"),
+                // Without a `?` the error is not returned here.
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_the_branches_coverage_never_saw_run() {
+        let segment = |line, column, count| json!([line, column, count, true, true, false]);
+
+        let mut coverage = Coverage::default();
+        for count in [1, 2] {
+            coverage.add(&json!({
+                "data": [{
+                    "files": [{
+                        "filename": "/src/meme.rs",
+                        "segments": [
+                            segment(4, 16, count),
+                            segment(8, 16, 0),
+                            // Where the region ends, not where one starts.
+                            json!([11, 73, 5, true, false, false]),
+                        ],
+                    }],
+                }],
+            }));
+        }
+
+        let (report, missed) = report(
+            &coverage,
+            Path::new("/src"),
+            file_branches(Path::new("meme.rs"), SOURCE),
+            &[(
+                "meme.rs",
+                "MemeError::InvalidMemeData",
+                "never deserialized",
+            )],
+        );
+
+        let statuses = report
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .skip(2)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            statuses,
+            ["hit 3 times", "NEVER HIT", "untestable: never deserialized"]
+        );
+        assert_eq!(missed, 1);
+    }
+}
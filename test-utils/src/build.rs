@@ -0,0 +1,145 @@
+//! Builds the programs under test with `cargo build-sbf`, so tests never load
+//! a missing or stale binary.
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+use serde_json::Value;
+
+/// Points at a directory of prebuilt programs, e.g. the release binaries
+/// being verified, which are then loaded as they are instead of built.
+pub const SBF_OUT_DIR: &str = "SBF_OUT_DIR";
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// Where the binaries built by hand live: `SBF_OUT_DIR`, or the
+/// `target/deploy` of a plain `cargo build-sbf`.
+pub(crate) fn deploy_dir() -> PathBuf {
+    match std::env::var_os(SBF_OUT_DIR) {
+        Some(dir) => PathBuf::from(dir),
+        None => workspace_root().join("target/deploy"),
+    }
+}
+
+/// The binary of the program built from the crate `crate_name` with
+/// `features`, built first unless `SBF_OUT_DIR` is set.
+///
+/// `cargo build-sbf` runs once per binary and test process, and only
+/// recompiles what changed since the last run.
+pub fn program_path(crate_name: &str, features: &[&str]) -> PathBuf {
+    let file_name = format!("{}.so", crate_name.replace('-', "_"));
+
+    if std::env::var_os(SBF_OUT_DIR).is_some() {
+        return deploy_dir().join(file_name);
+    }
+
+    // One target directory per feature set, so binaries built with different
+    // features neither overwrite nor keep rebuilding each other.
+    let features = features.join(",");
+    let target_dir = workspace_root()
+        .join("target/test-sbf")
+        .join(match features.as_str() {
+            "" => "default",
+            features => features,
+        });
+    let path = target_dir.join("deploy").join(file_name);
+
+    static BUILT: Mutex<BTreeSet<PathBuf>> = Mutex::new(BTreeSet::new());
+
+    // Held while building, so concurrent tests wait for the binary rather
+    // than build it again.
+    let mut built = BUILT.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if !built.contains(&path) {
+        build(crate_name, &features, &target_dir);
+        built.insert(path.clone());
+    }
+
+    path
+}
+
+fn build(crate_name: &str, features: &str, target_dir: &Path) {
+    let mut command = Command::new("cargo");
+
+    command
+        .arg("build-sbf")
+        .arg("--manifest-path")
+        .arg(manifest_path(crate_name))
+        .arg("--sbf-out-dir")
+        .arg(target_dir.join("deploy"))
+        // `cargo test` keeps the workspace target directory locked while the
+        // tests run.
+        .env("CARGO_TARGET_DIR", target_dir);
+
+    if !features.is_empty() {
+        command.args(["--features", features]);
+    }
+
+    let output = command
+        .output()
+        .unwrap_or_else(|error| panic!("could not run `cargo build-sbf`: {error}"));
+
+    if !output.status.success() {
+        panic!(
+            "`cargo build-sbf` failed for {crate_name}; it comes with the Solana CLI, or set \
+             {SBF_OUT_DIR} to a directory of prebuilt programs to load those instead:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+/// The manifest of the workspace member `crate_name`.
+fn manifest_path(crate_name: &str) -> PathBuf {
+    static METADATA: OnceLock<Value> = OnceLock::new();
+
+    let metadata = METADATA.get_or_init(|| {
+        let output = Command::new("cargo")
+            .args(["metadata", "--no-deps", "--format-version", "1"])
+            .arg("--manifest-path")
+            .arg(workspace_root().join("Cargo.toml"))
+            .output()
+            .unwrap_or_else(|error| panic!("could not run `cargo metadata`: {error}"));
+
+        assert!(
+            output.status.success(),
+            "`cargo metadata` failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        serde_json::from_slice(&output.stdout).unwrap()
+    });
+
+    metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|package| package["name"] == crate_name)
+        .and_then(|package| package["manifest_path"].as_str())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| panic!("{crate_name} is not a member of the workspace"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{manifest_path, workspace_root};
+
+    #[test]
+    fn finds_workspace_members() {
+        assert_eq!(
+            manifest_path("close-account"),
+            workspace_root().join("basic/close-account/Cargo.toml")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not-a-program is not a member of the workspace")]
+    fn rejects_unknown_crates() {
+        manifest_path("not-a-program");
+    }
+}
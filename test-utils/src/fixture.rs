@@ -0,0 +1,403 @@
+#[cfg(feature = "sbf")]
+use std::path::PathBuf;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "sbf")]
+use litesvm::EmptyInvocationInspectCallback;
+use litesvm::LiteSVM;
+use pinocchio::Address;
+use solana_sdk::{
+    account::Account, clock::Clock, instruction::Instruction, native_token::LAMPORTS_PER_SOL,
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+#[cfg(feature = "sbf")]
+use crate::build::program_path;
+#[cfg(not(feature = "sbf"))]
+use crate::native;
+#[cfg(feature = "sbf")]
+use crate::stack::StackTracer;
+use crate::{Outcome, Processor};
+
+/// Airdropped to every payer the fixture creates.
+pub const PAYER_LAMPORTS: u64 = LAMPORTS_PER_SOL;
+
+/// Configures a [`Fixture`]: the programs to load, the clock and the accounts
+/// present before the first transaction.
+#[derive(Default)]
+pub struct FixtureBuilder {
+    #[cfg(not(feature = "sbf"))]
+    programs: Vec<(Address, Processor)>,
+    #[cfg(feature = "sbf")]
+    programs: Vec<(Address, PathBuf)>,
+    accounts: Vec<(Address, Account)>,
+    clock: Option<Clock>,
+}
+
+impl FixtureBuilder {
+    /// Loads the program of the crate `crate_name`, e.g. `close-account`,
+    /// whose entrypoint calls `process`: natively, or built with
+    /// `cargo build-sbf` under the `sbf` feature.
+    pub fn program(
+        self,
+        program_id: impl Into<Address>,
+        crate_name: &str,
+        process: Processor,
+    ) -> Self {
+        self.program_with_features(program_id, crate_name, process, &[])
+    }
+
+    /// Loads the program of the crate `crate_name` with `features`, which a
+    /// native `process` was compiled with already: they must be the features
+    /// of the crate under test.
+    pub fn program_with_features(
+        mut self,
+        program_id: impl Into<Address>,
+        crate_name: &str,
+        process: Processor,
+        features: &[&str],
+    ) -> Self {
+        #[cfg(not(feature = "sbf"))]
+        {
+            let _ = (crate_name, features);
+            self.programs.push((program_id.into(), process));
+        }
+
+        #[cfg(feature = "sbf")]
+        {
+            let _ = process;
+            self.programs
+                .push((program_id.into(), program_path(crate_name, features)));
+        }
+
+        self
+    }
+
+    /// Pins the clock sysvar, e.g. so emitted events carry a known slot and
+    /// timestamp.
+    pub fn clock(mut self, clock: Clock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    pub fn account(mut self, address: Address, account: Account) -> Self {
+        self.accounts.push((address, account));
+        self
+    }
+
+    /// Presets the Borsh encoding of `value` at `address`, see
+    /// [`Fixture::set_borsh_account`].
+    pub fn borsh_account(
+        self,
+        address: Address,
+        owner: Address,
+        value: &impl BorshSerialize,
+    ) -> Self {
+        self.account(address, borsh_account(owner, value))
+    }
+
+    pub fn build(self) -> Fixture {
+        let mut svm = self.into_svm();
+
+        #[cfg(feature = "sbf")]
+        let stack = StackTracer::default();
+        #[cfg(feature = "sbf")]
+        svm.set_invocation_inspect_callback(stack.clone());
+
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), PAYER_LAMPORTS).unwrap();
+
+        Fixture {
+            svm,
+            payer,
+            #[cfg(feature = "sbf")]
+            stack,
+        }
+    }
+
+    /// The LiteSVM with the programs, clock and accounts configured, but no
+    /// payer, for harnesses sending their own transactions.
+    pub fn into_svm(self) -> LiteSVM {
+        // `LiteSVM::new` also loads the system, SPL Token and Token-2022
+        // programs.
+        #[cfg(not(feature = "sbf"))]
+        let mut svm = LiteSVM::new();
+
+        // Traces the registers of SBF programs, which `Outcome::stack_usage`
+        // is measured from, but leaves it to `build` to collect the traces.
+        #[cfg(feature = "sbf")]
+        let mut svm = LiteSVM::new_debuggable(true);
+        #[cfg(feature = "sbf")]
+        svm.set_invocation_inspect_callback(EmptyInvocationInspectCallback);
+
+        #[cfg(not(feature = "sbf"))]
+        for (program_id, process) in self.programs {
+            native::add_program(&mut svm, program_id, process);
+        }
+
+        #[cfg(feature = "sbf")]
+        for (program_id, path) in self.programs {
+            svm.add_program_from_file(program_id, &path)
+                .unwrap_or_else(|error| panic!("could not load {}: {error}", path.display()));
+        }
+
+        if let Some(clock) = self.clock {
+            svm.set_sysvar(&clock);
+        }
+
+        for (address, account) in self.accounts {
+            svm.set_account(address, account).unwrap();
+        }
+
+        svm
+    }
+}
+
+/// An account holding `value`, funded with one SOL, comfortably rent-exempt
+/// for the accounts of these examples.
+fn borsh_account(owner: Address, value: &impl BorshSerialize) -> Account {
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data: borsh::to_vec(value).unwrap(),
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+const UPGRADEABLE_LOADER: Address =
+    Address::from_str_const("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// A LiteSVM with the programs under test loaded and a funded payer, which
+/// pays for and signs every transaction the fixture sends.
+pub struct Fixture {
+    pub svm: LiteSVM,
+    pub payer: Keypair,
+    #[cfg(feature = "sbf")]
+    stack: StackTracer,
+}
+
+impl Fixture {
+    pub fn builder() -> FixtureBuilder {
+        FixtureBuilder::default()
+    }
+
+    /// A fixture with only the program of `crate_name` loaded.
+    pub fn new(program_id: impl Into<Address>, crate_name: &str, process: Processor) -> Self {
+        Self::builder()
+            .program(program_id, crate_name, process)
+            .build()
+    }
+
+    /// Another keypair, funded with [`PAYER_LAMPORTS`].
+    pub fn funded_payer(&mut self) -> Keypair {
+        let payer = Keypair::new();
+        self.svm.airdrop(&payer.pubkey(), PAYER_LAMPORTS).unwrap();
+        payer
+    }
+
+    pub fn set_account(&mut self, address: Address, account: Account) {
+        self.svm.set_account(address, account).unwrap();
+    }
+
+    /// Writes the Borsh encoding of `value`, e.g. a zero-copy account struct,
+    /// at `address`, owned by `owner` and funded with one SOL.
+    pub fn set_borsh_account(
+        &mut self,
+        address: Address,
+        owner: Address,
+        value: &impl BorshSerialize,
+    ) {
+        self.set_account(address, borsh_account(owner, value));
+    }
+
+    /// Presets the program data account the upgradeable loader keeps for
+    /// `program_id`, with `authority` as its upgrade authority, or none for
+    /// an immutable program. Returns its address.
+    pub fn set_upgrade_authority(
+        &mut self,
+        program_id: &Address,
+        authority: Option<Address>,
+    ) -> Address {
+        let (address, _) =
+            Address::find_program_address(&[program_id.as_ref()], &UPGRADEABLE_LOADER);
+
+        // Loader state tag, deployment slot, upgrade authority option.
+        let mut data = [3, 0, 0, 0].to_vec();
+        data.extend_from_slice(&0u64.to_le_bytes());
+        match authority {
+            Some(authority) => {
+                data.push(1);
+                data.extend_from_slice(authority.as_ref());
+            }
+            None => data.push(0),
+        }
+
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+
+        self.set_account(
+            address,
+            Account {
+                lamports,
+                data,
+                owner: UPGRADEABLE_LOADER,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        address
+    }
+
+    pub fn account(&self, address: &Address) -> Option<Account> {
+        self.svm.get_account(address)
+    }
+
+    /// Decodes the account at `address`, which must exist.
+    pub fn borsh_account<T: BorshDeserialize>(&self, address: &Address) -> T {
+        let account = self
+            .account(address)
+            .unwrap_or_else(|| panic!("account {address} does not exist"));
+
+        T::try_from_slice(&account.data).unwrap()
+    }
+
+    pub fn balance(&self, address: &Address) -> u64 {
+        self.svm.get_balance(address).unwrap_or_default()
+    }
+
+    /// Sends `instruction`, paid for and signed by the fixture payer.
+    pub fn send(&mut self, instruction: Instruction) -> Outcome {
+        self.send_signed(&[instruction], &[])
+    }
+
+    /// Sends `instructions` in one transaction, paid for and signed by the
+    /// fixture payer and also signed by `signers`.
+    pub fn send_signed(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Outcome {
+        let signers = [&self.payer]
+            .into_iter()
+            .chain(signers.iter().copied())
+            .collect::<Vec<_>>();
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &signers,
+            self.svm.latest_blockhash(),
+        );
+
+        #[cfg(feature = "sbf")]
+        self.stack.take();
+
+        let (meta, error) = match self.svm.send_transaction(transaction.clone()) {
+            Ok(meta) => (meta, None),
+            Err(failed) => (failed.meta, Some(failed.err)),
+        };
+
+        // Sending the same instructions again would otherwise be rejected as
+        // an already processed transaction.
+        self.svm.expire_blockhash();
+
+        Outcome {
+            transaction,
+            meta,
+            error,
+            #[cfg(feature = "sbf")]
+            stack: self.stack.take(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use solana_system_interface::instruction::transfer;
+
+    use super::{Fixture, PAYER_LAMPORTS};
+
+    #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+    struct Record {
+        count: u64,
+        bump: u8,
+    }
+
+    #[test]
+    fn sends_as_the_payer() {
+        let mut fixture = Fixture::builder().build();
+        let recipient = Keypair::new().pubkey();
+
+        let outcome = fixture.send(transfer(&fixture.payer.pubkey(), &recipient, 1_000));
+
+        outcome
+            .assert_success()
+            .assert_logs_contain(&["11111111111111111111111111111111 invoke [1]", "success"]);
+
+        assert!(outcome.compute_units() > 0);
+        assert_eq!(fixture.balance(&recipient), 1_000);
+    }
+
+    #[test]
+    fn sends_with_extra_signers() {
+        let mut fixture = Fixture::builder().build();
+        let sender = fixture.funded_payer();
+        let recipient = Keypair::new().pubkey();
+
+        fixture
+            .send_signed(&[transfer(&sender.pubkey(), &recipient, 1_000)], &[&sender])
+            .assert_success();
+
+        assert_eq!(fixture.balance(&sender.pubkey()), PAYER_LAMPORTS - 1_000);
+    }
+
+    #[test]
+    fn reports_program_errors() {
+        let mut fixture = Fixture::builder().build();
+        let recipient = Keypair::new().pubkey();
+
+        // `SystemError::ResultWithNegativeLamports`.
+        fixture
+            .send(transfer(
+                &fixture.payer.pubkey(),
+                &recipient,
+                PAYER_LAMPORTS + 1,
+            ))
+            .assert_error(ProgramError::Custom(1));
+
+        let mut ix = transfer(&Keypair::new().pubkey(), &recipient, 1);
+        ix.accounts[0].is_signer = false;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn presets_typed_accounts() {
+        let address = Address::new_from_array([1; 32]);
+        let owner = Address::new_from_array([2; 32]);
+        let record = Record {
+            count: 19,
+            bump: 254,
+        };
+
+        let mut fixture = Fixture::builder()
+            .borsh_account(address, owner, &record)
+            .build();
+
+        assert_eq!(fixture.borsh_account::<Record>(&address), record);
+        assert_eq!(fixture.account(&address).unwrap().owner, owner);
+
+        fixture.set_borsh_account(
+            address,
+            owner,
+            &Record {
+                count: 20,
+                ..record
+            },
+        );
+
+        assert_eq!(fixture.borsh_account::<Record>(&address).count, 20);
+    }
+}
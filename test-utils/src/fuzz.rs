@@ -0,0 +1,467 @@
+//! Random transactions against a program, checked against the invariants
+//! every transaction keeps, whether it lands or fails.
+//!
+//! A program describes the addresses it works with, the data of its accounts
+//! and its instructions as a [`Target`]. [`cases`] mixes them with random
+//! bytes, owners, lamports, account orderings and signer flags. The same
+//! strategy runs as a proptest and, through [`from_fuzz_input`], as a
+//! cargo-fuzz target.
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+use pinocchio::Address;
+use proptest::{
+    collection::vec,
+    option,
+    prelude::*,
+    sample::select,
+    strategy::ValueTree,
+    test_runner::{Config, RngAlgorithm, TestRng, TestRunner},
+};
+use solana_sdk::{
+    account::Account, instruction::Instruction, message::AccountMeta,
+    native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer,
+};
+
+use crate::{Fixture, Outcome};
+
+/// How many keypairs a case can sign with, on top of the fixture payer.
+pub const SIGNERS: u8 = 3;
+
+/// The keypair `index` below [`SIGNERS`], the same in every case so targets
+/// can list the addresses derived from it, e.g. its PDAs.
+pub fn signer(index: u8) -> Keypair {
+    Keypair::new_from_array([index + 1; 32])
+}
+
+/// What a program's cases are drawn from.
+pub struct Target {
+    pub program_id: Address,
+    /// The addresses the program checks or derives besides the signers,
+    /// e.g. its PDAs.
+    pub addresses: Vec<Address>,
+    /// Data the accounts of the program hold, e.g. initialized accounts with
+    /// any field values.
+    pub account_data: BoxedStrategy<Vec<u8>>,
+    /// Instructions the program accepts given the right accounts, e.g. each
+    /// of its instructions with any arguments, over the signers and
+    /// `addresses`.
+    pub instructions: BoxedStrategy<Instruction>,
+}
+
+/// One instruction and the accounts present before it.
+#[derive(Clone, Debug)]
+pub struct Case {
+    /// The signers and the target addresses, `None` for those that don't
+    /// exist.
+    pub accounts: Vec<(Address, Option<Account>)>,
+    pub instruction: Instruction,
+}
+
+/// Cases of `target`: its instructions, as they are or with their accounts
+/// reordered, flagged differently or replaced, or with random data, over
+/// accounts holding its account data or random bytes.
+pub fn cases(target: Target) -> BoxedStrategy<Case> {
+    let Target {
+        program_id,
+        addresses,
+        account_data,
+        instructions,
+    } = target;
+
+    let signers = (0..SIGNERS)
+        .map(|index| signer(index).pubkey())
+        .collect::<Vec<_>>();
+
+    // Executable accounts are passed as they are, never replaced.
+    let preset = signers
+        .iter()
+        .chain(&addresses)
+        .copied()
+        .collect::<Vec<_>>();
+    let keys = preset
+        .iter()
+        .copied()
+        .chain([program_id, solana_system_interface::program::ID])
+        .collect::<Vec<_>>();
+
+    let accounts = preset
+        .into_iter()
+        .map(|address| {
+            option::weighted(0.75, account(program_id, account_data.clone()))
+                .prop_map(move |account| (address, account))
+        })
+        .collect::<Vec<_>>();
+
+    let instruction = instructions
+        .prop_flat_map(move |instruction| mutations(instruction, keys.clone()))
+        .prop_map(move |mut instruction| {
+            instruction.program_id = program_id;
+
+            // Only the signers have keypairs to sign with.
+            for meta in &mut instruction.accounts {
+                meta.is_signer &= signers.contains(&meta.pubkey);
+            }
+
+            instruction
+        });
+
+    (accounts, instruction)
+        .prop_map(|(accounts, instruction)| Case {
+            accounts,
+            instruction,
+        })
+        .boxed()
+}
+
+/// `instruction` as it is, mostly, so cases get past the first checks of
+/// the program, or with its accounts or data mixed up.
+fn mutations(instruction: Instruction, keys: Vec<Address>) -> impl Strategy<Value = Instruction> {
+    let with_accounts = {
+        let instruction = instruction.clone();
+        move |accounts| Instruction {
+            accounts,
+            ..instruction.clone()
+        }
+    };
+
+    let metas = instruction.accounts.clone();
+    let flags = vec((any::<bool>(), any::<bool>()), metas.len());
+    let random = vec((select(keys), any::<bool>(), any::<bool>()), 0..=6);
+
+    prop_oneof![
+        4 => Just(instruction.clone()),
+        1 => Just(metas.clone()).prop_shuffle().prop_map(with_accounts.clone()),
+        1 => flags
+            .prop_map(move |flags| {
+                metas
+                    .iter()
+                    .zip(flags)
+                    .map(|(meta, (is_signer, is_writable))| AccountMeta {
+                        is_signer,
+                        is_writable,
+                        ..meta.clone()
+                    })
+                    .collect()
+            })
+            .prop_map(with_accounts.clone()),
+        1 => random
+            .prop_map(|metas| {
+                metas
+                    .into_iter()
+                    .map(|(pubkey, is_signer, is_writable)| AccountMeta {
+                        pubkey,
+                        is_signer,
+                        is_writable,
+                    })
+                    .collect()
+            })
+            .prop_map(with_accounts),
+        1 => vec(any::<u8>(), 0..16).prop_map(move |data| Instruction {
+            data,
+            ..instruction.clone()
+        }),
+    ]
+}
+
+fn account(program_id: Address, data: BoxedStrategy<Vec<u8>>) -> impl Strategy<Value = Account> {
+    let owner = prop_oneof![
+        Just(program_id),
+        Just(solana_system_interface::program::ID),
+        any::<[u8; 32]>().prop_map(Address::new_from_array),
+    ];
+
+    // Accounts without lamports don't exist, those are left out instead.
+    let lamports = prop_oneof![
+        1 => Just(1),
+        1 => 1..LAMPORTS_PER_SOL / 100,
+        2 => Just(LAMPORTS_PER_SOL),
+        2 => LAMPORTS_PER_SOL..10 * LAMPORTS_PER_SOL,
+    ];
+
+    let data = prop_oneof![
+        2 => Just(Vec::new()),
+        2 => data,
+        1 => vec(any::<u8>(), 0..128),
+    ];
+
+    (owner, lamports, data).prop_map(|(owner, lamports, data)| Account {
+        lamports,
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    })
+}
+
+/// The value `strategy` draws from a fuzzer input, folded into the seed of
+/// its random numbers.
+///
+/// Proptest's pass-through RNG would let the fuzzer pick each draw, but it
+/// halves the remaining input for every branch of a `prop_oneof!` and then
+/// reads zeros, which range sampling rejects forever.
+pub fn from_fuzz_input<S: Strategy>(strategy: &S, input: &[u8]) -> S::Value {
+    let mut seed = [0; 32];
+
+    for (index, byte) in input.iter().enumerate() {
+        seed[index % seed.len()] ^= byte;
+    }
+
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &seed);
+    let mut runner = TestRunner::new_with_rng(Config::default(), rng);
+
+    strategy.new_tree(&mut runner).unwrap().current()
+}
+
+/// Runs `cases` through `fixture` and `check` on each [`Run`], as a proptest
+/// would, shrinking the first case to fail.
+#[track_caller]
+pub fn check_cases(fixture: Fixture, cases: BoxedStrategy<Case>, check: impl Fn(&Run)) {
+    let fixture = RefCell::new(fixture);
+
+    let mut runner = TestRunner::new(Config {
+        failure_persistence: None,
+        ..Config::default()
+    });
+
+    let result = runner.run(&cases, |case| {
+        check(&run(&mut fixture.borrow_mut(), &case));
+        Ok(())
+    });
+
+    if let Err(error) = result {
+        panic!("{error}\n{runner}");
+    }
+}
+
+/// A [`Case`] sent by a fixture, with the accounts of the transaction before
+/// and after it.
+pub struct Run {
+    pub case: Case,
+    pub payer: Address,
+    /// `None` for the accounts that don't exist.
+    pub before: BTreeMap<Address, Option<Account>>,
+    pub after: BTreeMap<Address, Option<Account>>,
+    pub outcome: Outcome,
+}
+
+/// Presets the accounts of `case` in `fixture` and sends its instruction,
+/// signed by the signers it marks as such.
+///
+/// Every case presets all the addresses it can pass, so one fixture runs any
+/// number of cases.
+pub fn run(fixture: &mut Fixture, case: &Case) -> Run {
+    for (address, account) in &case.accounts {
+        fixture.set_account(*address, account.clone().unwrap_or_default());
+    }
+
+    let payer = fixture.payer.pubkey();
+    let keys = case
+        .instruction
+        .accounts
+        .iter()
+        .map(|meta| meta.pubkey)
+        .chain([payer])
+        .collect::<Vec<_>>();
+
+    let signers = (0..SIGNERS)
+        .map(signer)
+        .filter(|signer| {
+            case.instruction
+                .accounts
+                .iter()
+                .any(|meta| meta.is_signer && meta.pubkey == signer.pubkey())
+        })
+        .collect::<Vec<_>>();
+
+    let before = snapshot(fixture, &keys);
+    let outcome = fixture.send_signed(
+        std::slice::from_ref(&case.instruction),
+        &signers.iter().collect::<Vec<_>>(),
+    );
+    let after = snapshot(fixture, &keys);
+
+    Run {
+        case: case.clone(),
+        payer,
+        before,
+        after,
+        outcome,
+    }
+}
+
+fn snapshot(fixture: &Fixture, keys: &[Address]) -> BTreeMap<Address, Option<Account>> {
+    keys.iter()
+        .map(|address| {
+            let account = fixture
+                .account(address)
+                .filter(|account| account.lamports > 0);
+
+            (*address, account)
+        })
+        .collect()
+}
+
+impl Run {
+    fn is_signer(&self, address: &Address) -> bool {
+        *address == self.payer
+            || self
+                .case
+                .instruction
+                .accounts
+                .iter()
+                .any(|meta| meta.is_signer && meta.pubkey == *address)
+    }
+
+    /// Asserts the invariants every program keeps:
+    ///
+    /// - lamports are conserved, the fee aside;
+    /// - a failed transaction only charges the fee;
+    /// - accounts `program_id` doesn't own keep their owner and data, unless
+    ///   the program creates them;
+    /// - only signers and accounts of `program_id` lose lamports.
+    #[track_caller]
+    pub fn assert_invariants(&self, program_id: &Address) {
+        let fee = self.outcome.meta.fee;
+
+        let total = |accounts: &BTreeMap<Address, Option<Account>>| {
+            accounts
+                .values()
+                .flatten()
+                .map(|account| u128::from(account.lamports))
+                .sum::<u128>()
+        };
+
+        assert_eq!(
+            total(&self.before),
+            total(&self.after) + u128::from(fee),
+            "lamports not conserved by {:?}",
+            self.case
+        );
+
+        for (address, before) in &self.before {
+            let after = &self.after[address];
+
+            if self.outcome.error.is_some() {
+                let mut expected = before.clone();
+
+                if *address == self.payer {
+                    if let Some(payer) = &mut expected {
+                        payer.lamports -= fee;
+                    }
+                }
+
+                assert_eq!(
+                    *after, expected,
+                    "failed transaction changed {address}: {:?}",
+                    self.case
+                );
+                continue;
+            }
+
+            let before = before.clone().unwrap_or_default();
+            let after = after.clone().unwrap_or_default();
+
+            if before.owner == *program_id {
+                continue;
+            }
+
+            // `CreateAccount` hands empty system accounts to the program.
+            let created = after.owner == *program_id
+                && before.owner == solana_system_interface::program::ID
+                && before.data.is_empty();
+
+            if !created {
+                assert_eq!(
+                    (after.owner, &after.data),
+                    (before.owner, &before.data),
+                    "{address} written without being owned by the program: {:?}",
+                    self.case
+                );
+            }
+
+            assert!(
+                after.lamports >= before.lamports || self.is_signer(address),
+                "{address} debited without signing: {:?}",
+                self.case
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pinocchio::Address;
+    use proptest::{collection::vec, prelude::*};
+    use solana_sdk::{account::Account, native_token::LAMPORTS_PER_SOL, signer::Signer};
+    use solana_system_interface::instruction::{create_account, transfer};
+
+    use super::{cases, check_cases, from_fuzz_input, run, signer, Case, Target, SIGNERS};
+    use crate::Fixture;
+
+    /// The system program, as the one target that runs without SBF binaries.
+    fn system_target() -> Target {
+        let instructions = (0..SIGNERS, 0..SIGNERS, 0..LAMPORTS_PER_SOL, any::<bool>())
+            .prop_map(|(from, to, lamports, create)| {
+                let (from, to) = (signer(from).pubkey(), signer(to).pubkey());
+
+                if create {
+                    create_account(&from, &to, lamports, 8, &from)
+                } else {
+                    transfer(&from, &to, lamports)
+                }
+            })
+            .boxed();
+
+        Target {
+            program_id: solana_system_interface::program::ID,
+            addresses: Vec::new(),
+            account_data: vec(any::<u8>(), 0..16).boxed(),
+            instructions,
+        }
+    }
+
+    #[test]
+    fn draws_fuzz_inputs_deterministically() {
+        let cases = cases(system_target());
+
+        for input in [&[][..], &[7; 64], &[0xff; 512]] {
+            let case = from_fuzz_input(&cases, input);
+            let again = from_fuzz_input(&cases, input);
+
+            assert_eq!(format!("{case:?}"), format!("{again:?}"));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "written without being owned by the program")]
+    fn catches_writes_to_foreign_accounts() {
+        let mut fixture = Fixture::builder().build();
+        let (from, to) = (signer(0), signer(1));
+
+        let case = Case {
+            accounts: vec![
+                (
+                    from.pubkey(),
+                    Some(Account::new(LAMPORTS_PER_SOL, 0, &Address::default())),
+                ),
+                (to.pubkey(), None),
+            ],
+            instruction: create_account(&from.pubkey(), &to.pubkey(), 1_000_000, 8, &from.pubkey()),
+        };
+
+        let run = run(&mut fixture, &case);
+        run.outcome.assert_success();
+
+        // The system program writes accounts of its own, checked as if they
+        // belonged to another program.
+        run.assert_invariants(&Address::new_from_array([9; 32]));
+    }
+
+    #[test]
+    fn system_program_keeps_invariants() {
+        check_cases(Fixture::builder().build(), cases(system_target()), |run| {
+            run.assert_invariants(&solana_system_interface::program::ID)
+        });
+    }
+}
@@ -0,0 +1,52 @@
+//! LiteSVM fixtures shared by the programs' tests.
+//!
+//! ```ignore
+//! let mut fixture = Fixture::builder()
+//!     .program(ID, "counter", counter::process)
+//!     .build();
+//!
+//! fixture
+//!     .send(increase_counter_ix())
+//!     .assert_error(CounterError::CounterNotInitialized);
+//! ```
+//!
+//! Programs run natively by default: their `process` functions are loaded
+//! as LiteSVM builtins (see [`native`]), so `cargo test` needs no Solana
+//! toolchain and steps straight into the program code.
+//!
+//! With the `sbf` feature they run as SBF binaries instead, as on-chain,
+//! built by crate name with `cargo build-sbf` the first time a test process
+//! needs them, so a test never runs against a stale binary. Set `SBF_OUT_DIR`
+//! to load prebuilt binaries as they are instead, e.g. to verify a release
+//! build. Compute units and [`StackUsage`] are only measured there.
+//!
+//! [`fuzz`] sends random transactions and checks the invariants every program
+//! keeps, as proptests and cargo-fuzz targets.
+//!
+//! [`error_branch_report`] checks against `cargo llvm-cov` coverage that the
+//! tests reach every error a program returns, for the `error-branches`
+//! binary.
+
+use pinocchio::{AccountView, Address, ProgramResult};
+
+mod branches;
+#[cfg(feature = "sbf")]
+mod build;
+mod fixture;
+pub mod fuzz;
+#[cfg(not(feature = "sbf"))]
+pub mod native;
+mod outcome;
+#[cfg(feature = "sbf")]
+mod stack;
+
+pub use branches::{error_branch_report, error_branches, Coverage, ErrorBranch};
+#[cfg(feature = "sbf")]
+pub use build::{program_path, SBF_OUT_DIR};
+pub use fixture::{Fixture, FixtureBuilder, PAYER_LAMPORTS};
+pub use outcome::Outcome;
+#[cfg(feature = "sbf")]
+pub use stack::StackUsage;
+
+/// The `process` function of a program, which its entrypoint calls.
+pub type Processor = fn(&Address, &[AccountView], &[u8]) -> ProgramResult;
@@ -0,0 +1,345 @@
+//! Runs the programs under test natively, as LiteSVM builtins calling their
+//! `process` functions, so `cargo test` needs no SBF toolchain.
+//!
+//! The builtin lays the instruction's accounts out in the SBF input format,
+//! hands them to `process` through pinocchio's own `deserialize`, and writes
+//! them back the way the runtime does for SBF programs, with the same
+//! ownership, writability and realloc checks. The CPIs, sysvars and logs
+//! behind `shared::syscalls` run against the current invocation, CPIs
+//! synchronizing the accounts both ways like the runtime's `sol_invoke_signed`.
+//!
+//! The programs therefore declare their `entrypoint` only for
+//! `target_os = "solana"`: off-chain `process` is called directly, and an
+//! unmangled `entrypoint` per program would clash in a binary linking several
+//! of them.
+//!
+//! A native program charges a single compute unit and has no stack limit;
+//! measure those against the SBF binaries, with the `sbf` feature.
+
+use std::{
+    any::Any,
+    cell::Cell,
+    collections::BTreeMap,
+    mem::MaybeUninit,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+    sync::{Once, PoisonError, RwLock},
+};
+
+use litesvm::LiteSVM;
+use pinocchio::{
+    account::MAX_PERMITTED_DATA_INCREASE, entrypoint::deserialize, error::ProgramError,
+    instruction::InstructionView, AccountView, Address, ProgramResult, MAX_TX_ACCOUNTS,
+};
+use shared::syscalls::{set_syscall_stubs, SyscallStubs};
+use solana_program_runtime::{
+    declare_process_instruction,
+    invoke_context::{InvokeContext, SerializedAccountMetadata},
+    serialization::{deserialize_parameters, serialize_parameters},
+    solana_sbpf::ebpf::MM_INPUT_START,
+    stable_log,
+};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    native_loader,
+};
+use solana_transaction_context::IndexOfAccount;
+
+use crate::Processor;
+
+/// The processor of every program loaded natively, by program id.
+static PROCESSORS: RwLock<BTreeMap<Address, Processor>> = RwLock::new(BTreeMap::new());
+
+/// Loads `process` as the program `program_id`.
+pub(crate) fn add_program(svm: &mut LiteSVM, program_id: Address, process: Processor) {
+    static STUBS: Once = Once::new();
+    STUBS.call_once(|| {
+        set_syscall_stubs(&NativeStubs);
+    });
+
+    PROCESSORS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(program_id, process);
+
+    svm.add_builtin(program_id, Entrypoint::vm);
+
+    // `add_builtin` leaves the program owned by the BPF loader, which only
+    // runs SBF binaries; the runtime calls builtins owned by the native loader.
+    svm.set_account(
+        program_id,
+        Account {
+            lamports: 1,
+            data: Vec::new(),
+            owner: native_loader::ID,
+            executable: true,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+// The runtime fails builtins that consume no compute units, so each charges a
+// nominal one.
+declare_process_instruction!(Entrypoint, 1, |invoke_context| {
+    process_instruction(invoke_context)
+});
+
+/// A failed CPI or account sync, unwound out of the processor: on-chain it
+/// aborts the transaction without returning to the caller.
+struct Abort(InstructionError);
+
+/// The processor being run, for the syscall stubs.
+struct Invocation {
+    invoke_context: *mut InvokeContext<'static, 'static>,
+    /// The SBF input the processor's accounts point into.
+    input: *mut [u8],
+    metadata: Vec<SerializedAccountMetadata>,
+}
+
+thread_local! {
+    static INVOCATION: Cell<*mut Invocation> = const { Cell::new(ptr::null_mut()) };
+}
+
+fn process_instruction(invoke_context: &mut InvokeContext) -> Result<(), InstructionError> {
+    let (process, mut input, metadata) = {
+        let instruction_context = invoke_context
+            .transaction_context
+            .get_current_instruction_context()?;
+
+        let program_id = instruction_context.get_program_key()?;
+        let process = PROCESSORS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(program_id)
+            .copied()
+            .ok_or(InstructionError::UnsupportedProgramId)?;
+
+        let (input, _, metadata, _) =
+            serialize_parameters(&instruction_context, false, false, false)?;
+
+        (process, input, metadata)
+    };
+
+    let mut invocation = Invocation {
+        invoke_context: invoke_context as *mut InvokeContext as *mut _,
+        input: input.as_slice_mut(),
+        metadata,
+    };
+
+    // Nested for self-CPIs, which run the same builtin again.
+    let caller = INVOCATION.replace(&mut invocation);
+
+    let input_start = invocation.input.cast::<u8>();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut accounts = [const { MaybeUninit::<AccountView>::uninit() }; MAX_TX_ACCOUNTS];
+
+        // SAFETY: `input` is laid out in the SBF input format, aligned like
+        // the VM's, and outlives the processor.
+        let (program_id, count, data) = unsafe { deserialize(input_start, &mut accounts) };
+        // SAFETY: `deserialize` initialized the first `count` accounts.
+        let accounts = unsafe { std::slice::from_raw_parts(accounts.as_ptr().cast(), count) };
+
+        process(program_id, accounts, data)
+    }));
+
+    INVOCATION.set(caller);
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(error)) => return Err(InstructionError::from(u64::from(error))),
+        Err(payload) => return Err(aborted(payload)),
+    }
+
+    let instruction_context = invoke_context
+        .transaction_context
+        .get_current_instruction_context()?;
+
+    deserialize_parameters(
+        &instruction_context,
+        false,
+        false,
+        input.as_slice(),
+        &invocation.metadata,
+    )
+}
+
+/// The error of an [`Abort`], or of a processor that panicked, which on-chain
+/// fails the same way.
+fn aborted(payload: Box<dyn Any + Send>) -> InstructionError {
+    match payload.downcast::<Abort>() {
+        Ok(abort) => abort.0,
+        Err(_) => InstructionError::ProgramFailedToComplete,
+    }
+}
+
+impl Invocation {
+    fn invoke_context(&mut self) -> &mut InvokeContext<'static, 'static> {
+        // SAFETY: the invoke context outlives the invocation, which only the
+        // processor's thread reaches, through the stubs.
+        unsafe { &mut *self.invoke_context }
+    }
+
+    fn input(&mut self) -> &mut [u8] {
+        // SAFETY: the input outlives the invocation, and the processor does
+        // not hold borrows of the accounts across a CPI.
+        unsafe { &mut *self.input }
+    }
+
+    fn invoke(
+        &mut self,
+        instruction: &InstructionView,
+        signers: &[&[&[u8]]],
+    ) -> Result<(), InstructionError> {
+        let caller = *self
+            .invoke_context()
+            .transaction_context
+            .get_current_instruction_context()?
+            .get_program_key()?;
+
+        let signers = signers
+            .iter()
+            .map(|seeds| Address::create_program_address(seeds, &caller))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| InstructionError::InvalidSeeds)?;
+
+        let instruction = Instruction {
+            program_id: *instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: *account.address,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: instruction.data.to_vec(),
+        };
+
+        self.sync_to_runtime()?;
+        self.invoke_context().native_invoke(instruction, &signers)?;
+        self.sync_from_runtime()
+    }
+
+    /// Writes what the processor changed in the input to the accounts the
+    /// callee sees.
+    fn sync_to_runtime(&mut self) -> Result<(), InstructionError> {
+        // SAFETY: see `invoke_context` and `input`, which do not overlap.
+        let (invoke_context, input) = unsafe { (&*self.invoke_context, &*self.input) };
+        let instruction_context = invoke_context
+            .transaction_context
+            .get_current_instruction_context()?;
+
+        deserialize_parameters(&instruction_context, false, false, input, &self.metadata)
+    }
+
+    /// Writes what the callee changed back to the input, where the account
+    /// data may grow by up to `MAX_PERMITTED_DATA_INCREASE` bytes.
+    fn sync_from_runtime(&mut self) -> Result<(), InstructionError> {
+        let offset = |vm_addr: u64| (vm_addr - MM_INPUT_START) as usize;
+
+        for index in 0..self.metadata.len() {
+            let (lamports, owner, data) = {
+                let instruction_context = self
+                    .invoke_context()
+                    .transaction_context
+                    .get_current_instruction_context()?;
+
+                if instruction_context
+                    .is_instruction_account_duplicate(index as IndexOfAccount)?
+                    .is_some()
+                {
+                    continue;
+                }
+
+                let account =
+                    instruction_context.try_borrow_instruction_account(index as IndexOfAccount)?;
+
+                (
+                    account.get_lamports(),
+                    *account.get_owner(),
+                    account.get_data().to_vec(),
+                )
+            };
+
+            let metadata = self.metadata[index].clone();
+
+            if data.len() > metadata.original_data_len + MAX_PERMITTED_DATA_INCREASE {
+                return Err(InstructionError::InvalidRealloc);
+            }
+
+            let input = self.input();
+            let lamports_at = offset(metadata.vm_lamports_addr);
+            let owner_at = offset(metadata.vm_owner_addr);
+            let data_at = offset(metadata.vm_data_addr);
+
+            input[lamports_at..lamports_at + 8].copy_from_slice(&lamports.to_le_bytes());
+            input[owner_at..owner_at + 32].copy_from_slice(owner.as_ref());
+            input[data_at - 8..data_at].copy_from_slice(&(data.len() as u64).to_le_bytes());
+            input[data_at..data_at + data.len()].copy_from_slice(&data);
+        }
+
+        Ok(())
+    }
+}
+
+/// Carries the syscalls of `shared::syscalls` out against the invocation
+/// running on the calling thread.
+struct NativeStubs;
+
+/// Runs `f` on the current invocation.
+fn with_invocation<T>(f: impl FnOnce(&mut Invocation) -> T) -> T {
+    let invocation = INVOCATION.get();
+
+    assert!(
+        !invocation.is_null(),
+        "a syscall ran outside of a native program invocation"
+    );
+
+    // SAFETY: set while the invocation runs its processor on this thread,
+    // which is the only caller of the stubs.
+    f(unsafe { &mut *invocation })
+}
+
+impl SyscallStubs for NativeStubs {
+    fn invoke_signed(&self, instruction: &InstructionView, signers: &[&[&[u8]]]) -> ProgramResult {
+        with_invocation(|invocation| invocation.invoke(instruction, signers))
+            .unwrap_or_else(|error| panic::resume_unwind(Box::new(Abort(error))));
+
+        Ok(())
+    }
+
+    fn get_sysvar(&self, id: &Address, var: &mut [u8]) -> ProgramResult {
+        with_invocation(|invocation| {
+            let sysvar = invocation
+                .invoke_context()
+                .get_sysvar_cache()
+                .sysvar_id_to_buffer(id)
+                .as_deref()
+                .and_then(|sysvar| sysvar.get(..var.len()))
+                .ok_or(ProgramError::UnsupportedSysvar)?;
+
+            var.copy_from_slice(sysvar);
+
+            Ok(())
+        })
+    }
+
+    fn log(&self, message: &[u8]) {
+        with_invocation(|invocation| {
+            stable_log::program_log(
+                &invocation.invoke_context().get_log_collector(),
+                &String::from_utf8_lossy(message),
+            );
+        });
+    }
+
+    fn log_data(&self, data: &[&[u8]]) {
+        with_invocation(|invocation| {
+            stable_log::program_data(&invocation.invoke_context().get_log_collector(), data);
+        });
+    }
+}
@@ -0,0 +1,233 @@
+use litesvm::types::TransactionMetadata;
+use pinocchio::{error::ProgramError, Address};
+use shared::event::{cpi::EventInstructions, logs, EventSet};
+use solana_sdk::{instruction::InstructionError, transaction::Transaction};
+use solana_transaction_error::TransactionError;
+
+#[cfg(feature = "sbf")]
+use crate::StackUsage;
+
+/// A transaction sent by a [`Fixture`](crate::Fixture), landed or not. Its
+/// assertions print the transaction logs when they fail.
+pub struct Outcome {
+    pub transaction: Transaction,
+    pub meta: TransactionMetadata,
+    pub error: Option<TransactionError>,
+    #[cfg(feature = "sbf")]
+    pub stack: StackUsage,
+}
+
+impl Outcome {
+    #[track_caller]
+    pub fn assert_success(&self) -> &Self {
+        if let Some(error) = &self.error {
+            panic!("transaction failed: {error:?}, logs: {:#?}", self.logs());
+        }
+        self
+    }
+
+    /// Asserts that an instruction failed with `expected`, a `ProgramError` or
+    /// any program error enum converting into one.
+    #[track_caller]
+    pub fn assert_error(&self, expected: impl Into<ProgramError>) -> &Self {
+        let expected = expected.into();
+
+        match &self.error {
+            Some(TransactionError::InstructionError(_, error)) => {
+                assert_eq!(
+                    ProgramError::try_from(error.clone()),
+                    Ok(expected),
+                    "unexpected instruction error, logs: {:#?}",
+                    self.logs()
+                );
+            }
+            error => panic!(
+                "expected {expected:?}, got {error:?}, logs: {:#?}",
+                self.logs()
+            ),
+        }
+        self
+    }
+
+    /// Asserts that an instruction failed with `expected`, for the runtime
+    /// errors that have no `ProgramError` counterpart.
+    #[track_caller]
+    pub fn assert_instruction_error(&self, expected: InstructionError) -> &Self {
+        match &self.error {
+            Some(TransactionError::InstructionError(_, error)) => assert_eq!(
+                error,
+                &expected,
+                "unexpected instruction error, logs: {:#?}",
+                self.logs()
+            ),
+            error => panic!(
+                "expected {expected:?}, got {error:?}, logs: {:#?}",
+                self.logs()
+            ),
+        }
+        self
+    }
+
+    pub fn compute_units(&self) -> u64 {
+        self.meta.compute_units_consumed
+    }
+
+    /// The stack the SBF programs of the transaction used.
+    #[cfg(feature = "sbf")]
+    pub fn stack_usage(&self) -> StackUsage {
+        self.stack
+    }
+
+    pub fn logs(&self) -> &[String] {
+        &self.meta.logs
+    }
+
+    /// Asserts that each of `expected` is part of a log line, in order.
+    #[track_caller]
+    pub fn assert_logs_contain(&self, expected: &[&str]) -> &Self {
+        let mut logs = self.logs().iter();
+
+        for needle in expected {
+            assert!(
+                logs.any(|line| line.contains(needle)),
+                "no log line containing {needle:?} in order, logs: {:#?}",
+                self.logs()
+            );
+        }
+        self
+    }
+
+    /// The events `program_id` logged, in order.
+    #[track_caller]
+    pub fn events<E: EventSet>(&self, program_id: &Address) -> Vec<E> {
+        logs::decode_transaction(program_id, &self.meta).unwrap()
+    }
+
+    /// The events `program_id` emitted through self-CPIs signed by its
+    /// `event_authority`, in order, chunked ones reassembled. CPIs from anyone
+    /// else are not events.
+    #[track_caller]
+    pub fn cpi_events<E: EventSet>(
+        &self,
+        program_id: &Address,
+        event_authority: &Address,
+    ) -> Vec<E> {
+        let account_keys = &self.transaction.message.account_keys;
+        let mut events = EventInstructions::new();
+
+        let decoded = self
+            .meta
+            .inner_instructions
+            .iter()
+            .flatten()
+            .map(|inner| &inner.instruction)
+            .filter(|ix| {
+                &account_keys[ix.program_id_index as usize] == program_id
+                    && ix.accounts.first().map(|i| &account_keys[*i as usize])
+                        == Some(event_authority)
+            })
+            .filter_map(|ix| events.push(&ix.data))
+            .map(|data| E::decode(&data.unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        events.finish().unwrap();
+        decoded
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use litesvm::types::TransactionMetadata;
+    use pinocchio::Address;
+    use shared::event::{
+        chunk::{checksum, CHUNK_PAYLOAD_LEN},
+        cpi::{EVENT_CHUNK_IX_TAG, EVENT_IX_TAG},
+        Event, EventSet,
+    };
+    use solana_sdk::{
+        message::{
+            compiled_instruction::CompiledInstruction, inner_instruction::InnerInstruction, Message,
+        },
+        transaction::Transaction,
+    };
+
+    use super::Outcome;
+
+    #[derive(BorshSerialize, BorshDeserialize, Event, Debug, PartialEq)]
+    struct Blob {
+        bytes: Vec<u8>,
+    }
+
+    #[derive(EventSet, Debug, PartialEq)]
+    enum BlobEvent {
+        Blob(Blob),
+    }
+
+    fn encode(event: &Blob) -> Vec<u8> {
+        let mut data = Blob::DISCRIMINATOR.to_vec();
+        data.push(Blob::VERSION);
+        event.serialize(&mut data).unwrap();
+        data
+    }
+
+    /// The data of the self-CPIs emitting `event` in chunks.
+    fn chunk_instructions(event: &Blob) -> Vec<Vec<u8>> {
+        let data = encode(event);
+        let count = data.len().div_ceil(CHUNK_PAYLOAD_LEN) as u16;
+
+        data.chunks(CHUNK_PAYLOAD_LEN)
+            .enumerate()
+            .map(|(index, payload)| {
+                let mut instruction = EVENT_CHUNK_IX_TAG.to_vec();
+                instruction.extend_from_slice(&(index as u16).to_le_bytes());
+                instruction.extend_from_slice(&count.to_le_bytes());
+                instruction.extend_from_slice(&checksum(&data).to_le_bytes());
+                instruction.extend_from_slice(payload);
+                instruction
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reassembles_chunked_cpi_events() {
+        let program_id = Address::new_from_array([1; 32]);
+        let event_authority = Address::new_from_array([2; 32]);
+
+        let small = Blob { bytes: vec![3; 16] };
+        let large = Blob {
+            bytes: vec![4; 1024],
+        };
+
+        let instructions = [
+            vec![[&EVENT_IX_TAG[..], &encode(&small)].concat()],
+            chunk_instructions(&large),
+        ]
+        .concat();
+
+        let mut transaction = Transaction::new_unsigned(Message::default());
+        transaction.message.account_keys = vec![program_id, event_authority];
+
+        let outcome = Outcome {
+            transaction,
+            meta: TransactionMetadata {
+                inner_instructions: vec![instructions
+                    .into_iter()
+                    .map(|data| InnerInstruction {
+                        instruction: CompiledInstruction::new_from_raw_parts(0, data, vec![1, 0]),
+                        stack_height: 2,
+                    })
+                    .collect()],
+                ..TransactionMetadata::default()
+            },
+            error: None,
+            #[cfg(feature = "sbf")]
+            stack: Default::default(),
+        };
+
+        assert_eq!(
+            outcome.cpi_events::<BlobEvent>(&program_id, &event_authority),
+            [BlobEvent::Blob(small), BlobEvent::Blob(large)]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Measures the stack an SBF program uses, from LiteSVM's register trace.
+//!
+//! Every load and store the program executes is traced with the registers it
+//! ran with, so its effective address is known. The ones hitting the stack
+//! region tell how deep into its frame each call reached: the runtime gives
+//! each call a fixed frame of `stack_frame_size` bytes, 4 KiB, growing down,
+//! and the frame pointer tells which calls are live.
+
+use std::sync::{Arc, Mutex, PoisonError};
+
+use litesvm::InvocationInspectCallback;
+use solana_program_runtime::{
+    invoke_context::{Executable, InvokeContext, RegisterTrace},
+    solana_sbpf::ebpf::{self, MM_STACK_START},
+};
+use solana_sdk::transaction::SanitizedTransaction;
+use solana_transaction_context::{IndexOfAccount, InstructionContext};
+
+/// The stack a transaction's SBF programs used, over all their invocations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StackUsage {
+    /// The most bytes used by the live call frames together.
+    pub peak: u64,
+    /// The most bytes used by a single call frame, which must stay within
+    /// the frame size.
+    pub max_frame: u64,
+}
+
+/// Records the [`StackUsage`] of the transactions LiteSVM runs.
+#[derive(Clone, Default)]
+pub(crate) struct StackTracer {
+    usage: Arc<Mutex<StackUsage>>,
+}
+
+impl StackTracer {
+    /// The usage recorded since the last call.
+    pub(crate) fn take(&self) -> StackUsage {
+        std::mem::take(&mut *self.usage.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn record(&self, executable: &Executable, register_trace: RegisterTrace) {
+        assert!(
+            !executable.get_sbpf_version().dynamic_stack_frames(),
+            "stack usage is measured for the fixed frames of SBPF v0 only"
+        );
+
+        let config = executable.get_config();
+        let frame_size = config.stack_frame_size as u64;
+        // Each frame is followed by an unmapped gap of the same size.
+        let stride = if config.enable_stack_frame_gaps {
+            2 * frame_size
+        } else {
+            frame_size
+        };
+        let (_, text) = executable.get_text_bytes();
+
+        let mut usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        // The bytes used by each live frame, the current call's last.
+        let mut frames: Vec<u64> = Vec::new();
+
+        for registers in register_trace {
+            let depth = (registers[ebpf::FRAME_PTR_REG] - MM_STACK_START - frame_size) / stride;
+            // Drops the frames of returned calls, or opens the one just called.
+            frames.resize(depth as usize + 1, 0);
+
+            // The program counter is traced in r11.
+            let insn = ebpf::get_insn_unchecked(text, registers[11] as usize);
+
+            let base = match insn.opc & ebpf::BPF_CLS_MASK {
+                ebpf::BPF_LDX => insn.src,
+                ebpf::BPF_ST | ebpf::BPF_STX => insn.dst,
+                _ => continue,
+            };
+            let address = registers[base as usize].wrapping_add_signed(insn.off as i64);
+
+            let Some(offset) = address.checked_sub(MM_STACK_START) else {
+                continue;
+            };
+            let (frame, within) = (offset / stride, offset % stride);
+
+            if within >= frame_size {
+                continue;
+            }
+            let Some(used) = frames.get_mut(frame as usize) else {
+                continue;
+            };
+
+            *used = (*used).max(frame_size - within);
+
+            usage.max_frame = usage.max_frame.max(*used);
+            usage.peak = usage.peak.max(frames.iter().sum());
+        }
+    }
+}
+
+impl InvocationInspectCallback for StackTracer {
+    fn before_invocation(&self, _: &SanitizedTransaction, _: &[IndexOfAccount], _: &InvokeContext) {
+    }
+
+    fn after_invocation(&self, invoke_context: &InvokeContext, register_tracing_enabled: bool) {
+        if register_tracing_enabled {
+            invoke_context.iterate_vm_traces(
+                &|_: InstructionContext, executable: &Executable, register_trace: RegisterTrace| {
+                    self.record(executable, register_trace)
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use pinocchio::Address;
+
+use crate::ID;
+
+/// PDA set as the mint authority of every mint created by this program.
+///
+/// It holds no data; only this program can sign for it, so only this program
+/// can mint new supply.
+pub struct MintAuthority;
+
+impl MintAuthority {
+    pub const SEED_PREFIX: &[u8; 14] = b"mint_authority";
+
+    pub fn derive(mint: &Address) -> (Address, u8) {
+        Address::find_program_address(&[Self::SEED_PREFIX, mint.as_array()], &ID.into())
+    }
+}
@@ -0,0 +1,3 @@
+mod mint_authority;
+
+pub use mint_authority::*;
@@ -0,0 +1,15 @@
+use shared::error::ErrorCode;
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error, ErrorCode)]
+#[repr(u32)]
+pub enum CreateMintError {
+    #[error("Mint authority does not match the expected PDA")]
+    InvalidMintAuthority = 6000,
+
+    #[error("Mint account is already in use")]
+    MintAlreadyInitialized = 6001,
+
+    #[error("Mint extensions require the Token-2022 program")]
+    ExtensionsRequireToken2022 = 6002,
+}
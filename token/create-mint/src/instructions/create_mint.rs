@@ -0,0 +1,628 @@
+use alloc::{string::String, vec::Vec};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use pinocchio_token_2022::state::Mint;
+use shank::ShankType;
+use shared::{
+    syscalls,
+    system::{self, CreateAccount},
+};
+
+use crate::{
+    accounts::MintAuthority,
+    errors::CreateMintError,
+    token_2022::{
+        mint_len, token_metadata_len, InitializeMetadataPointer, InitializeMint2,
+        InitializeMintCloseAuthority, InitializeNonTransferableMint, InitializeTokenMetadata,
+        InitializeTransferFeeConfig, METADATA_POINTER_LEN, MINT_CLOSE_AUTHORITY_LEN,
+        NON_TRANSFERABLE_LEN, TRANSFER_FEE_CONFIG_LEN,
+    },
+};
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct CreateMintArgs {
+    pub decimals: u8,
+    pub extensions: MintExtensions,
+}
+
+/// Token-2022 extensions to initialize on the mint. All of them must be left
+/// unset when creating an SPL Token mint.
+///
+/// Every authority an extension needs is set to the mint authority PDA.
+#[derive(BorshSerialize, BorshDeserialize, ShankType, Default)]
+pub struct MintExtensions {
+    /// MetadataPointer to the mint itself, plus the TokenMetadata it points to.
+    pub metadata: Option<TokenMetadataArgs>,
+    pub transfer_fee: Option<TransferFeeArgs>,
+    pub close_authority: bool,
+    pub non_transferable: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct TokenMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct TransferFeeArgs {
+    pub transfer_fee_basis_points: u16,
+    pub maximum_fee: u64,
+}
+
+impl MintExtensions {
+    fn is_empty(&self) -> bool {
+        self.metadata.is_none()
+            && self.transfer_fee.is_none()
+            && !self.close_authority
+            && !self.non_transferable
+    }
+
+    /// Value lengths of the extensions that must be allocated up front, i.e.
+    /// all but TokenMetadata, which the token program reallocs for itself.
+    fn fixed_lens(&self) -> Vec<usize> {
+        let mut lens = Vec::new();
+
+        if self.transfer_fee.is_some() {
+            lens.push(TRANSFER_FEE_CONFIG_LEN);
+        }
+        if self.close_authority {
+            lens.push(MINT_CLOSE_AUTHORITY_LEN);
+        }
+        if self.non_transferable {
+            lens.push(NON_TRANSFERABLE_LEN);
+        }
+        if self.metadata.is_some() {
+            lens.push(METADATA_POINTER_LEN);
+        }
+
+        lens
+    }
+}
+
+pub fn create_mint(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    args: CreateMintArgs,
+) -> ProgramResult {
+    let [payer, mint, mint_authority, token_program, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !payer.is_signer() || !mint.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (pda, bump) = MintAuthority::derive(mint.address());
+
+    if mint_authority.address().ne(&pda) {
+        return Err(CreateMintError::InvalidMintAuthority.into());
+    }
+
+    if mint.lamports().ne(&0) {
+        return Err(CreateMintError::MintAlreadyInitialized.into());
+    }
+
+    let token_program_id = token_program.address();
+
+    if token_program_id.eq(&pinocchio_token::ID) {
+        if !args.extensions.is_empty() {
+            return Err(CreateMintError::ExtensionsRequireToken2022.into());
+        }
+    } else if token_program_id.ne(&pinocchio_token_2022::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if system_program.address().ne(&system::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    };
+
+    let extensions = &args.extensions;
+
+    let mut lens = extensions.fixed_lens();
+    let space = mint_len(Mint::BASE_LEN, &lens);
+
+    // Fund the final size, metadata included, so the mint stays rent exempt
+    // once the token program grows it.
+    if let Some(metadata) = &extensions.metadata {
+        lens.push(token_metadata_len(
+            &metadata.name,
+            &metadata.symbol,
+            &metadata.uri,
+        ));
+    }
+    let lamports_required =
+        syscalls::rent()?.minimum_balance_unchecked(mint_len(Mint::BASE_LEN, &lens));
+
+    CreateAccount {
+        from: payer,
+        to: mint,
+        lamports: lamports_required,
+        space: space as u64,
+        owner: token_program_id,
+    }
+    .invoke()?;
+
+    if let Some(transfer_fee) = &extensions.transfer_fee {
+        InitializeTransferFeeConfig {
+            mint,
+            transfer_fee_config_authority: Some(&pda),
+            withdraw_withheld_authority: Some(&pda),
+            transfer_fee_basis_points: transfer_fee.transfer_fee_basis_points,
+            maximum_fee: transfer_fee.maximum_fee,
+            token_program: token_program_id,
+        }
+        .invoke()?;
+    }
+
+    if extensions.close_authority {
+        InitializeMintCloseAuthority {
+            mint,
+            close_authority: Some(&pda),
+            token_program: token_program_id,
+        }
+        .invoke()?;
+    }
+
+    if extensions.non_transferable {
+        InitializeNonTransferableMint {
+            mint,
+            token_program: token_program_id,
+        }
+        .invoke()?;
+    }
+
+    if extensions.metadata.is_some() {
+        InitializeMetadataPointer {
+            mint,
+            authority: Some(&pda),
+            metadata_address: Some(mint.address()),
+            token_program: token_program_id,
+        }
+        .invoke()?;
+    }
+
+    InitializeMint2 {
+        mint,
+        decimals: args.decimals,
+        mint_authority: &pda,
+        freeze_authority: None,
+        token_program: token_program_id,
+    }
+    .invoke()?;
+
+    if let Some(metadata) = &extensions.metadata {
+        let bump_bytes = &[bump];
+        let seeds: [&[u8]; 3] = [
+            MintAuthority::SEED_PREFIX,
+            mint.address().as_ref(),
+            bump_bytes,
+        ];
+
+        InitializeTokenMetadata {
+            mint,
+            update_authority: mint_authority,
+            mint_authority,
+            name: &metadata.name,
+            symbol: &metadata.symbol,
+            uri: &metadata.uri,
+            token_program: token_program_id,
+        }
+        .invoke_signed(&[&seeds])?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::string::ToString;
+
+    use pinocchio::{error::ProgramError, Address};
+    use pinocchio_token_2022::state::Mint;
+    use solana_sdk::{
+        account::Account, instruction::Instruction, message::AccountMeta,
+        native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer,
+    };
+    use test_utils::Fixture;
+
+    use crate::{accounts::MintAuthority, errors::CreateMintError, CreateMintInstruction, ID};
+
+    use super::{CreateMintArgs, MintExtensions, TokenMetadataArgs, TransferFeeArgs};
+
+    const TRANSFER_FEE_CONFIG: u16 = 1;
+    const MINT_CLOSE_AUTHORITY: u16 = 3;
+    const NON_TRANSFERABLE: u16 = 9;
+    const METADATA_POINTER: u16 = 18;
+    const TOKEN_METADATA: u16 = 19;
+
+    fn setup() -> Fixture {
+        Fixture::new(ID, "create-mint", crate::process)
+    }
+
+    fn create_mint_ix(
+        payer: Address,
+        mint: Address,
+        mint_authority: Address,
+        token_program: Address,
+        decimals: u8,
+        extensions: MintExtensions,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID.into(),
+            accounts: [
+                AccountMeta::new(payer, true),
+                AccountMeta::new(mint, true),
+                AccountMeta::new_readonly(mint_authority, false),
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ]
+            .to_vec(),
+            data: borsh::to_vec(&CreateMintInstruction::CreateMint(CreateMintArgs {
+                decimals,
+                extensions,
+            }))
+            .unwrap(),
+        }
+    }
+
+    /// Creates a Token-2022 mint with `extensions` and returns its authority
+    /// PDA and account.
+    fn create_token_2022_mint(
+        fixture: &mut Fixture,
+        extensions: MintExtensions,
+    ) -> (Address, Address, Account) {
+        let mint = Keypair::new();
+        let (mint_authority, _) = MintAuthority::derive(&mint.pubkey());
+
+        let ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority,
+            pinocchio_token_2022::ID,
+            9,
+            extensions,
+        );
+
+        fixture.send_signed(&[ix], &[&mint]).assert_success();
+
+        let account = fixture.account(&mint.pubkey()).unwrap();
+
+        assert_eq!(account.owner, pinocchio_token_2022::ID);
+
+        let mint_data = unsafe { Mint::from_bytes_unchecked(&account.data) };
+
+        assert!(mint_data.is_initialized());
+        assert_eq!(mint_data.decimals(), 9);
+        assert_eq!(mint_data.mint_authority(), Some(&mint_authority));
+
+        (mint.pubkey(), mint_authority, account)
+    }
+
+    /// Finds the value of a Token-2022 TLV extension entry in mint data.
+    fn extension(data: &[u8], extension_type: u16) -> Option<&[u8]> {
+        // Account type byte after the base account, padded to 165 bytes.
+        assert_eq!(data[165], 1);
+
+        let mut offset = 166;
+        while offset + 4 <= data.len() {
+            let entry_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+            let len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+            let value = &data[offset + 4..offset + 4 + len];
+
+            if entry_type == extension_type {
+                return Some(value);
+            }
+
+            offset += 4 + len;
+        }
+
+        None
+    }
+
+    fn read_string(data: &[u8], offset: &mut usize) -> std::string::String {
+        let len = u32::from_le_bytes(data[*offset..*offset + 4].try_into().unwrap()) as usize;
+        let value = std::str::from_utf8(&data[*offset + 4..*offset + 4 + len])
+            .unwrap()
+            .to_string();
+        *offset += 4 + len;
+        value
+    }
+
+    #[test]
+    fn create_mint() {
+        let mut fixture = setup();
+
+        let mint = Keypair::new();
+        let (mint_authority, _) = MintAuthority::derive(&mint.pubkey());
+
+        let ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority,
+            pinocchio_token::ID,
+            6,
+            MintExtensions::default(),
+        );
+
+        fixture.send_signed(&[ix], &[&mint]).assert_success();
+
+        let account = fixture.account(&mint.pubkey()).unwrap();
+
+        assert_eq!(account.owner, pinocchio_token::ID);
+        assert_eq!(account.data.len(), Mint::BASE_LEN);
+
+        let mint_data = unsafe { Mint::from_bytes_unchecked(&account.data) };
+
+        assert!(mint_data.is_initialized());
+        assert_eq!(mint_data.decimals(), 6);
+        assert_eq!(mint_data.supply(), 0);
+        assert_eq!(mint_data.mint_authority(), Some(&mint_authority));
+        assert_eq!(mint_data.freeze_authority(), None);
+    }
+
+    #[test]
+    fn create_mint_with_token_2022() {
+        let mut fixture = setup();
+
+        let (_, _, account) = create_token_2022_mint(&mut fixture, MintExtensions::default());
+
+        assert_eq!(account.data.len(), Mint::BASE_LEN);
+    }
+
+    #[test]
+    fn create_mint_with_metadata() {
+        let mut fixture = setup();
+
+        let (mint, mint_authority, account) = create_token_2022_mint(
+            &mut fixture,
+            MintExtensions {
+                metadata: Some(TokenMetadataArgs {
+                    name: "Pinocchio".to_string(),
+                    symbol: "PINO".to_string(),
+                    uri: "https://example.com/pino.json".to_string(),
+                }),
+                ..MintExtensions::default()
+            },
+        );
+
+        let pointer = extension(&account.data, METADATA_POINTER).unwrap();
+
+        assert_eq!(&pointer[..32], mint_authority.as_ref());
+        assert_eq!(&pointer[32..], mint.as_ref());
+
+        let metadata = extension(&account.data, TOKEN_METADATA).unwrap();
+
+        assert_eq!(&metadata[..32], mint_authority.as_ref());
+        assert_eq!(&metadata[32..64], mint.as_ref());
+
+        let mut offset = 64;
+        assert_eq!(read_string(metadata, &mut offset), "Pinocchio");
+        assert_eq!(read_string(metadata, &mut offset), "PINO");
+        assert_eq!(
+            read_string(metadata, &mut offset),
+            "https://example.com/pino.json"
+        );
+        // No additional metadata.
+        assert_eq!(&metadata[offset..], &[0u8; 4]);
+
+        assert!(
+            account.lamports
+                >= fixture
+                    .svm
+                    .minimum_balance_for_rent_exemption(account.data.len())
+        );
+    }
+
+    #[test]
+    fn create_mint_with_transfer_fee_and_close_authority() {
+        let mut fixture = setup();
+
+        let (_, mint_authority, account) = create_token_2022_mint(
+            &mut fixture,
+            MintExtensions {
+                transfer_fee: Some(TransferFeeArgs {
+                    transfer_fee_basis_points: 50,
+                    maximum_fee: 5_000,
+                }),
+                close_authority: true,
+                ..MintExtensions::default()
+            },
+        );
+
+        let transfer_fee = extension(&account.data, TRANSFER_FEE_CONFIG).unwrap();
+
+        // Config and withdraw authorities, withheld amount, then the older and
+        // newer fees as (epoch, maximum fee, basis points).
+        assert_eq!(&transfer_fee[..32], mint_authority.as_ref());
+        assert_eq!(&transfer_fee[32..64], mint_authority.as_ref());
+        assert_eq!(&transfer_fee[98..106], &5_000u64.to_le_bytes());
+        assert_eq!(&transfer_fee[106..108], &50u16.to_le_bytes());
+
+        let close_authority = extension(&account.data, MINT_CLOSE_AUTHORITY).unwrap();
+
+        assert_eq!(close_authority, mint_authority.as_ref());
+    }
+
+    #[test]
+    fn create_mint_non_transferable() {
+        let mut fixture = setup();
+
+        let (_, _, account) = create_token_2022_mint(
+            &mut fixture,
+            MintExtensions {
+                non_transferable: true,
+                ..MintExtensions::default()
+            },
+        );
+
+        assert_eq!(extension(&account.data, NON_TRANSFERABLE), Some(&[][..]));
+    }
+
+    #[test]
+    fn create_mint_extensions_with_spl_token() {
+        let mut fixture = setup();
+
+        let mint = Keypair::new();
+        let (mint_authority, _) = MintAuthority::derive(&mint.pubkey());
+
+        let ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority,
+            pinocchio_token::ID,
+            6,
+            MintExtensions {
+                non_transferable: true,
+                ..MintExtensions::default()
+            },
+        );
+
+        fixture
+            .send_signed(&[ix], &[&mint])
+            .assert_error(CreateMintError::ExtensionsRequireToken2022);
+    }
+
+    #[test]
+    fn create_mint_with_wrong_mint_authority() {
+        let mut fixture = setup();
+
+        let mint = Keypair::new();
+
+        let ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            fixture.payer.pubkey(),
+            pinocchio_token::ID,
+            6,
+            MintExtensions::default(),
+        );
+
+        fixture
+            .send_signed(&[ix], &[&mint])
+            .assert_error(CreateMintError::InvalidMintAuthority);
+    }
+
+    #[test]
+    fn create_mint_already_initialized() {
+        let mut fixture = setup();
+
+        let mint = Keypair::new();
+        let (mint_authority, _) = MintAuthority::derive(&mint.pubkey());
+
+        fixture.set_account(
+            mint.pubkey(),
+            Account {
+                data: [0u8; Mint::BASE_LEN].to_vec(),
+                executable: false,
+                lamports: LAMPORTS_PER_SOL,
+                owner: pinocchio_token::ID,
+                rent_epoch: 0,
+            },
+        );
+
+        let ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority,
+            pinocchio_token::ID,
+            6,
+            MintExtensions::default(),
+        );
+
+        fixture
+            .send_signed(&[ix], &[&mint])
+            .assert_error(CreateMintError::MintAlreadyInitialized);
+    }
+
+    #[test]
+    fn create_mint_without_mint_signer() {
+        let mut fixture = setup();
+
+        let mint = Keypair::new();
+        let (mint_authority, _) = MintAuthority::derive(&mint.pubkey());
+
+        let mut ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority,
+            pinocchio_token::ID,
+            6,
+            MintExtensions::default(),
+        );
+        ix.accounts[1].is_signer = false;
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::MissingRequiredSignature);
+    }
+
+    #[test]
+    fn create_mint_with_wrong_token_program() {
+        let mut fixture = setup();
+
+        let mint = Keypair::new();
+        let (mint_authority, _) = MintAuthority::derive(&mint.pubkey());
+
+        let ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority,
+            Keypair::new().pubkey(),
+            6,
+            MintExtensions::default(),
+        );
+
+        fixture
+            .send_signed(&[ix], &[&mint])
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn create_mint_with_wrong_system_program() {
+        let mut fixture = setup();
+
+        let mint = Keypair::new();
+        let (mint_authority, _) = MintAuthority::derive(&mint.pubkey());
+
+        let mut ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority,
+            pinocchio_token::ID,
+            6,
+            MintExtensions::default(),
+        );
+        ix.accounts[4].pubkey = Keypair::new().pubkey();
+
+        fixture
+            .send_signed(&[ix], &[&mint])
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn create_mint_with_missing_accounts() {
+        let mut fixture = setup();
+
+        let mint = Keypair::new();
+        let (mint_authority, _) = MintAuthority::derive(&mint.pubkey());
+
+        let mut ix = create_mint_ix(
+            fixture.payer.pubkey(),
+            mint.pubkey(),
+            mint_authority,
+            pinocchio_token::ID,
+            6,
+            MintExtensions::default(),
+        );
+        ix.accounts.truncate(4);
+
+        fixture
+            .send_signed(&[ix], &[&mint])
+            .assert_error(ProgramError::NotEnoughAccountKeys);
+    }
+}
@@ -0,0 +1,3 @@
+mod create_mint;
+
+pub use create_mint::*;
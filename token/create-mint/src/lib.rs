@@ -1,14 +1,93 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
+#![no_std]
+#![allow(unexpected_cfgs)]
+
+extern crate alloc;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{
+    default_allocator, default_panic_handler, error::ProgramError, AccountView, Address,
+    ProgramResult,
+};
+use pinocchio_pubkey::declare_id;
+use shank::ShankInstruction;
+
+use crate::instructions::{create_mint, CreateMintArgs};
+
+mod accounts;
+mod errors;
+mod instructions;
+mod token_2022;
+
+#[cfg(target_os = "solana")]
+pinocchio::program_entrypoint!(process);
+default_allocator!();
+default_panic_handler!();
+
+declare_id!("7G2hHZRSc1S8yNbDyhn1HxiPz2jVmoEbz1BChG8yAmLZ");
+
+#[derive(ShankInstruction, BorshDeserialize, BorshSerialize)]
+pub enum CreateMintInstruction {
+    #[account(0, sig, mut, name = "payer")]
+    #[account(1, sig, mut, name = "mint")]
+    #[account(2, name = "mint_authority")]
+    #[account(3, name = "token_program")]
+    #[account(4, name = "system_program")]
+    CreateMint(CreateMintArgs),
+}
+
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if !crate::check_id(program_id.as_array()) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    match CreateMintInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?
+    {
+        CreateMintInstruction::CreateMint(params) => create_mint(program_id, accounts, params),
+    }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
+mod test {
+    use pinocchio::{error::ProgramError, Address};
+    use solana_sdk::instruction::Instruction;
+    use test_utils::Fixture;
+
+    use crate::ID;
+
+    #[test]
+    fn process_under_other_program_id() {
+        let other_id = Address::new_from_array([7; 32]);
+
+        let mut fixture = Fixture::new(other_id, "create-mint", crate::process);
+
+        let ix = Instruction {
+            program_id: other_id,
+            accounts: [].to_vec(),
+            data: [0].to_vec(),
+        };
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::IncorrectProgramId);
+    }
 
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn process_unknown_instruction() {
+        let mut fixture = Fixture::new(ID, "create-mint", crate::process);
+
+        let ix = Instruction {
+            program_id: ID.into(),
+            accounts: [].to_vec(),
+            data: [0xff].to_vec(),
+        };
+
+        fixture
+            .send(ix)
+            .assert_error(ProgramError::InvalidInstructionData);
     }
 }
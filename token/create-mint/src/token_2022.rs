@@ -0,0 +1,285 @@
+//! CPI builders for the Token-2022 mint extension instructions that
+//! `pinocchio-token-2022` does not provide yet, invoked through
+//! `shared::syscalls` so they also run natively, plus `InitializeMint2`, which
+//! is the `pinocchio-token-2022` builder on-chain.
+//!
+//! Each mirrors the `spl-token-2022` wire format and must be invoked on a
+//! mint that was allocated with room for the extension, before
+//! `InitializeMint2` (except `InitializeTokenMetadata`, which runs after).
+
+use alloc::vec::Vec;
+
+use pinocchio::{
+    instruction::{InstructionAccount, InstructionView},
+    AccountView, Address, ProgramResult,
+};
+use shared::{
+    discriminator::discriminator,
+    syscalls::{invoke, invoke_signed},
+};
+
+/// Data length of a Token-2022 account type byte plus the base account, which
+/// every mint with extensions is padded to before its TLV entries start.
+const BASE_LEN_WITH_EXTENSIONS: usize = 165 + 1;
+
+/// Type + length header of every TLV entry.
+const TLV_HEADER_LEN: usize = 2 + 2;
+
+/// Multisig accounts are 355 bytes; mints of that size get padded to stay
+/// distinguishable.
+const MULTISIG_LEN: usize = 355;
+
+pub const MINT_CLOSE_AUTHORITY_LEN: usize = 32;
+pub const TRANSFER_FEE_CONFIG_LEN: usize = 108;
+pub const NON_TRANSFERABLE_LEN: usize = 0;
+pub const METADATA_POINTER_LEN: usize = 64;
+
+/// Length of the `TokenMetadata` extension value: update authority, mint,
+/// three length-prefixed strings and an empty additional metadata vector.
+pub const fn token_metadata_len(name: &str, symbol: &str, uri: &str) -> usize {
+    32 + 32 + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len()) + 4
+}
+
+/// Account length of a mint carrying extensions of the given value lengths.
+///
+/// Returns the plain mint length when `extensions` is empty.
+pub fn mint_len(base_len: usize, extensions: &[usize]) -> usize {
+    if extensions.is_empty() {
+        return base_len;
+    }
+
+    let len = BASE_LEN_WITH_EXTENSIONS
+        + extensions
+            .iter()
+            .map(|len| TLV_HEADER_LEN + len)
+            .sum::<usize>();
+
+    if len == MULTISIG_LEN {
+        len + 2
+    } else {
+        len
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+const INITIALIZE_MINT_2: u8 = 20;
+const TRANSFER_FEE_EXTENSION: u8 = 26;
+const INITIALIZE_MINT_CLOSE_AUTHORITY: u8 = 25;
+const INITIALIZE_NON_TRANSFERABLE_MINT: u8 = 32;
+const METADATA_POINTER_EXTENSION: u8 = 39;
+
+const INITIALIZE_TOKEN_METADATA: [u8; 8] =
+    discriminator(b"spl_token_metadata_interface:initialize_account");
+
+/// `COption<Pubkey>` as packed by Token-2022 instructions: a presence byte,
+/// followed by the key only when present.
+fn push_optional_address(data: &mut Vec<u8>, address: Option<&Address>) {
+    match address {
+        Some(address) => {
+            data.push(1);
+            data.extend_from_slice(address.as_ref());
+        }
+        None => data.push(0),
+    }
+}
+
+fn invoke_on_mint(mint: &AccountView, token_program: &Address, data: &[u8]) -> ProgramResult {
+    let instruction_accounts = [InstructionAccount::writable(mint.address())];
+
+    let instruction = InstructionView {
+        program_id: token_program,
+        accounts: &instruction_accounts,
+        data,
+    };
+
+    invoke(&instruction, &[mint])
+}
+
+/// `TokenInstruction::InitializeMint2`, for SPL Token and Token-2022 mints.
+pub struct InitializeMint2<'a> {
+    pub mint: &'a AccountView,
+    pub decimals: u8,
+    pub mint_authority: &'a Address,
+    pub freeze_authority: Option<&'a Address>,
+    pub token_program: &'a Address,
+}
+
+impl InitializeMint2<'_> {
+    #[cfg(target_os = "solana")]
+    pub fn invoke(&self) -> ProgramResult {
+        pinocchio_token_2022::instructions::InitializeMint2 {
+            mint: self.mint,
+            decimals: self.decimals,
+            mint_authority: self.mint_authority,
+            freeze_authority: self.freeze_authority,
+            token_program: self.token_program,
+        }
+        .invoke()
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = Vec::with_capacity(2 + 32 + 33);
+        data.extend_from_slice(&[INITIALIZE_MINT_2, self.decimals]);
+        data.extend_from_slice(self.mint_authority.as_ref());
+        push_optional_address(&mut data, self.freeze_authority);
+
+        invoke_on_mint(self.mint, self.token_program, &data)
+    }
+}
+
+/// `TransferFeeInstruction::InitializeTransferFeeConfig`.
+pub struct InitializeTransferFeeConfig<'a> {
+    pub mint: &'a AccountView,
+    pub transfer_fee_config_authority: Option<&'a Address>,
+    pub withdraw_withheld_authority: Option<&'a Address>,
+    pub transfer_fee_basis_points: u16,
+    pub maximum_fee: u64,
+    pub token_program: &'a Address,
+}
+
+impl InitializeTransferFeeConfig<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = Vec::with_capacity(2 + 33 + 33 + 2 + 8);
+        data.extend_from_slice(&[TRANSFER_FEE_EXTENSION, 0]);
+        push_optional_address(&mut data, self.transfer_fee_config_authority);
+        push_optional_address(&mut data, self.withdraw_withheld_authority);
+        data.extend_from_slice(&self.transfer_fee_basis_points.to_le_bytes());
+        data.extend_from_slice(&self.maximum_fee.to_le_bytes());
+
+        invoke_on_mint(self.mint, self.token_program, &data)
+    }
+}
+
+/// `TokenInstruction::InitializeMintCloseAuthority`.
+pub struct InitializeMintCloseAuthority<'a> {
+    pub mint: &'a AccountView,
+    pub close_authority: Option<&'a Address>,
+    pub token_program: &'a Address,
+}
+
+impl InitializeMintCloseAuthority<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let mut data = Vec::with_capacity(1 + 33);
+        data.push(INITIALIZE_MINT_CLOSE_AUTHORITY);
+        push_optional_address(&mut data, self.close_authority);
+
+        invoke_on_mint(self.mint, self.token_program, &data)
+    }
+}
+
+/// `TokenInstruction::InitializeNonTransferableMint`.
+pub struct InitializeNonTransferableMint<'a> {
+    pub mint: &'a AccountView,
+    pub token_program: &'a Address,
+}
+
+impl InitializeNonTransferableMint<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        invoke_on_mint(
+            self.mint,
+            self.token_program,
+            &[INITIALIZE_NON_TRANSFERABLE_MINT],
+        )
+    }
+}
+
+/// `MetadataPointerInstruction::Initialize`.
+pub struct InitializeMetadataPointer<'a> {
+    pub mint: &'a AccountView,
+    pub authority: Option<&'a Address>,
+    pub metadata_address: Option<&'a Address>,
+    pub token_program: &'a Address,
+}
+
+impl InitializeMetadataPointer<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        // `OptionalNonZeroPubkey`: the all-zero key stands for `None`.
+        let none = [0u8; 32];
+
+        let mut data = Vec::with_capacity(2 + 32 + 32);
+        data.extend_from_slice(&[METADATA_POINTER_EXTENSION, 0]);
+        data.extend_from_slice(self.authority.map_or(&none, |a| a.as_array()));
+        data.extend_from_slice(self.metadata_address.map_or(&none, |a| a.as_array()));
+
+        invoke_on_mint(self.mint, self.token_program, &data)
+    }
+}
+
+/// `TokenMetadataInstruction::Initialize`, storing the metadata in the mint
+/// itself. Must run after `InitializeMint2` and be signed by the mint
+/// authority.
+pub struct InitializeTokenMetadata<'a> {
+    pub mint: &'a AccountView,
+    pub update_authority: &'a AccountView,
+    pub mint_authority: &'a AccountView,
+    pub name: &'a str,
+    pub symbol: &'a str,
+    pub uri: &'a str,
+    pub token_program: &'a Address,
+}
+
+impl InitializeTokenMetadata<'_> {
+    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
+        let instruction_accounts = [
+            InstructionAccount::writable(self.mint.address()),
+            InstructionAccount::readonly(self.update_authority.address()),
+            InstructionAccount::readonly(self.mint.address()),
+            InstructionAccount::readonly_signer(self.mint_authority.address()),
+        ];
+
+        let mut data = Vec::with_capacity(
+            8 + 4 + self.name.len() + 4 + self.symbol.len() + 4 + self.uri.len(),
+        );
+        data.extend_from_slice(&INITIALIZE_TOKEN_METADATA);
+        for value in [self.name, self.symbol, self.uri] {
+            data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            data.extend_from_slice(value.as_bytes());
+        }
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.mint,
+                self.update_authority,
+                self.mint,
+                self.mint_authority,
+            ],
+            signers,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        mint_len, token_metadata_len, METADATA_POINTER_LEN, MINT_CLOSE_AUTHORITY_LEN,
+        NON_TRANSFERABLE_LEN, TRANSFER_FEE_CONFIG_LEN,
+    };
+
+    #[test]
+    fn mint_len_matches_token_2022() {
+        assert_eq!(mint_len(82, &[]), 82);
+        assert_eq!(mint_len(82, &[MINT_CLOSE_AUTHORITY_LEN]), 202);
+        assert_eq!(mint_len(82, &[TRANSFER_FEE_CONFIG_LEN]), 278);
+        assert_eq!(mint_len(82, &[NON_TRANSFERABLE_LEN]), 170);
+        assert_eq!(mint_len(82, &[METADATA_POINTER_LEN]), 234);
+    }
+
+    #[test]
+    fn mint_len_skips_multisig_len() {
+        // 166 + 4 + 185 would collide with the multisig length.
+        assert_eq!(mint_len(82, &[185]), 357);
+    }
+
+    #[test]
+    fn token_metadata_len_counts_prefixes() {
+        assert_eq!(token_metadata_len("a", "bc", "def"), 64 + 5 + 6 + 7 + 4);
+    }
+}
@@ -0,0 +1,3 @@
+mod vault;
+
+pub use vault::*;
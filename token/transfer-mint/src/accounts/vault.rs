@@ -0,0 +1,20 @@
+use pinocchio::Address;
+
+use crate::ID;
+
+/// SPL Token account holding an owner's deposits for one mint.
+///
+/// The vault lives at a PDA and is its own token authority, so only this
+/// program can move tokens out of it, and only back to the owner.
+pub struct Vault;
+
+impl Vault {
+    pub const SEED_PREFIX: &[u8; 5] = b"vault";
+
+    pub fn derive(owner: &Address, mint: &Address) -> (Address, u8) {
+        Address::find_program_address(
+            &[Self::SEED_PREFIX, owner.as_array(), mint.as_array()],
+            &ID.into(),
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use shared::error::ErrorCode;
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error, ErrorCode)]
+#[repr(u32)]
+pub enum TransferMintError {
+    #[error("Vault address does not match the expected PDA")]
+    InvalidVaultAddress = 6000,
+
+    #[error("Vault is already initialized")]
+    VaultAlreadyInitialized = 6001,
+
+    #[error("Token account mint does not match the vault mint")]
+    MintMismatch = 6002,
+
+    #[error("Token account is not owned by the signer")]
+    InvalidTokenAccountOwner = 6003,
+
+    #[error("Decimals do not match the mint")]
+    DecimalsMismatch = 6004,
+
+    #[error("Source token account balance is too low")]
+    InsufficientFunds = 6005,
+}
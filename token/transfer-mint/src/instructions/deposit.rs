@@ -0,0 +1,153 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use pinocchio_token::state::TokenAccount;
+use shank::ShankType;
+
+use crate::{accounts::Vault, errors::TransferMintError, token::Transfer};
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct DepositArgs {
+    pub amount: u64,
+}
+
+pub fn deposit(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    args: DepositArgs,
+) -> ProgramResult {
+    let [owner, owner_token, vault, mint, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if token_program.address().ne(&pinocchio_token::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (pda, _) = Vault::derive(owner.address(), mint.address());
+
+    if vault.address().ne(&pda) {
+        return Err(TransferMintError::InvalidVaultAddress.into());
+    }
+
+    let vault_data = TokenAccount::from_account_view(vault)?;
+    let owner_token_data = TokenAccount::from_account_view(owner_token)?;
+
+    if vault_data.mint().ne(mint.address()) || owner_token_data.mint().ne(mint.address()) {
+        return Err(TransferMintError::MintMismatch.into());
+    }
+
+    if owner_token_data.owner().ne(owner.address()) {
+        return Err(TransferMintError::InvalidTokenAccountOwner.into());
+    }
+
+    if owner_token_data.amount() < args.amount {
+        return Err(TransferMintError::InsufficientFunds.into());
+    }
+
+    drop(vault_data);
+    drop(owner_token_data);
+
+    Transfer {
+        from: owner_token,
+        to: vault,
+        authority: owner,
+        amount: args.amount,
+    }
+    .invoke()
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer};
+
+    use crate::{
+        errors::TransferMintError,
+        test_helpers::{
+            set_mint, set_token_account, setup_vault, token_amount, transfer_ix, VaultSetup,
+        },
+        TransferMintInstruction,
+    };
+
+    use super::DepositArgs;
+
+    fn deposit_ix(setup: &VaultSetup, amount: u64) -> Instruction {
+        transfer_ix(
+            TransferMintInstruction::Deposit(DepositArgs { amount }),
+            setup.owner,
+            setup.owner_token,
+            setup.vault,
+            setup.mint,
+        )
+    }
+
+    #[test]
+    fn deposit() {
+        let mut setup = setup_vault(100, 0);
+
+        let ix = deposit_ix(&setup, 40);
+
+        setup.fixture.send(ix).assert_success();
+
+        assert_eq!(token_amount(&setup.fixture, &setup.owner_token), 60);
+        assert_eq!(token_amount(&setup.fixture, &setup.vault), 40);
+    }
+
+    #[test]
+    fn deposit_with_insufficient_balance() {
+        let mut setup = setup_vault(10, 0);
+
+        let ix = deposit_ix(&setup, 11);
+
+        setup
+            .fixture
+            .send(ix)
+            .assert_error(TransferMintError::InsufficientFunds);
+    }
+
+    #[test]
+    fn deposit_with_wrong_mint() {
+        let mut setup = setup_vault(100, 0);
+
+        let other_mint = set_mint(&mut setup.fixture);
+        set_token_account(
+            &mut setup.fixture,
+            setup.owner_token,
+            &other_mint,
+            &setup.owner,
+            100,
+        );
+
+        let ix = deposit_ix(&setup, 40);
+
+        setup
+            .fixture
+            .send(ix)
+            .assert_error(TransferMintError::MintMismatch);
+    }
+
+    #[test]
+    fn deposit_with_wrong_owner() {
+        let mut setup = setup_vault(100, 0);
+
+        set_token_account(
+            &mut setup.fixture,
+            setup.owner_token,
+            &setup.mint,
+            &Keypair::new().pubkey(),
+            100,
+        );
+
+        let ix = deposit_ix(&setup, 40);
+
+        setup
+            .fixture
+            .send(ix)
+            .assert_error(TransferMintError::InvalidTokenAccountOwner);
+    }
+}
@@ -0,0 +1,145 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use pinocchio_token::state::TokenAccount;
+use shared::{
+    syscalls,
+    system::{self, CreateAccount},
+};
+
+use crate::{accounts::Vault, errors::TransferMintError, token::InitializeAccount3};
+
+pub fn init_vault(_program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [owner, vault, mint, token_program, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (pda, bump) = Vault::derive(owner.address(), mint.address());
+
+    if vault.address().ne(&pda) {
+        return Err(TransferMintError::InvalidVaultAddress.into());
+    }
+
+    if vault.lamports().ne(&0) {
+        return Err(TransferMintError::VaultAlreadyInitialized.into());
+    }
+
+    if token_program.address().ne(&pinocchio_token::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if system_program.address().ne(&system::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    };
+
+    let lamports_required = syscalls::rent()?.minimum_balance_unchecked(TokenAccount::LEN);
+
+    let bump_bytes = &[bump];
+    let seeds: [&[u8]; 4] = [
+        Vault::SEED_PREFIX,
+        owner.address().as_ref(),
+        mint.address().as_ref(),
+        bump_bytes,
+    ];
+
+    CreateAccount {
+        from: owner,
+        to: vault,
+        lamports: lamports_required,
+        space: TokenAccount::LEN as u64,
+        owner: &pinocchio_token::ID,
+    }
+    .invoke_signed(&[&seeds])?;
+
+    InitializeAccount3 {
+        account: vault,
+        mint,
+        owner: vault.address(),
+    }
+    .invoke()
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use pinocchio::Address;
+    use pinocchio_token::state::TokenAccount;
+    use solana_sdk::{instruction::Instruction, message::AccountMeta, signer::Signer};
+
+    use crate::{
+        accounts::Vault,
+        errors::TransferMintError,
+        test_helpers::{set_mint, set_token_account, setup},
+        TransferMintInstruction, ID,
+    };
+
+    fn init_vault_ix(owner: Address, vault: Address, mint: Address) -> Instruction {
+        Instruction {
+            program_id: ID.into(),
+            accounts: [
+                AccountMeta::new(owner, true),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(pinocchio_token::ID, false),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ]
+            .to_vec(),
+            data: borsh::to_vec(&TransferMintInstruction::InitVault).unwrap(),
+        }
+    }
+
+    #[test]
+    fn init_vault() {
+        let mut fixture = setup();
+        let owner = fixture.payer.pubkey();
+
+        let mint = set_mint(&mut fixture);
+        let (vault, _) = Vault::derive(&owner, &mint);
+
+        fixture
+            .send(init_vault_ix(owner, vault, mint))
+            .assert_success();
+
+        let account = fixture.account(&vault).unwrap();
+
+        assert_eq!(account.owner, pinocchio_token::ID);
+
+        let vault_data = unsafe { TokenAccount::from_bytes_unchecked(&account.data) };
+
+        assert!(vault_data.is_initialized());
+        assert_eq!(vault_data.mint(), &mint);
+        assert_eq!(vault_data.owner(), &vault);
+        assert_eq!(vault_data.amount(), 0);
+    }
+
+    #[test]
+    fn init_vault_already_initialized() {
+        let mut fixture = setup();
+        let owner = fixture.payer.pubkey();
+
+        let mint = set_mint(&mut fixture);
+        let (vault, _) = Vault::derive(&owner, &mint);
+
+        set_token_account(&mut fixture, vault, &mint, &vault, 0);
+
+        fixture
+            .send(init_vault_ix(owner, vault, mint))
+            .assert_error(TransferMintError::VaultAlreadyInitialized);
+    }
+
+    #[test]
+    fn init_vault_with_wrong_address() {
+        let mut fixture = setup();
+        let owner = fixture.payer.pubkey();
+
+        let mint = set_mint(&mut fixture);
+        let (other_vault, _) = Vault::derive(&mint, &owner);
+
+        fixture
+            .send(init_vault_ix(owner, other_vault, mint))
+            .assert_error(TransferMintError::InvalidVaultAddress);
+    }
+}
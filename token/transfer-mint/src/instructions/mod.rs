@@ -0,0 +1,9 @@
+mod deposit;
+mod init_vault;
+mod withdraw;
+mod withdraw_checked;
+
+pub use deposit::*;
+pub use init_vault::*;
+pub use withdraw::*;
+pub use withdraw_checked::*;
@@ -0,0 +1,165 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use pinocchio_token::state::TokenAccount;
+use shank::ShankType;
+
+use crate::{accounts::Vault, errors::TransferMintError, token::Transfer};
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct WithdrawArgs {
+    pub amount: u64,
+}
+
+pub fn withdraw(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    args: WithdrawArgs,
+) -> ProgramResult {
+    let [owner, owner_token, vault, mint, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if token_program.address().ne(&pinocchio_token::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (pda, bump) = Vault::derive(owner.address(), mint.address());
+
+    if vault.address().ne(&pda) {
+        return Err(TransferMintError::InvalidVaultAddress.into());
+    }
+
+    let vault_data = TokenAccount::from_account_view(vault)?;
+    let owner_token_data = TokenAccount::from_account_view(owner_token)?;
+
+    if vault_data.mint().ne(mint.address()) || owner_token_data.mint().ne(mint.address()) {
+        return Err(TransferMintError::MintMismatch.into());
+    }
+
+    if owner_token_data.owner().ne(owner.address()) {
+        return Err(TransferMintError::InvalidTokenAccountOwner.into());
+    }
+
+    if vault_data.amount() < args.amount {
+        return Err(TransferMintError::InsufficientFunds.into());
+    }
+
+    drop(vault_data);
+    drop(owner_token_data);
+
+    let bump_bytes = &[bump];
+    let seeds: [&[u8]; 4] = [
+        Vault::SEED_PREFIX,
+        owner.address().as_ref(),
+        mint.address().as_ref(),
+        bump_bytes,
+    ];
+
+    Transfer {
+        from: vault,
+        to: owner_token,
+        authority: vault,
+        amount: args.amount,
+    }
+    .invoke_signed(&[&seeds])
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer};
+
+    use crate::{
+        errors::TransferMintError,
+        test_helpers::{set_token_account, setup_vault, token_amount, transfer_ix, VaultSetup},
+        TransferMintInstruction,
+    };
+
+    use super::WithdrawArgs;
+
+    fn withdraw_ix(setup: &VaultSetup, amount: u64) -> Instruction {
+        transfer_ix(
+            TransferMintInstruction::Withdraw(WithdrawArgs { amount }),
+            setup.owner,
+            setup.owner_token,
+            setup.vault,
+            setup.mint,
+        )
+    }
+
+    #[test]
+    fn withdraw() {
+        let mut setup = setup_vault(0, 40);
+
+        let ix = withdraw_ix(&setup, 15);
+
+        setup.fixture.send(ix).assert_success();
+
+        assert_eq!(token_amount(&setup.fixture, &setup.owner_token), 15);
+        assert_eq!(token_amount(&setup.fixture, &setup.vault), 25);
+    }
+
+    #[test]
+    fn withdraw_with_insufficient_balance() {
+        let mut setup = setup_vault(0, 40);
+
+        let ix = withdraw_ix(&setup, 41);
+
+        setup
+            .fixture
+            .send(ix)
+            .assert_error(TransferMintError::InsufficientFunds);
+    }
+
+    #[test]
+    fn withdraw_to_wrong_owner() {
+        let mut setup = setup_vault(0, 40);
+
+        set_token_account(
+            &mut setup.fixture,
+            setup.owner_token,
+            &setup.mint,
+            &Keypair::new().pubkey(),
+            0,
+        );
+
+        let ix = withdraw_ix(&setup, 15);
+
+        setup
+            .fixture
+            .send(ix)
+            .assert_error(TransferMintError::InvalidTokenAccountOwner);
+    }
+
+    #[test]
+    fn withdraw_from_someone_elses_vault() {
+        let mut setup = setup_vault(0, 40);
+
+        let thief = setup.fixture.funded_payer();
+        set_token_account(
+            &mut setup.fixture,
+            setup.owner_token,
+            &setup.mint,
+            &thief.pubkey(),
+            0,
+        );
+
+        let ix = transfer_ix(
+            TransferMintInstruction::Withdraw(WithdrawArgs { amount: 15 }),
+            thief.pubkey(),
+            setup.owner_token,
+            setup.vault,
+            setup.mint,
+        );
+
+        setup
+            .fixture
+            .send_signed(&[ix], &[&thief])
+            .assert_error(TransferMintError::InvalidVaultAddress);
+    }
+}
@@ -0,0 +1,177 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use pinocchio_token::state::{Mint, TokenAccount};
+use shank::ShankType;
+
+use crate::{accounts::Vault, errors::TransferMintError, token::TransferChecked};
+
+#[derive(BorshSerialize, BorshDeserialize, ShankType)]
+pub struct WithdrawCheckedArgs {
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+/// Same as `withdraw`, but the caller states the mint decimals and the token
+/// program re-checks the mint through `TransferChecked`.
+pub fn withdraw_checked(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    args: WithdrawCheckedArgs,
+) -> ProgramResult {
+    let [owner, owner_token, vault, mint, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !owner.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if token_program.address().ne(&pinocchio_token::ID) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (pda, bump) = Vault::derive(owner.address(), mint.address());
+
+    if vault.address().ne(&pda) {
+        return Err(TransferMintError::InvalidVaultAddress.into());
+    }
+
+    let mint_data = Mint::from_account_view(mint)?;
+    let vault_data = TokenAccount::from_account_view(vault)?;
+    let owner_token_data = TokenAccount::from_account_view(owner_token)?;
+
+    if vault_data.mint().ne(mint.address()) || owner_token_data.mint().ne(mint.address()) {
+        return Err(TransferMintError::MintMismatch.into());
+    }
+
+    if mint_data.decimals().ne(&args.decimals) {
+        return Err(TransferMintError::DecimalsMismatch.into());
+    }
+
+    if owner_token_data.owner().ne(owner.address()) {
+        return Err(TransferMintError::InvalidTokenAccountOwner.into());
+    }
+
+    if vault_data.amount() < args.amount {
+        return Err(TransferMintError::InsufficientFunds.into());
+    }
+
+    drop(mint_data);
+    drop(vault_data);
+    drop(owner_token_data);
+
+    let bump_bytes = &[bump];
+    let seeds: [&[u8]; 4] = [
+        Vault::SEED_PREFIX,
+        owner.address().as_ref(),
+        mint.address().as_ref(),
+        bump_bytes,
+    ];
+
+    TransferChecked {
+        from: vault,
+        mint,
+        to: owner_token,
+        authority: vault,
+        amount: args.amount,
+        decimals: args.decimals,
+    }
+    .invoke_signed(&[&seeds])
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use solana_sdk::instruction::Instruction;
+
+    use crate::{
+        accounts::Vault,
+        errors::TransferMintError,
+        test_helpers::{
+            set_mint, set_token_account, setup_vault, token_amount, transfer_ix, VaultSetup,
+            DECIMALS,
+        },
+        TransferMintInstruction,
+    };
+
+    use super::WithdrawCheckedArgs;
+
+    fn withdraw_checked_ix(setup: &VaultSetup, amount: u64, decimals: u8) -> Instruction {
+        transfer_ix(
+            TransferMintInstruction::WithdrawChecked(WithdrawCheckedArgs { amount, decimals }),
+            setup.owner,
+            setup.owner_token,
+            setup.vault,
+            setup.mint,
+        )
+    }
+
+    #[test]
+    fn withdraw_checked() {
+        let mut setup = setup_vault(0, 40);
+
+        let ix = withdraw_checked_ix(&setup, 15, DECIMALS);
+
+        setup.fixture.send(ix).assert_success();
+
+        assert_eq!(token_amount(&setup.fixture, &setup.owner_token), 15);
+        assert_eq!(token_amount(&setup.fixture, &setup.vault), 25);
+    }
+
+    #[test]
+    fn withdraw_checked_with_wrong_decimals() {
+        let mut setup = setup_vault(0, 40);
+
+        let ix = withdraw_checked_ix(&setup, 15, DECIMALS + 1);
+
+        setup
+            .fixture
+            .send(ix)
+            .assert_error(TransferMintError::DecimalsMismatch);
+    }
+
+    #[test]
+    fn withdraw_checked_with_wrong_mint() {
+        let mut setup = setup_vault(0, 40);
+
+        // A vault for another mint that still holds tokens of the original one.
+        let other_mint = set_mint(&mut setup.fixture);
+        let (other_vault, _) = Vault::derive(&setup.owner, &other_mint);
+        set_token_account(
+            &mut setup.fixture,
+            other_vault,
+            &setup.mint,
+            &other_vault,
+            40,
+        );
+
+        let ix = transfer_ix(
+            TransferMintInstruction::WithdrawChecked(WithdrawCheckedArgs {
+                amount: 15,
+                decimals: DECIMALS,
+            }),
+            setup.owner,
+            setup.owner_token,
+            other_vault,
+            other_mint,
+        );
+
+        setup
+            .fixture
+            .send(ix)
+            .assert_error(TransferMintError::MintMismatch);
+    }
+
+    #[test]
+    fn withdraw_checked_with_insufficient_balance() {
+        let mut setup = setup_vault(0, 40);
+
+        let ix = withdraw_checked_ix(&setup, 41, DECIMALS);
+
+        setup
+            .fixture
+            .send(ix)
+            .assert_error(TransferMintError::InsufficientFunds);
+    }
+}
@@ -1,14 +1,80 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+#![no_std]
+#![allow(unexpected_cfgs)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{
+    default_panic_handler, error::ProgramError, no_allocator, AccountView, Address, ProgramResult,
+};
+use pinocchio_pubkey::declare_id;
+use shank::ShankInstruction;
+
+use crate::instructions::{
+    deposit, init_vault, withdraw, withdraw_checked, DepositArgs, WithdrawArgs, WithdrawCheckedArgs,
+};
+
+mod accounts;
+mod errors;
+mod instructions;
+mod token;
 
 #[cfg(test)]
-mod tests {
-    use super::*;
+mod test_helpers;
+
+#[cfg(target_os = "solana")]
+pinocchio::program_entrypoint!(process);
+no_allocator!();
+default_panic_handler!();
+
+declare_id!("FfQmPA3CVeGL8Ai5ARrsVHwiRspiM5vvv4VJWAmDvAUM");
+
+#[derive(ShankInstruction, BorshDeserialize, BorshSerialize)]
+pub enum TransferMintInstruction {
+    #[account(0, sig, mut, name = "owner")]
+    #[account(1, mut, name = "vault")]
+    #[account(2, name = "mint")]
+    #[account(3, name = "token_program")]
+    #[account(4, name = "system_program")]
+    InitVault,
+
+    #[account(0, sig, name = "owner")]
+    #[account(1, mut, name = "owner_token")]
+    #[account(2, mut, name = "vault")]
+    #[account(3, name = "mint")]
+    #[account(4, name = "token_program")]
+    Deposit(DepositArgs),
+
+    #[account(0, sig, name = "owner")]
+    #[account(1, mut, name = "owner_token")]
+    #[account(2, mut, name = "vault")]
+    #[account(3, name = "mint")]
+    #[account(4, name = "token_program")]
+    Withdraw(WithdrawArgs),
+
+    #[account(0, sig, name = "owner")]
+    #[account(1, mut, name = "owner_token")]
+    #[account(2, mut, name = "vault")]
+    #[account(3, name = "mint")]
+    #[account(4, name = "token_program")]
+    WithdrawChecked(WithdrawCheckedArgs),
+}
+
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if !crate::check_id(program_id.as_array()) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    match TransferMintInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?
+    {
+        TransferMintInstruction::InitVault => init_vault(program_id, accounts),
+        TransferMintInstruction::Deposit(params) => deposit(program_id, accounts, params),
+        TransferMintInstruction::Withdraw(params) => withdraw(program_id, accounts, params),
+        TransferMintInstruction::WithdrawChecked(params) => {
+            withdraw_checked(program_id, accounts, params)
+        }
     }
 }
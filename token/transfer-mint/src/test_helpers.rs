@@ -0,0 +1,137 @@
+extern crate std;
+
+use std::vec::Vec;
+
+use pinocchio::Address;
+use pinocchio_token::state::{Mint, TokenAccount};
+use solana_sdk::{
+    account::Account, instruction::Instruction, message::AccountMeta, signature::Keypair,
+    signer::Signer,
+};
+use test_utils::Fixture;
+
+use crate::{accounts::Vault, TransferMintInstruction, ID};
+
+pub const DECIMALS: u8 = 6;
+
+/// A fixture with the program loaded, whose payer is the owner of the
+/// vaults.
+pub fn setup() -> Fixture {
+    Fixture::new(ID, "transfer-mint", crate::process)
+}
+
+/// The fixture payer with a token account and an initialized vault for the
+/// same mint.
+pub struct VaultSetup {
+    pub fixture: Fixture,
+    pub owner: Address,
+    pub mint: Address,
+    pub owner_token: Address,
+    pub vault: Address,
+}
+
+pub fn setup_vault(owner_amount: u64, vault_amount: u64) -> VaultSetup {
+    let mut fixture = setup();
+
+    let owner = fixture.payer.pubkey();
+    let mint = set_mint(&mut fixture);
+    let owner_token = Keypair::new().pubkey();
+    let (vault, _) = Vault::derive(&owner, &mint);
+
+    set_token_account(&mut fixture, owner_token, &mint, &owner, owner_amount);
+    set_token_account(&mut fixture, vault, &mint, &vault, vault_amount);
+
+    VaultSetup {
+        fixture,
+        owner,
+        mint,
+        owner_token,
+        vault,
+    }
+}
+
+/// Builds a `Deposit`, `Withdraw` or `WithdrawChecked` instruction, which all
+/// share the same account list.
+pub fn transfer_ix(
+    instruction: TransferMintInstruction,
+    owner: Address,
+    owner_token: Address,
+    vault: Address,
+    mint: Address,
+) -> Instruction {
+    Instruction {
+        program_id: ID.into(),
+        accounts: [
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new(owner_token, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(pinocchio_token::ID, false),
+        ]
+        .to_vec(),
+        data: borsh::to_vec(&instruction).unwrap(),
+    }
+}
+
+/// Writes an initialized SPL Token mint at a new address.
+pub fn set_mint(fixture: &mut Fixture) -> Address {
+    let mint = Keypair::new().pubkey();
+
+    // COption<Pubkey> mint authority (none), supply, decimals, is_initialized,
+    // COption<Pubkey> freeze authority (none).
+    let mut data = Vec::with_capacity(Mint::LEN);
+    data.extend_from_slice(&[0u8; 36]);
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(DECIMALS);
+    data.push(1);
+    data.extend_from_slice(&[0u8; 36]);
+
+    set_token_program_account(fixture, mint, data);
+
+    mint
+}
+
+/// Writes an initialized SPL Token account at `address`.
+pub fn set_token_account(
+    fixture: &mut Fixture,
+    address: Address,
+    mint: &Address,
+    owner: &Address,
+    amount: u64,
+) {
+    // mint, owner, amount, COption<Pubkey> delegate (none), state
+    // (initialized), COption<u64> is_native (none), delegated amount,
+    // COption<Pubkey> close authority (none).
+    let mut data = Vec::with_capacity(TokenAccount::LEN);
+    data.extend_from_slice(mint.as_ref());
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&[0u8; 36]);
+    data.push(1);
+    data.extend_from_slice(&[0u8; 12]);
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(&[0u8; 36]);
+
+    set_token_program_account(fixture, address, data);
+}
+
+pub fn token_amount(fixture: &Fixture, address: &Address) -> u64 {
+    let account = fixture.account(address).unwrap();
+
+    unsafe { TokenAccount::from_bytes_unchecked(&account.data) }.amount()
+}
+
+fn set_token_program_account(fixture: &mut Fixture, address: Address, data: Vec<u8>) {
+    let lamports = fixture.svm.minimum_balance_for_rent_exemption(data.len());
+
+    fixture.set_account(
+        address,
+        Account {
+            lamports,
+            data,
+            executable: false,
+            owner: pinocchio_token::ID,
+            rent_epoch: 0,
+        },
+    );
+}
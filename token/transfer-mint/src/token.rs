@@ -0,0 +1,162 @@
+//! CPI builders for the SPL Token instructions the vault makes, with the API
+//! of `pinocchio-token` but taking the signer seeds directly. On-chain they
+//! are the `pinocchio-token` builders; off-chain the same instructions go
+//! through `shared::syscalls`, so they also run natively.
+
+#[cfg(not(target_os = "solana"))]
+use pinocchio::instruction::{InstructionAccount, InstructionView};
+use pinocchio::{AccountView, Address, ProgramResult};
+#[cfg(target_os = "solana")]
+use pinocchio_token::instructions;
+#[cfg(target_os = "solana")]
+use shared::syscalls::with_signers;
+#[cfg(not(target_os = "solana"))]
+use shared::syscalls::{invoke, invoke_signed};
+
+#[cfg(not(target_os = "solana"))]
+const TRANSFER: u8 = 3;
+#[cfg(not(target_os = "solana"))]
+const TRANSFER_CHECKED: u8 = 12;
+#[cfg(not(target_os = "solana"))]
+const INITIALIZE_ACCOUNT_3: u8 = 18;
+
+/// `TokenInstruction::Transfer`.
+pub struct Transfer<'a> {
+    pub from: &'a AccountView,
+    pub to: &'a AccountView,
+    pub authority: &'a AccountView,
+    pub amount: u64,
+}
+
+impl Transfer<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[cfg(target_os = "solana")]
+    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
+        with_signers(signers, |signers| {
+            instructions::Transfer {
+                from: self.from,
+                to: self.to,
+                authority: self.authority,
+                amount: self.amount,
+            }
+            .invoke_signed(signers)
+        })
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
+        let instruction_accounts = [
+            InstructionAccount::writable(self.from.address()),
+            InstructionAccount::writable(self.to.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        let mut data = [0; 1 + 8];
+        data[0] = TRANSFER;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: &pinocchio_token::ID,
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(&instruction, &[self.from, self.to, self.authority], signers)
+    }
+}
+
+/// `TokenInstruction::TransferChecked`.
+pub struct TransferChecked<'a> {
+    pub from: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub to: &'a AccountView,
+    pub authority: &'a AccountView,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl TransferChecked<'_> {
+    #[cfg(target_os = "solana")]
+    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
+        with_signers(signers, |signers| {
+            instructions::TransferChecked {
+                from: self.from,
+                mint: self.mint,
+                to: self.to,
+                authority: self.authority,
+                amount: self.amount,
+                decimals: self.decimals,
+            }
+            .invoke_signed(signers)
+        })
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    pub fn invoke_signed(&self, signers: &[&[&[u8]]]) -> ProgramResult {
+        let instruction_accounts = [
+            InstructionAccount::writable(self.from.address()),
+            InstructionAccount::readonly(self.mint.address()),
+            InstructionAccount::writable(self.to.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        let mut data = [0; 1 + 8 + 1];
+        data[0] = TRANSFER_CHECKED;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        data[9] = self.decimals;
+
+        let instruction = InstructionView {
+            program_id: &pinocchio_token::ID,
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.from, self.mint, self.to, self.authority],
+            signers,
+        )
+    }
+}
+
+/// `TokenInstruction::InitializeAccount3`.
+pub struct InitializeAccount3<'a> {
+    pub account: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub owner: &'a Address,
+}
+
+impl InitializeAccount3<'_> {
+    #[cfg(target_os = "solana")]
+    pub fn invoke(&self) -> ProgramResult {
+        instructions::InitializeAccount3 {
+            account: self.account,
+            mint: self.mint,
+            owner: self.owner,
+        }
+        .invoke()
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    pub fn invoke(&self) -> ProgramResult {
+        let instruction_accounts = [
+            InstructionAccount::writable(self.account.address()),
+            InstructionAccount::readonly(self.mint.address()),
+        ];
+
+        let mut data = [0; 1 + 32];
+        data[0] = INITIALIZE_ACCOUNT_3;
+        data[1..33].copy_from_slice(self.owner.as_ref());
+
+        let instruction = InstructionView {
+            program_id: &pinocchio_token::ID,
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke(&instruction, &[self.account, self.mint])
+    }
+}